@@ -21,9 +21,13 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'feroxbuster' {
-            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from] used)')
-            [CompletionResult]::new('--url', 'url', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from] used)')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from || --request-file || --targets-from || --har] used)')
+            [CompletionResult]::new('--url', 'url', [CompletionResultType]::ParameterName, 'The target URL (required, unless [--stdin || --resume-from || --request-file || --targets-from || --har] used)')
             [CompletionResult]::new('--resume-from', 'resume-from', [CompletionResultType]::ParameterName, 'State file from which to resume a partially complete scan (ex. --resume-from ferox-1606586780.state)')
+            [CompletionResult]::new('--targets-from', 'targets-from', [CompletionResultType]::ParameterName, 'Read targets from nmap XML, masscan JSON, or naabu JSON output; web ports are turned into http(s) urls (ex. --targets-from nmap.xml)')
+            [CompletionResult]::new('--har', 'har', [CompletionResultType]::ParameterName, 'Seed the scan from a HAR file; the parent directory of each request to the --url''s origin (or the file''s most common origin) becomes a target and its responses are treated as already known (ex. --har session.har)')
+            [CompletionResult]::new('--request-file', 'request-file', [CompletionResultType]::ParameterName, 'Raw HTTP request file to use as a template for all requests (ex. --request-file req.txt)')
+            [CompletionResult]::new('--protocol', 'protocol', [CompletionResultType]::ParameterName, 'Protocol to use when building the target URL from --request-file (default: https)')
             [CompletionResult]::new('-p', 'p', [CompletionResultType]::ParameterName, 'Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)')
             [CompletionResult]::new('-P', 'P ', [CompletionResultType]::ParameterName, 'Send only unfiltered requests through a Replay Proxy, instead of all requests')
@@ -66,21 +70,33 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('--threads', 'threads', [CompletionResultType]::ParameterName, 'Number of concurrent threads (default: 50)')
             [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)')
             [CompletionResult]::new('--depth', 'depth', [CompletionResultType]::ParameterName, 'Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)')
+            [CompletionResult]::new('--openapi', 'openapi', [CompletionResultType]::ParameterName, 'Request the endpoints described by an OpenAPI/Swagger specification (json or yaml) instead of looking for one on the target (ex. --openapi swagger.json)')
+            [CompletionResult]::new('--source-map-dir', 'source-map-dir', [CompletionResultType]::ParameterName, 'Save the original sources found in javascript source maps to the given directory (ex. --source-map-dir ./sources)')
+            [CompletionResult]::new('--param-mine', 'param-mine', [CompletionResultType]::ParameterName, 'Brute force hidden query parameter names from the given wordlist against each endpoint found (ex. --param-mine params.txt)')
+            [CompletionResult]::new('--vhost', 'vhost', [CompletionResultType]::ParameterName, 'Enumerate virtual hosts by sending each word as the Host header to the target url; words become subdomains of DOMAIN when given (ex. --vhost example.com)')
             [CompletionResult]::new('-L', 'L ', [CompletionResultType]::ParameterName, 'Limit total number of concurrent scans (default: 0, i.e. no limit)')
             [CompletionResult]::new('--scan-limit', 'scan-limit', [CompletionResultType]::ParameterName, 'Limit total number of concurrent scans (default: 0, i.e. no limit)')
             [CompletionResult]::new('--parallel', 'parallel', [CompletionResultType]::ParameterName, 'Run parallel feroxbuster instances (one child process per url passed via stdin)')
             [CompletionResult]::new('--rate-limit', 'rate-limit', [CompletionResultType]::ParameterName, 'Limit number of requests per second (per directory) (default: 0, i.e. no limit)')
             [CompletionResult]::new('--time-limit', 'time-limit', [CompletionResultType]::ParameterName, 'Limit total run time of all scans (ex: --time-limit 10m)')
-            [CompletionResult]::new('-w', 'w', [CompletionResultType]::ParameterName, 'Path or URL of the wordlist')
-            [CompletionResult]::new('--wordlist', 'wordlist', [CompletionResultType]::ParameterName, 'Path or URL of the wordlist')
+            [CompletionResult]::new('-w', 'w', [CompletionResultType]::ParameterName, 'Path or URL of the wordlist (.gz/.zst are decompressed automatically); may be used more than once when each is given a unique KEYWORD (ex: -w dirs.txt:DIR -w versions.txt:VER)')
+            [CompletionResult]::new('--wordlist', 'wordlist', [CompletionResultType]::ParameterName, 'Path or URL of the wordlist (.gz/.zst are decompressed automatically); may be used more than once when each is given a unique KEYWORD (ex: -w dirs.txt:DIR -w versions.txt:VER)')
+            [CompletionResult]::new('--rules', 'rules', [CompletionResultType]::ParameterName, 'File of hashcat-style rules used to mutate each word from the wordlist (ex: c, &[2019-2024])')
+            [CompletionResult]::new('--wordlist-mode', 'wordlist-mode', [CompletionResultType]::ParameterName, 'How multiple wordlists are combined: every combination (clusterbomb) or line by line (pitchfork) (default: clusterbomb)')
             [CompletionResult]::new('-B', 'B ', [CompletionResultType]::ParameterName, 'Automatically request likely backup extensions for "found" urls (default: ~, .bak, .bak2, .old, .1)')
             [CompletionResult]::new('--collect-backups', 'collect-backups', [CompletionResultType]::ParameterName, 'Automatically request likely backup extensions for "found" urls (default: ~, .bak, .bak2, .old, .1)')
+            [CompletionResult]::new('--discover-methods', 'discover-methods', [CompletionResultType]::ParameterName, 'Probe "found" urls with OPTIONS, the given methods, and method-override headers (default: PUT, PATCH, TRACE, FOO)')
+            [CompletionResult]::new('--fingerprint', 'fingerprint', [CompletionResultType]::ParameterName, 'Detect the technologies (and versions) behind each host from headers, cookies, bodies, generator tags and favicons; SIGNATURES is a TOML file of signatures used in addition to the built-in ones')
             [CompletionResult]::new('-I', 'I ', [CompletionResultType]::ParameterName, 'File extension(s) to Ignore while collecting extensions (only used with --collect-extensions)')
             [CompletionResult]::new('--dont-collect', 'dont-collect', [CompletionResultType]::ParameterName, 'File extension(s) to Ignore while collecting extensions (only used with --collect-extensions)')
             [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to write results to (use w/ --json for JSON entries)')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Output file to write results to (use w/ --json for JSON entries)')
             [CompletionResult]::new('--debug-log', 'debug-log', [CompletionResultType]::ParameterName, 'Output file to write log entries (use w/ --json for JSON entries)')
+            [CompletionResult]::new('--save-responses', 'save-responses', [CompletionResultType]::ParameterName, 'Save the body of each "found" url to a directory tree mirroring host/path (ex. --save-responses ./responses)')
+            [CompletionResult]::new('--warc', 'warc', [CompletionResultType]::ParameterName, 'Archive the request and response of each "found" url to a WARC file (ex. --warc scan.warc)')
+            [CompletionResult]::new('--har-out', 'har-out', [CompletionResultType]::ParameterName, 'Export "found" urls to a HAR 1.2 file, written as they''re found (ex. --har-out findings.har)')
             [CompletionResult]::new('--stdin', 'stdin', [CompletionResultType]::ParameterName, 'Read url(s) from STDIN')
+            [CompletionResult]::new('--har-headers', 'har-headers', [CompletionResultType]::ParameterName, 'Reuse the headers and cookies found in the --har file''s requests to the target''s origin')
             [CompletionResult]::new('--burp', 'burp', [CompletionResultType]::ParameterName, 'Set --proxy to http://127.0.0.1:8080 and set --insecure to true')
             [CompletionResult]::new('--burp-replay', 'burp-replay', [CompletionResultType]::ParameterName, 'Set --replay-proxy to http://127.0.0.1:8080 and set --insecure to true')
             [CompletionResult]::new('--smart', 'smart', [CompletionResultType]::ParameterName, 'Set --auto-tune, --collect-words, and --collect-backups to true')
@@ -99,12 +115,18 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, 'Extract links from response body (html, javascript, etc...); make new requests based on findings (default: true)')
             [CompletionResult]::new('--extract-links', 'extract-links', [CompletionResultType]::ParameterName, 'Extract links from response body (html, javascript, etc...); make new requests based on findings (default: true)')
             [CompletionResult]::new('--dont-extract-links', 'dont-extract-links', [CompletionResultType]::ParameterName, 'Don''t extract links from response body (html, javascript, etc...)')
+            [CompletionResult]::new('--openapi-methods', 'openapi-methods', [CompletionResultType]::ParameterName, 'Request api endpoints with every method their specification declares (PUT, POST, DELETE, etc...), not only GET, HEAD and OPTIONS')
+            [CompletionResult]::new('--discover', 'discover', [CompletionResultType]::ParameterName, 'Request /.well-known/ resources (security.txt, openid-configuration, etc...) and common OpenAPI/Swagger specification locations before scanning each target')
+            [CompletionResult]::new('--detect-case', 'detect-case', [CompletionResultType]::ParameterName, 'Check whether each target is case-insensitive using the first resource found on it; if so, skip words that only differ by case')
             [CompletionResult]::new('--auto-tune', 'auto-tune', [CompletionResultType]::ParameterName, 'Automatically lower scan rate when an excessive amount of errors are encountered')
             [CompletionResult]::new('--auto-bail', 'auto-bail', [CompletionResultType]::ParameterName, 'Automatically stop scanning when an excessive amount of errors are encountered')
             [CompletionResult]::new('-D', 'D ', [CompletionResultType]::ParameterName, 'Don''t auto-filter wildcard responses')
             [CompletionResult]::new('--dont-filter', 'dont-filter', [CompletionResultType]::ParameterName, 'Don''t auto-filter wildcard responses')
             [CompletionResult]::new('-E', 'E ', [CompletionResultType]::ParameterName, 'Automatically discover extensions and add them to --extensions (unless they''re in --dont-collect)')
             [CompletionResult]::new('--collect-extensions', 'collect-extensions', [CompletionResultType]::ParameterName, 'Automatically discover extensions and add them to --extensions (unless they''re in --dont-collect)')
+            [CompletionResult]::new('--auto-extensions', 'auto-extensions', [CompletionResultType]::ParameterName, 'Add extensions to --extensions based on each target''s technology (Server, X-Powered-By, cookies, and extensions seen), using the extension_map in the config')
+            [CompletionResult]::new('--bypass-403', 'bypass-403', [CompletionResultType]::ParameterName, 'Probe 401/403 "found" urls with path mutations and header tricks, reporting any that get a different response')
+            [CompletionResult]::new('--secrets', 'secrets', [CompletionResultType]::ParameterName, 'Scan the body of each "found" url for leaked secrets (keys, tokens, passwords, ...), using the secret_rules in the config')
             [CompletionResult]::new('-g', 'g', [CompletionResultType]::ParameterName, 'Automatically discover important words from within responses and add them to the wordlist')
             [CompletionResult]::new('--collect-words', 'collect-words', [CompletionResultType]::ParameterName, 'Automatically discover important words from within responses and add them to the wordlist')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Increase verbosity level (use -vv or more for greater effect. [CAUTION] 4 -v''s is probably too much)')
@@ -113,6 +135,9 @@ Register-ArgumentCompleter -Native -CommandName 'feroxbuster' -ScriptBlock {
             [CompletionResult]::new('-q', 'q', [CompletionResultType]::ParameterName, 'Hide progress bars and banner (good for tmux windows w/ notifications)')
             [CompletionResult]::new('--quiet', 'quiet', [CompletionResultType]::ParameterName, 'Hide progress bars and banner (good for tmux windows w/ notifications)')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Emit JSON logs to --output and --debug-log instead of normal text')
+            [CompletionResult]::new('--save-headers', 'save-headers', [CompletionResultType]::ParameterName, 'Save each response''s headers alongside its body (use w/ --save-responses)')
+            [CompletionResult]::new('--warc-all', 'warc-all', [CompletionResultType]::ParameterName, 'Archive every request made while scanning, not only "found" urls (use w/ --warc)')
+            [CompletionResult]::new('--har-bodies', 'har-bodies', [CompletionResultType]::ParameterName, 'Include response bodies in the exported HAR file (use w/ --har-out)')
             [CompletionResult]::new('--no-state', 'no-state', [CompletionResultType]::ParameterName, 'Disable state output file (*.state)')
             [CompletionResult]::new('-U', 'U ', [CompletionResultType]::ParameterName, 'Update feroxbuster to the latest version')
             [CompletionResult]::new('--update', 'update', [CompletionResultType]::ParameterName, 'Update feroxbuster to the latest version')
@@ -4,7 +4,8 @@ use crate::{
     config::Configuration,
     event_handlers::Handles,
     utils::{make_request, parse_url_with_raw_path, status_colorizer},
-    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD, DEFAULT_STATUS_CODES, VERSION,
+    DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD, DEFAULT_SECRET_RULES, DEFAULT_STATUS_CODES,
+    VERSION,
 };
 use anyhow::{bail, Result};
 use console::{style, Emoji};
@@ -45,6 +46,15 @@ pub struct Banner {
     /// represents Configuration.wordlist
     wordlist: BannerEntry,
 
+    /// represents Configuration.additional_wordlists
+    additional_wordlists: Vec<BannerEntry>,
+
+    /// represents Configuration.wordlist_mode
+    wordlist_mode: BannerEntry,
+
+    /// represents Configuration.rules
+    rules: BannerEntry,
+
     /// represents Configuration.timeout
     timeout: BannerEntry,
 
@@ -57,6 +67,18 @@ pub struct Banner {
     /// represents Configuration.config
     config: BannerEntry,
 
+    /// represents Configuration.request_file
+    request_file: BannerEntry,
+
+    /// represents Configuration.targets_from
+    targets_from: BannerEntry,
+
+    /// represents Configuration.har
+    har: BannerEntry,
+
+    /// represents Configuration.har_headers
+    har_headers: BannerEntry,
+
     /// represents Configuration.proxy
     proxy: BannerEntry,
 
@@ -96,6 +118,27 @@ pub struct Banner {
     /// represents Configuration.extract_links
     extract_links: BannerEntry,
 
+    /// represents Configuration.openapi
+    openapi: BannerEntry,
+
+    /// represents Configuration.openapi_methods
+    openapi_methods: BannerEntry,
+
+    /// represents Configuration.discover
+    discover: BannerEntry,
+
+    /// represents Configuration.source_map_dir
+    source_map_dir: BannerEntry,
+
+    /// represents Configuration.param_mine
+    param_mine: BannerEntry,
+
+    /// represents Configuration.vhost
+    vhost: BannerEntry,
+
+    /// represents Configuration.detect_case
+    detect_case: BannerEntry,
+
     /// represents Configuration.json
     json: BannerEntry,
 
@@ -105,6 +148,24 @@ pub struct Banner {
     /// represents Configuration.debug_log
     debug_log: BannerEntry,
 
+    /// represents Configuration.save_responses
+    save_responses: BannerEntry,
+
+    /// represents Configuration.save_headers
+    save_headers: BannerEntry,
+
+    /// represents Configuration.warc
+    warc: BannerEntry,
+
+    /// represents Configuration.warc_all
+    warc_all: BannerEntry,
+
+    /// represents Configuration.har_out
+    har_out: BannerEntry,
+
+    /// represents Configuration.har_bodies
+    har_bodies: BannerEntry,
+
     /// represents Configuration.extensions
     extensions: BannerEntry,
 
@@ -168,9 +229,24 @@ pub struct Banner {
     /// represents Configuration.dont_collect
     dont_collect: BannerEntry,
 
+    /// represents Configuration.auto_extensions
+    auto_extensions: BannerEntry,
+
     /// represents Configuration.collect_backups
     collect_backups: BannerEntry,
 
+    /// represents Configuration.discover_methods
+    discover_methods: BannerEntry,
+
+    /// represents Configuration.bypass_403
+    bypass_403: BannerEntry,
+
+    /// represents Configuration.fingerprint
+    fingerprint: BannerEntry,
+
+    /// represents Configuration.secrets
+    secrets: BannerEntry,
+
     /// represents Configuration.collect_words
     collect_words: BannerEntry,
 
@@ -332,6 +408,10 @@ impl Banner {
         let auto_tune = BannerEntry::new("🎶", "Auto Tune", &config.auto_tune.to_string());
         let auto_bail = BannerEntry::new("🙅", "Auto Bail", &config.auto_bail.to_string());
         let cfg = BannerEntry::new("💉", "Config File", &config.config);
+        let request_file = BannerEntry::new("📄", "Request File", &config.request_file);
+        let targets_from = BannerEntry::new("🗺", "Targets From", &config.targets_from);
+        let har = BannerEntry::new("🗃", "HAR File", &config.har);
+        let har_headers = BannerEntry::new("🍪", "HAR Headers", &config.har_headers.to_string());
         let proxy = BannerEntry::new("💎", "Proxy", &config.proxy);
         let server_certs = BannerEntry::new(
             "🏅",
@@ -342,14 +422,47 @@ impl Banner {
         let client_key = BannerEntry::new("🔑", "Client Key", &config.client_key);
         let threads = BannerEntry::new("🚀", "Threads", &config.threads.to_string());
         let wordlist = BannerEntry::new("📖", "Wordlist", &config.wordlist);
+        let additional_wordlists = config
+            .additional_wordlists
+            .iter()
+            .map(|spec| BannerEntry::new("📖", "Wordlist", spec))
+            .collect();
+        let wordlist_mode = BannerEntry::new(
+            "🧮",
+            "Wordlist Mode",
+            &format!("{:?}", config.wordlist_mode).to_lowercase(),
+        );
+        let rules = BannerEntry::new(
+            "🧬",
+            "Wordlist Rules",
+            &format!(
+                "[{}] ({} per word)",
+                config.rules,
+                config.rules.num_candidates()
+            ),
+        );
         let timeout = BannerEntry::new("💥", "Timeout (secs)", &config.timeout.to_string());
         let user_agent = BannerEntry::new("🦡", "User-Agent", &config.user_agent);
         let random_agent = BannerEntry::new("🦡", "User-Agent", "Random");
         let extract_links =
             BannerEntry::new("🔎", "Extract Links", &config.extract_links.to_string());
+        let openapi = BannerEntry::new("📜", "OpenAPI Spec", &config.openapi);
+        let openapi_methods =
+            BannerEntry::new("🧨", "OpenAPI Methods", &config.openapi_methods.to_string());
+        let discover = BannerEntry::new("🛰", "Discovery", &config.discover.to_string());
+        let source_map_dir = BannerEntry::new("🗂", "Source Map Dir", &config.source_map_dir);
+        let param_mine = BannerEntry::new("⛏", "Param Mining", &config.param_mine);
+        let vhost = BannerEntry::new("🏘", "Virtual Hosts", &config.vhost_for("WORD"));
+        let detect_case = BannerEntry::new("🔠", "Detect Case", &config.detect_case.to_string());
         let json = BannerEntry::new("🧔", "JSON Output", &config.json.to_string());
         let output = BannerEntry::new("💾", "Output File", &config.output);
         let debug_log = BannerEntry::new("🪲", "Debugging Log", &config.debug_log);
+        let save_responses = BannerEntry::new("📥", "Save Responses", &config.save_responses);
+        let save_headers = BannerEntry::new("📋", "Save Headers", &config.save_headers.to_string());
+        let warc = BannerEntry::new("🗄", "WARC Archive", &config.warc);
+        let warc_all = BannerEntry::new("📼", "WARC All Requests", &config.warc_all.to_string());
+        let har_out = BannerEntry::new("📤", "HAR Export", &config.har_out);
+        let har_bodies = BannerEntry::new("📄", "HAR Bodies", &config.har_bodies.to_string());
         let extensions = BannerEntry::new(
             "💲",
             "Extensions",
@@ -401,8 +514,53 @@ impl Banner {
             "Collect Extensions",
             &config.collect_extensions.to_string(),
         );
+        let auto_extensions =
+            BannerEntry::new("🧭", "Auto Extensions", &config.auto_extensions.to_string());
         let collect_backups =
             BannerEntry::new("🏦", "Collect Backups", &config.collect_backups.to_string());
+        let discover_methods = BannerEntry::new(
+            "🔀",
+            "Discover Methods",
+            &format!("[{}]", config.probe_methods.join(", ")),
+        );
+        let bypass_403 = BannerEntry::new("🚪", "Bypass 403", &config.bypass_403.to_string());
+
+        // extra signatures are shown in place of `true` when they're used
+        let fingerprint = if config.fingerprint_signatures.is_empty() {
+            BannerEntry::new("🧬", "Fingerprint", &config.fingerprint.to_string())
+        } else {
+            BannerEntry::new("🧬", "Fingerprint", &config.fingerprint_signatures)
+        };
+
+        let default_secret_rules = config.secret_rules.len() == DEFAULT_SECRET_RULES.len()
+            && config.secret_rules.iter().zip(DEFAULT_SECRET_RULES).all(
+                |(rule, (id, regex, min_entropy))| {
+                    rule.id == id && rule.regex == regex && rule.min_entropy == min_entropy
+                },
+            );
+
+        let secrets = if default_secret_rules {
+            // default has 10 rules, just trim it up
+            BannerEntry::new(
+                "🔑",
+                "Secret Rules",
+                "[Cloud keys, Private keys, JWTs, Tokens, etc...]",
+            )
+        } else {
+            BannerEntry::new(
+                "🔑",
+                "Secret Rules",
+                &format!(
+                    "[{}]",
+                    config
+                        .secret_rules
+                        .iter()
+                        .map(|rule| rule.id.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        };
 
         let collect_words =
             BannerEntry::new("🤑", "Collect Words", &config.collect_words.to_string());
@@ -412,6 +570,9 @@ impl Banner {
             status_codes,
             threads,
             wordlist,
+            additional_wordlists,
+            wordlist_mode,
+            rules,
             filter_status,
             timeout,
             user_agent,
@@ -431,11 +592,24 @@ impl Banner {
             filter_line_count,
             filter_regex,
             extract_links,
+            openapi,
+            openapi_methods,
+            discover,
+            source_map_dir,
+            param_mine,
+            vhost,
+            detect_case,
             parallel,
             json,
             queries,
             output,
             debug_log,
+            save_responses,
+            save_headers,
+            warc,
+            warc_all,
+            har_out,
+            har_bodies,
             extensions,
             methods,
             data,
@@ -451,10 +625,19 @@ impl Banner {
             time_limit,
             url_denylist,
             collect_extensions,
+            auto_extensions,
             collect_backups,
+            discover_methods,
+            bypass_403,
+            fingerprint,
+            secrets,
             collect_words,
             dont_collect,
             config: cfg,
+            request_file,
+            targets_from,
+            har,
+            har_headers,
             version: VERSION.to_string(),
             update_status: UpdateStatus::Unknown,
         }
@@ -522,6 +705,7 @@ by Ben "epi" Risher {}                 ver: {}"#,
             &api_url,
             DEFAULT_METHOD,
             None,
+            None,
             level,
             &handles.config,
             tx_stats,
@@ -559,6 +743,34 @@ by Ben "epi" Risher {}                 ver: {}"#,
         Ok(())
     }
 
+    /// add the decisions made by --detect-case that were recorded in a resumed scan's state, as
+    /// they're reused instead of being made again
+    pub fn add_case_decisions(&mut self, decisions: &HashMap<String, bool>) {
+        if decisions.is_empty() {
+            return;
+        }
+
+        let mut origins: Vec<_> = decisions
+            .iter()
+            .map(|(origin, insensitive)| {
+                let case = if *insensitive {
+                    "insensitive"
+                } else {
+                    "sensitive"
+                };
+                format!("{origin}: {case}")
+            })
+            .collect();
+
+        origins.sort();
+
+        self.detect_case = BannerEntry::new(
+            "🔠",
+            "Detect Case",
+            &format!("true [{}]", origins.join(", ")),
+        );
+    }
+
     /// display the banner on Write writer
     pub fn print_to<W>(&self, mut writer: W, config: Arc<Configuration>) -> Result<()>
     where
@@ -578,6 +790,18 @@ by Ben "epi" Risher {}                 ver: {}"#,
         writeln!(&mut writer, "{}", self.threads)?;
         writeln!(&mut writer, "{}", self.wordlist)?;
 
+        for wordlist in &self.additional_wordlists {
+            writeln!(&mut writer, "{wordlist}")?;
+        }
+
+        if !config.additional_wordlists.is_empty() {
+            writeln!(&mut writer, "{}", self.wordlist_mode)?;
+        }
+
+        if !config.rules.is_empty() {
+            writeln!(&mut writer, "{}", self.rules)?;
+        }
+
         if config.filter_status.is_empty() {
             // -C and -s are mutually exclusive, and -s meaning changes when -C is used
             // so only print one or the other
@@ -599,6 +823,22 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.config)?;
         }
 
+        if !config.request_file.is_empty() {
+            writeln!(&mut writer, "{}", self.request_file)?;
+        }
+
+        if !config.targets_from.is_empty() {
+            writeln!(&mut writer, "{}", self.targets_from)?;
+        }
+
+        if !config.har.is_empty() {
+            writeln!(&mut writer, "{}", self.har)?;
+        }
+
+        if config.har_headers {
+            writeln!(&mut writer, "{}", self.har_headers)?;
+        }
+
         if !config.proxy.is_empty() {
             writeln!(&mut writer, "{}", self.proxy)?;
         }
@@ -650,6 +890,34 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.extract_links)?;
         }
 
+        if !config.openapi.is_empty() {
+            writeln!(&mut writer, "{}", self.openapi)?;
+        }
+
+        if config.openapi_methods {
+            writeln!(&mut writer, "{}", self.openapi_methods)?;
+        }
+
+        if config.discover {
+            writeln!(&mut writer, "{}", self.discover)?;
+        }
+
+        if !config.source_map_dir.is_empty() {
+            writeln!(&mut writer, "{}", self.source_map_dir)?;
+        }
+
+        if !config.param_mine.is_empty() {
+            writeln!(&mut writer, "{}", self.param_mine)?;
+        }
+
+        if config.vhost {
+            writeln!(&mut writer, "{}", self.vhost)?;
+        }
+
+        if config.detect_case {
+            writeln!(&mut writer, "{}", self.detect_case)?;
+        }
+
         if config.json {
             writeln!(&mut writer, "{}", self.json)?;
         }
@@ -666,6 +934,30 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.debug_log)?;
         }
 
+        if !config.save_responses.is_empty() {
+            writeln!(&mut writer, "{}", self.save_responses)?;
+        }
+
+        if config.save_headers {
+            writeln!(&mut writer, "{}", self.save_headers)?;
+        }
+
+        if !config.warc.is_empty() {
+            writeln!(&mut writer, "{}", self.warc)?;
+        }
+
+        if config.warc_all {
+            writeln!(&mut writer, "{}", self.warc_all)?;
+        }
+
+        if !config.har_out.is_empty() {
+            writeln!(&mut writer, "{}", self.har_out)?;
+        }
+
+        if config.har_bodies {
+            writeln!(&mut writer, "{}", self.har_bodies)?;
+        }
+
         if !config.extensions.is_empty() {
             writeln!(&mut writer, "{}", self.extensions)?;
         }
@@ -676,10 +968,30 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.dont_collect)?;
         }
 
+        if config.auto_extensions {
+            writeln!(&mut writer, "{}", self.auto_extensions)?;
+        }
+
         if config.collect_backups {
             writeln!(&mut writer, "{}", self.collect_backups)?;
         }
 
+        if config.discover_methods {
+            writeln!(&mut writer, "{}", self.discover_methods)?;
+        }
+
+        if config.bypass_403 {
+            writeln!(&mut writer, "{}", self.bypass_403)?;
+        }
+
+        if config.fingerprint {
+            writeln!(&mut writer, "{}", self.fingerprint)?;
+        }
+
+        if config.secrets {
+            writeln!(&mut writer, "{}", self.secrets)?;
+        }
+
         if config.collect_words {
             writeln!(&mut writer, "{}", self.collect_words)?;
         }
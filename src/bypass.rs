@@ -0,0 +1,329 @@
+//! building blocks for probing 401/403 responses for access control bypasses (--bypass-403); the
+//! requests themselves are made by the `TermOutHandler`, the same as with --collect-backups
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use console::style;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::OutputLevel,
+    traits::FeroxSerialize,
+    utils::{fmt_err, status_colorizer},
+};
+
+/// headers asking a reverse proxy or framework to route the request to the given path, instead
+/// of the one in the request line
+const REWRITE_HEADERS: [&str; 2] = ["X-Original-URL", "X-Rewrite-URL"];
+
+/// minimum relative change in size for a response with the same status code as the one it's
+/// compared to, to be considered different
+const SIZE_CHANGE_RATIO: f64 = 0.1;
+
+/// a single request made in an attempt to get past a 401/403
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BypassVariant {
+    /// url to request, exactly as it should be sent
+    pub(crate) url: String,
+
+    /// headers sent along with the request
+    pub(crate) headers: BTreeMap<String, String>,
+
+    /// short description of the technique, i.e. `/x/../path` or `X-Original-URL`
+    pub(crate) technique: String,
+
+    /// whether the request is sent to the site's root and relies on a rewrite header to reach
+    /// the blocked path; these need to differ from the root's own response as well
+    pub(crate) rewrites: bool,
+}
+
+/// implementation of BypassVariant
+impl BypassVariant {
+    /// create a variant that requests the given url with the given headers
+    fn new(url: String, headers: &[(&str, &str)], technique: &str) -> Self {
+        Self {
+            url,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            technique: technique.to_string(),
+            rewrites: false,
+        }
+    }
+}
+
+/// every variant of the given (blocked) url's request that will be tried
+///
+/// given http://localhost/secret/admin, the following are requested:
+/// - http://localhost/secret/.;/admin
+/// - http://localhost/secret/x/../admin
+/// - http://localhost/secret//admin
+/// - http://localhost/secret/..;/admin
+/// - http://localhost/secret/ADMIN
+/// - http://localhost/secret/admin%20
+/// - http://localhost/secret/admin;
+/// - http://localhost/ with an `X-Original-URL: /secret/admin` header
+/// - http://localhost/ with an `X-Rewrite-URL: /secret/admin` header
+/// - http://localhost/secret/admin with an `X-Forwarded-For: 127.0.0.1` header
+pub(crate) fn bypass_variants(url: &Url) -> Vec<BypassVariant> {
+    let origin = url.origin().ascii_serialization();
+    let query = url
+        .query()
+        .map(|query| format!("?{query}"))
+        .unwrap_or_default();
+
+    let path = url.path();
+    let slash = if path.len() > 1 && path.ends_with('/') {
+        "/"
+    } else {
+        ""
+    };
+
+    let mut variants = Vec::new();
+
+    if let Some((parent, name)) = path.trim_end_matches('/').rsplit_once('/') {
+        if !name.is_empty() {
+            let prefix = format!("{origin}{parent}");
+
+            // `Url` removes single-dot segments (`/./`, `/%2e/`) no matter how it's built, so the
+            // dot tricks use segments that survive `parse_url_with_raw_path` instead
+            let mut mutations = vec![
+                ("/.;/path", format!("{prefix}/.;/{name}{slash}")),
+                ("/x/../path", format!("{prefix}/x/../{name}{slash}")),
+                ("//path", format!("{prefix}//{name}{slash}")),
+                ("..;/path", format!("{prefix}/..;/{name}{slash}")),
+            ];
+
+            let upper = name.to_uppercase();
+            let lower = name.to_lowercase();
+
+            if upper != name {
+                mutations.push(("PATH", format!("{prefix}/{upper}{slash}")));
+            } else if lower != name {
+                mutations.push(("path", format!("{prefix}/{lower}{slash}")));
+            }
+
+            mutations.push(("path%20", format!("{prefix}/{name}%20{slash}")));
+            mutations.push(("path;", format!("{prefix}/{name};{slash}")));
+
+            for (technique, mutated) in mutations {
+                variants.push(BypassVariant::new(
+                    format!("{mutated}{query}"),
+                    &[],
+                    technique,
+                ));
+            }
+
+            for header in REWRITE_HEADERS {
+                let mut variant = BypassVariant::new(
+                    format!("{origin}/"),
+                    &[(header, &format!("{path}{query}"))],
+                    header,
+                );
+                variant.rewrites = true;
+                variants.push(variant);
+            }
+        }
+    }
+
+    variants.push(BypassVariant::new(
+        url.to_string(),
+        &[("X-Forwarded-For", "127.0.0.1")],
+        "X-Forwarded-For",
+    ));
+
+    variants
+}
+
+/// whether a response's (status code, size) differs meaningfully from another's
+///
+/// a different status code counts when it isn't an error, and the same status code counts when
+/// the size changed by more than 10%
+pub(crate) fn differs_meaningfully(original: (u16, u64), other: (u16, u64)) -> bool {
+    if other.0 != original.0 {
+        return other.0 < 400;
+    }
+
+    original.1.abs_diff(other.1) as f64 > original.1.max(1) as f64 * SIZE_CHANGE_RATIO
+}
+
+/// A variant of a 401/403's request that received a meaningfully different response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BypassFinding {
+    #[serde(rename = "type", default = "bypass_type")]
+    /// Name of this type of struct, used for serialization, i.e. `{"type":"bypass"}`
+    kind: String,
+
+    /// url that was requested
+    pub(crate) url: String,
+
+    /// url of the 401/403 response
+    pub(crate) original_url: String,
+
+    /// http method used for the request
+    pub(crate) method: String,
+
+    /// technique that was used, i.e. `/x/../path` or `X-Original-URL`
+    pub(crate) technique: String,
+
+    /// headers sent along with the request
+    pub(crate) headers: BTreeMap<String, String>,
+
+    /// status code of the response
+    pub(crate) status: u16,
+
+    /// status code of the 401/403 response
+    pub(crate) original_status: u16,
+
+    /// size of the response
+    pub(crate) content_length: u64,
+
+    /// whether the user passed --quiet|--silent|--json on the command line
+    #[serde(skip)]
+    output_level: OutputLevel,
+}
+
+/// default value for `BypassFinding.kind`
+fn bypass_type() -> String {
+    String::from("bypass")
+}
+
+/// implementation of BypassFinding
+impl BypassFinding {
+    /// create a new BypassFinding from the variant that was sent and its response
+    pub(crate) fn new(
+        variant: &BypassVariant,
+        original_url: &str,
+        method: &str,
+        original_status: u16,
+        (status, content_length): (u16, u64),
+        output_level: OutputLevel,
+    ) -> Self {
+        Self {
+            kind: bypass_type(),
+            url: variant.url.clone(),
+            original_url: original_url.to_string(),
+            method: method.to_string(),
+            technique: variant.technique.clone(),
+            headers: variant.headers.clone(),
+            status,
+            original_status,
+            content_length,
+            output_level,
+        }
+    }
+}
+
+/// FeroxSerialize implementation for BypassFinding
+impl FeroxSerialize for BypassFinding {
+    /// Create a string representation of the bypass
+    ///
+    /// ex: BYP      GET 200     1294c http://localhost/secret/x/../admin (/x/../path, was 403)
+    fn as_str(&self) -> String {
+        match self.output_level {
+            OutputLevel::Silent => format!("{}\n", self.url),
+            OutputLevel::SilentJSON => self.as_json().unwrap_or_default(),
+            _ => format!(
+                "{} {:>8} {} {:>8}c {} ({}, was {})\n",
+                style("BYP").bg(console::Color::Red).black(),
+                self.method,
+                status_colorizer(&self.status.to_string()),
+                self.content_length,
+                self.url,
+                self.technique,
+                self.original_status
+            ),
+        }
+    }
+
+    /// Create an NDJSON representation of the bypass
+    ///
+    /// (expanded for clarity)
+    /// ex:
+    /// {
+    ///   "type": "bypass",
+    ///   "url": "http://localhost/",
+    ///   "original_url": "http://localhost/secret/admin",
+    ///   "method": "GET",
+    ///   "technique": "X-Original-URL",
+    ///   "headers": {"X-Original-URL": "/secret/admin"},
+    ///   "status": 200,
+    ///   "original_status": 403,
+    ///   "content_length": 1294
+    /// }\n
+    fn as_json(&self) -> anyhow::Result<String> {
+        let mut json = serde_json::to_string(&self)
+            .with_context(|| fmt_err(&format!("Could not convert {} to JSON", self.url)))?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse_url_with_raw_path;
+
+    #[test]
+    /// each path mutation is built from the blocked url's last segment, and every variant is
+    /// sent exactly as it was built
+    fn bypass_variants_mutates_last_segment() {
+        let url = Url::parse("http://localhost/secret/admin?x=1").unwrap();
+        let variants = bypass_variants(&url);
+
+        let urls: Vec<_> = variants
+            .iter()
+            .map(|variant| variant.url.as_str())
+            .collect();
+
+        assert_eq!(
+            urls,
+            [
+                "http://localhost/secret/.;/admin?x=1",
+                "http://localhost/secret/x/../admin?x=1",
+                "http://localhost/secret//admin?x=1",
+                "http://localhost/secret/..;/admin?x=1",
+                "http://localhost/secret/ADMIN?x=1",
+                "http://localhost/secret/admin%20?x=1",
+                "http://localhost/secret/admin;?x=1",
+                "http://localhost/",
+                "http://localhost/",
+                "http://localhost/secret/admin?x=1",
+            ]
+        );
+
+        for variant in &variants {
+            assert_eq!(
+                parse_url_with_raw_path(&variant.url).unwrap().as_str(),
+                variant.url
+            );
+        }
+
+        assert!(variants[7].rewrites);
+        assert_eq!(variants[7].headers["X-Original-URL"], "/secret/admin?x=1");
+        assert_eq!(variants[9].headers["X-Forwarded-For"], "127.0.0.1");
+    }
+
+    #[test]
+    /// the site's root only gets header tricks, since there's no path to mutate
+    fn bypass_variants_root_only_uses_headers() {
+        let url = Url::parse("http://localhost/").unwrap();
+        let variants = bypass_variants(&url);
+
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].technique, "X-Forwarded-For");
+    }
+
+    #[test]
+    /// non-error status codes and large size changes are meaningful, nothing else is
+    fn differs_meaningfully_compares_status_then_size() {
+        assert!(differs_meaningfully((403, 100), (200, 100)));
+        assert!(differs_meaningfully((403, 100), (302, 0)));
+        assert!(!differs_meaningfully((403, 100), (404, 5000)));
+        assert!(!differs_meaningfully((403, 100), (403, 105)));
+        assert!(differs_meaningfully((403, 100), (403, 250)));
+        assert!(differs_meaningfully((403, 0), (403, 10)));
+    }
+}
@@ -1,16 +1,21 @@
 use super::utils::{
-    backup_extensions, depth, extract_links, ignored_extensions, methods, report_and_exit,
-    save_state, serialized_type, status_codes, threads, timeout, user_agent, wordlist, OutputLevel,
-    RequesterPolicy,
+    backup_extensions, depth, extension_map, extract_links, ignored_extensions, methods,
+    parse_raw_request, probe_methods, report_and_exit, report_warning, request_protocol,
+    save_state, secret_rules, serialized_type, status_codes, threads, timeout, user_agent,
+    wordlist, OutputLevel, RequesterPolicy, SecretRule, WordlistMode,
 };
 use crate::config::determine_output_level;
 use crate::config::utils::determine_requester_policy;
 use crate::{
-    client, parser,
+    client,
+    har::{read_har_file, same_origin_entries, session_headers},
+    parser,
+    rules::Rules,
     scan_manager::resume_scan,
     traits::FeroxSerialize,
     utils::{fmt_err, parse_url_with_raw_path},
-    DEFAULT_CONFIG_NAME,
+    wordlists::parse_wordlist_spec,
+    DEFAULT_CONFIG_NAME, FUZZ_KEYWORD,
 };
 use anyhow::{anyhow, Context, Result};
 use clap::{parser::ValueSource, ArgMatches};
@@ -88,10 +93,30 @@ pub struct Configuration {
     /// Name of this type of struct, used for serialization, i.e. `{"type":"configuration"}`
     pub kind: String,
 
-    /// Path to the wordlist
+    /// Path to the wordlist, optionally followed by :KEYWORD (i.e. dirs.txt:DIR)
     #[serde(default = "wordlist")]
     pub wordlist: String,
 
+    /// Paths to any wordlists beyond the first, each optionally followed by :KEYWORD
+    #[serde(default)]
+    pub additional_wordlists: Vec<String>,
+
+    /// How multiple wordlists are combined into a single keyspace (clusterbomb or pitchfork)
+    #[serde(default)]
+    pub wordlist_mode: WordlistMode,
+
+    /// keyword associated with each wordlist, cached by `Configuration::cache_keywords`
+    #[serde(skip)]
+    pub(crate) keywords: Vec<String>,
+
+    /// whether any wordlist keyword is used, cached by `Configuration::cache_keywords`
+    #[serde(skip)]
+    pub(crate) fuzzing: bool,
+
+    /// Rules used to mutate each word from the wordlist (i.e. `c`, `&[2019-2024]`)
+    #[serde(default)]
+    pub rules: Rules,
+
     /// Path to the config file used
     #[serde(default)]
     pub config: String,
@@ -120,6 +145,61 @@ pub struct Configuration {
     #[serde(default)]
     pub target_url: String,
 
+    /// Raw HTTP request file to use as a template for all requests made during the scan
+    #[serde(default)]
+    pub request_file: String,
+
+    /// Port scanner output (nmap XML, masscan/naabu JSON) from which to derive target urls
+    #[serde(default)]
+    pub targets_from: String,
+
+    /// HAR file whose requests seed the scan's targets and already-known responses
+    #[serde(default)]
+    pub har: String,
+
+    /// Reuse the request headers and cookies found in the HAR file
+    #[serde(default)]
+    pub har_headers: bool,
+
+    /// OpenAPI/Swagger specification to use instead of looking for one on the target
+    #[serde(default)]
+    pub openapi: String,
+
+    /// Request api endpoints with every method their specification declares, not only GET, HEAD
+    /// and OPTIONS
+    #[serde(default)]
+    pub openapi_methods: bool,
+
+    /// Request a curated set of /.well-known/ resources and the usual OpenAPI/Swagger
+    /// specification locations from each initial target before scanning it
+    #[serde(default)]
+    pub discover: bool,
+
+    /// Directory in which to save the original sources reconstructed from javascript source maps
+    #[serde(default)]
+    pub source_map_dir: String,
+
+    /// Wordlist of query parameter names to brute force against each endpoint found
+    #[serde(default)]
+    pub param_mine: String,
+
+    /// Enumerate virtual hosts by sending each word as the Host header to the target url
+    #[serde(default)]
+    pub vhost: bool,
+
+    /// Domain appended to each word in --vhost mode, i.e. WORD.example.com
+    #[serde(default)]
+    pub vhost_domain: String,
+
+    /// Check whether each target is case-insensitive, using the first resource found on it, and
+    /// skip words that only differ by case when it is
+    #[serde(default)]
+    pub detect_case: bool,
+
+    /// Protocol used to build the target URL from a raw request file (default: https)
+    #[serde(default = "request_protocol")]
+    pub protocol: String,
+
     /// Status Codes to include (allow list) (default: 200 204 301 302 307 308 401 403 405)
     #[serde(default = "status_codes")]
     pub status_codes: Vec<u16>,
@@ -189,6 +269,30 @@ pub struct Configuration {
     #[serde(default)]
     pub debug_log: String,
 
+    /// Directory in which to save the body of each reported response, mirroring host/path
+    #[serde(default)]
+    pub save_responses: String,
+
+    /// Save each response's headers next to its body when --save-responses is used
+    #[serde(default)]
+    pub save_headers: bool,
+
+    /// WARC file to archive the request and response of each reported finding to
+    #[serde(default)]
+    pub warc: String,
+
+    /// Archive every request made by the scanner to the WARC file, not only reported findings
+    #[serde(default)]
+    pub warc_all: bool,
+
+    /// HAR file to export reported findings to
+    #[serde(default)]
+    pub har_out: String,
+
+    /// Include each response's body in the entries of the --har-out file
+    #[serde(default)]
+    pub har_bodies: bool,
+
     /// Sets the User-Agent (default: feroxbuster/VERSION)
     #[serde(default = "user_agent")]
     pub user_agent: String,
@@ -314,6 +418,16 @@ pub struct Configuration {
     #[serde(default = "ignored_extensions")]
     pub dont_collect: Vec<String>,
 
+    /// Pick extensions to add to --extensions from each target's technology, based on its
+    /// headers, cookies and the file extensions seen in its responses
+    #[serde(default)]
+    pub auto_extensions: bool,
+
+    /// Technology markers (matched against Server and X-Powered-By values and Set-Cookie names)
+    /// mapped to the extensions --auto-extensions adds for them
+    #[serde(default = "extension_map")]
+    pub extension_map: HashMap<String, Vec<String>>,
+
     /// Automatically request likely backup extensions on "found" urls
     #[serde(default)]
     pub collect_backups: bool,
@@ -321,6 +435,37 @@ pub struct Configuration {
     #[serde(default = "backup_extensions")]
     pub backup_extensions: Vec<String>,
 
+    /// Probe "found" urls for the HTTP methods they allow, via OPTIONS, the methods in
+    /// `probe_methods`, and method-override headers
+    #[serde(default)]
+    pub discover_methods: bool,
+
+    /// Methods sent to "found" urls when --discover-methods is used
+    #[serde(default = "probe_methods")]
+    pub probe_methods: Vec<String>,
+
+    /// Probe 401/403 "found" urls with path mutations and header tricks that may bypass the
+    /// access control in front of them
+    #[serde(default)]
+    pub bypass_403: bool,
+
+    /// Match every response against the technology signature database and summarize what was
+    /// detected on each host
+    #[serde(default)]
+    pub fingerprint: bool,
+
+    /// TOML file of signatures used by --fingerprint in addition to the embedded ones
+    #[serde(default)]
+    pub fingerprint_signatures: String,
+
+    /// Scan every reported response's body for leaked secrets, i.e. keys, tokens and passwords
+    #[serde(default)]
+    pub secrets: bool,
+
+    /// Rules used by --secrets to find sensitive data in response bodies
+    #[serde(default = "secret_rules")]
+    pub secret_rules: Vec<SecretRule>,
+
     /// Automatically discover important words from within responses and add them to the wordlist
     #[serde(default)]
     pub collect_words: bool,
@@ -389,6 +534,12 @@ impl Default for Configuration {
             random_agent: false,
             collect_extensions: false,
             collect_backups: false,
+            discover_methods: false,
+            bypass_403: false,
+            fingerprint: false,
+            fingerprint_signatures: String::new(),
+            secrets: false,
+            secret_rules: secret_rules(),
             collect_words: false,
             save_state: true,
             force_recursion: false,
@@ -399,7 +550,26 @@ impl Default for Configuration {
             config: String::new(),
             output: String::new(),
             debug_log: String::new(),
+            save_responses: String::new(),
+            save_headers: false,
+            warc: String::new(),
+            warc_all: false,
+            har_out: String::new(),
+            har_bodies: false,
             target_url: String::new(),
+            request_file: String::new(),
+            targets_from: String::new(),
+            har: String::new(),
+            har_headers: false,
+            openapi: String::new(),
+            openapi_methods: false,
+            discover: false,
+            source_map_dir: String::new(),
+            param_mine: String::new(),
+            vhost: false,
+            vhost_domain: String::new(),
+            detect_case: false,
+            protocol: request_protocol(),
             time_limit: String::new(),
             resume_from: String::new(),
             replay_proxy: String::new(),
@@ -420,8 +590,16 @@ impl Default for Configuration {
             depth: depth(),
             threads: threads(),
             wordlist: wordlist(),
+            additional_wordlists: Vec::new(),
+            wordlist_mode: WordlistMode::default(),
+            keywords: vec![FUZZ_KEYWORD.to_string()],
+            fuzzing: false,
+            rules: Rules::default(),
             dont_collect: ignored_extensions(),
+            auto_extensions: false,
+            extension_map: extension_map(),
             backup_extensions: backup_extensions(),
+            probe_methods: probe_methods(),
         }
     }
 }
@@ -434,6 +612,9 @@ impl Configuration {
     /// - **redirects**: `false`
     /// - **extract_links**: `true`
     /// - **wordlist**: [`DEFAULT_WORDLIST`](constant.DEFAULT_WORDLIST.html)
+    /// - **additional_wordlists**: `None`
+    /// - **wordlist_mode**: `clusterbomb`
+    /// - **rules**: `None`
     /// - **config**: `None`
     /// - **threads**: `50`
     /// - **timeout**: `7` seconds
@@ -443,6 +624,12 @@ impl Configuration {
     /// - **filter_status**: `None`
     /// - **output**: `None` (print to stdout)
     /// - **debug_log**: `None`
+    /// - **save_responses**: `None`
+    /// - **save_headers**: `false`
+    /// - **warc**: `None`
+    /// - **warc_all**: `false`
+    /// - **har_out**: `None`
+    /// - **har_bodies**: `false`
     /// - **quiet**: `false`
     /// - **silent**: `false`
     /// - **auto_tune**: `false`
@@ -455,8 +642,17 @@ impl Configuration {
     /// - **collect_extensions**: `false`
     /// - **collect_backups**: `false`
     /// - **backup_extensions**: [`DEFAULT_BACKUP_EXTENSIONS`](constant.DEFAULT_BACKUP_EXTENSIONS.html)
+    /// - **discover_methods**: `false`
+    /// - **probe_methods**: [`DEFAULT_PROBE_METHODS`](constant.DEFAULT_PROBE_METHODS.html)
+    /// - **bypass_403**: `false`
+    /// - **fingerprint**: `false`
+    /// - **fingerprint_signatures**: `None`
+    /// - **secrets**: `false`
+    /// - **secret_rules**: [`DEFAULT_SECRET_RULES`](constant.DEFAULT_SECRET_RULES.html)
     /// - **collect_words**: `false`
     /// - **dont_collect**: [`DEFAULT_IGNORED_EXTENSIONS`](constant.DEFAULT_RESPONSE_CODES.html)
+    /// - **auto_extensions**: `false`
+    /// - **extension_map**: [`DEFAULT_EXTENSION_MAP`](constant.DEFAULT_EXTENSION_MAP.html)
     /// - **methods**: [`DEFAULT_METHOD`](constant.DEFAULT_METHOD.html)
     /// - **data**: `None`
     /// - **url_denylist**: `None`
@@ -482,6 +678,19 @@ impl Configuration {
     /// - **replay_proxy**: `None` (no limit on concurrent scans imposed)
     /// - **replay_codes**: [`DEFAULT_RESPONSE_CODES`](constant.DEFAULT_RESPONSE_CODES.html)
     /// - **update_app**: `false`
+    /// - **request_file**: `None`
+    /// - **targets_from**: `None`
+    /// - **har**: `None`
+    /// - **har_headers**: `false`
+    /// - **openapi**: `None`
+    /// - **openapi_methods**: `false`
+    /// - **discover**: `false`
+    /// - **source_map_dir**: `None`
+    /// - **param_mine**: `None`
+    /// - **vhost**: `false`
+    /// - **vhost_domain**: `None`
+    /// - **detect_case**: `false`
+    /// - **protocol**: `https`
     ///
     /// After which, any values defined in a
     /// [ferox-config.toml](constant.DEFAULT_CONFIG_NAME.html) config file will override the
@@ -545,6 +754,9 @@ impl Configuration {
             // not flipped to false, the program hangs waiting for input from stdin again)
             previous_config.stdin = false;
 
+            // neither are the cached keywords
+            previous_config.cache_keywords();
+
             // clients aren't serialized, have to remake them from the previous config
             Self::try_rebuild_clients(&mut previous_config);
 
@@ -555,6 +767,37 @@ impl Configuration {
         // merge the cli options into the config file options and return the result
         Self::merge_config(&mut config, cli_config);
 
+        // a raw request file fills in whatever the config file(s) and cli didn't explicitly set;
+        // resumed scans skip this step, as the parsed values were already saved to the state file
+        Self::parse_request_file(&mut config, came_from_cli!(args, "methods"))?;
+
+        // same goes for the headers/cookies captured in a HAR file, when asked for
+        Self::parse_har_headers(&mut config)?;
+
+        // keywords are looked up for every request, so they're parsed from the wordlist specs once
+        config.cache_keywords();
+
+        if config.uses_fuzz_keyword() && !config.no_recursion {
+            // words are placed at the keyword instead of being appended to the target url, so
+            // recursing into discovered directories would just replay the same requests
+            config.no_recursion = true;
+
+            if config.output_level != OutputLevel::Silent {
+                report_warning(
+                    "the target uses a wordlist keyword (i.e. FUZZ), recursion is disabled",
+                );
+            }
+        }
+
+        if config.vhost {
+            // every request goes to the same url, only the Host header changes; recursion,
+            // link extraction, and backup collection all build new urls from the responses,
+            // which don't mean anything here
+            config.no_recursion = true;
+            config.extract_links = false;
+            config.collect_backups = false;
+        }
+
         // rebuild clients is the last step in either code branch
         Self::try_rebuild_clients(&mut config);
 
@@ -616,10 +859,44 @@ impl Configuration {
         update_config_with_num_type_if_present!(&mut config.depth, args, "depth", usize);
         update_config_with_num_type_if_present!(&mut config.scan_limit, args, "scan_limit", usize);
         update_config_with_num_type_if_present!(&mut config.rate_limit, args, "rate_limit", usize);
-        update_config_if_present!(&mut config.wordlist, args, "wordlist", String);
+        if let Some(arg) = args.get_many::<String>("wordlist") {
+            // the first wordlist is the primary one; any others are combined with it according
+            // to --wordlist-mode
+            let mut wordlists = arg.map(|val| val.to_string());
+
+            if let Some(first) = wordlists.next() {
+                config.wordlist = first;
+            }
+
+            config.additional_wordlists = wordlists.collect();
+        }
+
+        if let Some(arg) = args.get_one::<String>("rules") {
+            let contents = read_to_string(arg).unwrap_or_else(|e| report_and_exit(&e.to_string()));
+            let lines: Vec<&str> = contents.lines().collect();
+
+            config.rules = Rules::parse(&lines).unwrap_or_else(|e| report_and_exit(&e.to_string()));
+        }
+
+        if let Some(arg) = args.get_one::<String>("wordlist_mode") {
+            config.wordlist_mode = match arg.as_str() {
+                "pitchfork" => WordlistMode::Pitchfork,
+                _ => WordlistMode::Clusterbomb,
+            };
+        }
         update_config_if_present!(&mut config.output, args, "output", String);
         update_config_if_present!(&mut config.debug_log, args, "debug_log", String);
+        update_config_if_present!(&mut config.save_responses, args, "save_responses", String);
+        update_config_if_present!(&mut config.warc, args, "warc", String);
+        update_config_if_present!(&mut config.har_out, args, "har_out", String);
         update_config_if_present!(&mut config.resume_from, args, "resume_from", String);
+        update_config_if_present!(&mut config.request_file, args, "request_file", String);
+        update_config_if_present!(&mut config.targets_from, args, "targets_from", String);
+        update_config_if_present!(&mut config.har, args, "har", String);
+        update_config_if_present!(&mut config.openapi, args, "openapi", String);
+        update_config_if_present!(&mut config.source_map_dir, args, "source_map_dir", String);
+        update_config_if_present!(&mut config.param_mine, args, "param_mine", String);
+        update_config_if_present!(&mut config.protocol, args, "protocol", String);
 
         if let Ok(Some(inner)) = args.try_get_one::<String>("time_limit") {
             config.time_limit = inner.to_owned();
@@ -708,6 +985,30 @@ impl Configuration {
             }
         }
 
+        if came_from_cli!(args, "har_headers") {
+            config.har_headers = true;
+        }
+
+        if came_from_cli!(args, "openapi_methods") {
+            config.openapi_methods = true;
+        }
+
+        if came_from_cli!(args, "discover") {
+            config.discover = true;
+        }
+
+        if came_from_cli!(args, "vhost") {
+            config.vhost = true;
+
+            if let Some(domain) = args.get_one::<String>("vhost") {
+                config.vhost_domain = domain.trim().trim_matches('.').to_string();
+            }
+        }
+
+        if came_from_cli!(args, "detect_case") {
+            config.detect_case = true;
+        }
+
         if came_from_cli!(args, "stdin") {
             config.stdin = true;
         } else if let Some(url) = args.get_one::<String>("url") {
@@ -836,6 +1137,10 @@ impl Configuration {
             config.collect_extensions = true;
         }
 
+        if came_from_cli!(args, "auto_extensions") {
+            config.auto_extensions = true;
+        }
+
         if came_from_cli!(args, "collect_backups")
             || came_from_cli!(args, "smart")
             || came_from_cli!(args, "thorough")
@@ -857,6 +1162,50 @@ impl Configuration {
             }
         }
 
+        if came_from_cli!(args, "discover_methods") {
+            config.discover_methods = true;
+            config.probe_methods = probe_methods();
+
+            if let Some(arg) = args.get_many::<String>("discover_methods") {
+                let methods = arg
+                    .map(|method| method.trim().to_uppercase())
+                    .collect::<Vec<String>>();
+
+                if !methods.is_empty() {
+                    // have at least one cli method, override the defaults
+                    config.probe_methods = methods;
+                }
+            }
+        }
+
+        if came_from_cli!(args, "bypass_403") {
+            config.bypass_403 = true;
+        }
+
+        if came_from_cli!(args, "fingerprint") {
+            config.fingerprint = true;
+
+            if let Some(signatures) = args.get_one::<String>("fingerprint") {
+                config.fingerprint_signatures = signatures.to_string();
+            }
+        }
+
+        if came_from_cli!(args, "secrets") {
+            config.secrets = true;
+        }
+
+        if came_from_cli!(args, "save_headers") {
+            config.save_headers = true;
+        }
+
+        if came_from_cli!(args, "warc_all") {
+            config.warc_all = true;
+        }
+
+        if came_from_cli!(args, "har_bodies") {
+            config.har_bodies = true;
+        }
+
         if came_from_cli!(args, "collect_words")
             || came_from_cli!(args, "smart")
             || came_from_cli!(args, "thorough")
@@ -1051,6 +1400,203 @@ impl Configuration {
         }
     }
 
+    /// whether reported responses need to reach the output handlers with their bodies, i.e. to
+    /// be saved by --save-responses, archived by --warc, exported by --har-out --har-bodies or
+    /// scanned by --secrets
+    pub fn keep_response_bodies(&self) -> bool {
+        !self.save_responses.is_empty() || !self.warc.is_empty() || self.har_bodies || self.secrets
+    }
+
+    /// whether responses with the given status code are reported, according to -C when it was
+    /// used and -s otherwise
+    pub fn reports_status(&self, status: u16) -> bool {
+        if !self.filter_status.is_empty() {
+            // -C indicates that we should filter that status code, but allow all others
+            // https://github.com/epi052/feroxbuster/issues/535
+            !self.filter_status.contains(&status)
+        } else {
+            self.status_codes.contains(&status)
+        }
+    }
+
+    /// parse the keyword of each wordlist spec and check whether any of them is used, caching
+    /// the results for `keywords` and `uses_fuzz_keyword`; needs to be called again whenever the
+    /// wordlists, target url, headers, queries or body change
+    pub fn cache_keywords(&mut self) {
+        self.keywords = std::iter::once(&self.wordlist)
+            .chain(self.additional_wordlists.iter())
+            .map(|spec| parse_wordlist_spec(spec).1.to_string())
+            .collect();
+
+        self.fuzzing = self
+            .keywords
+            .iter()
+            .any(|keyword| self.uses_keyword(keyword));
+    }
+
+    /// the keyword associated with each wordlist, in the order the wordlists were given
+    ///
+    /// wordlists given without a :KEYWORD suffix use the FUZZ keyword
+    pub fn keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// whether or not the given keyword was placed in the target url, a header value, a query or
+    /// the request body
+    pub fn uses_keyword(&self, keyword: &str) -> bool {
+        self.target_url.contains(keyword)
+            || self.headers.values().any(|value| value.contains(keyword))
+            || self
+                .queries
+                .iter()
+                .any(|(name, value)| name.contains(keyword) || value.contains(keyword))
+            || self
+                .data
+                .windows(keyword.len())
+                .any(|window| window == keyword.as_bytes())
+    }
+
+    /// whether or not any wordlist keyword (FUZZ by default) was placed in the target url, a
+    /// header value, a query or the request body
+    pub fn uses_fuzz_keyword(&self) -> bool {
+        self.fuzzing
+    }
+
+    /// whether or not any wordlist keyword was placed in the given target url; targets read from
+    /// --stdin or --targets-from aren't part of the configuration, so they're checked separately
+    pub fn is_keyword_target(&self, url: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|keyword| url.contains(keyword.as_str()))
+    }
+
+    /// the Host header value a word is sent with in --vhost mode; words are used as-is unless a
+    /// domain was given, in which case they become subdomains of it
+    pub fn vhost_for(&self, word: &str) -> String {
+        if self.vhost_domain.is_empty() {
+            word.to_string()
+        } else {
+            format!("{word}.{}", self.vhost_domain)
+        }
+    }
+
+    /// if a raw request file was given, read it in and use its method, url, queries, headers and
+    /// body as the template for every request made during the scan
+    ///
+    /// values explicitly set via the config file(s) or command line take precedence over those
+    /// found in the request file; `methods_from_cli` tells whether -m was used, as an explicit
+    /// `-m GET` can't be told apart from the default otherwise
+    ///
+    /// repeated headers are combined the way HTTP allows (`; ` for Cookie, `, ` for the rest)
+    pub(super) fn parse_request_file(config: &mut Self, methods_from_cli: bool) -> Result<()> {
+        if config.request_file.is_empty() {
+            return Ok(());
+        }
+
+        let raw = std::fs::read(&config.request_file)
+            .with_context(|| format!("Could not read request file {}", config.request_file))?;
+
+        let request = parse_raw_request(&raw, &config.protocol)
+            .with_context(|| format!("Could not parse request file {}", config.request_file))?;
+
+        if config.target_url.is_empty() {
+            config.target_url = request.target_url;
+        }
+
+        if !methods_from_cli && config.methods == methods() {
+            let method = Method::from_bytes(request.method.as_bytes())
+                .with_context(|| format!("Invalid method in request file: {}", request.method))?;
+            config.methods = vec![method.as_str().to_string()];
+        }
+
+        if config.data.is_empty() {
+            config.data = request.body;
+        }
+
+        for (name, value) in request.queries {
+            if !config.queries.iter().any(|(known, _)| *known == name) {
+                config.queries.push((name, value));
+            }
+        }
+
+        // names of the headers taken from the request file, so that repeats of them can be
+        // told apart from headers that were explicitly set elsewhere
+        let mut from_file: Vec<String> = Vec::new();
+
+        for (name, value) in request.headers {
+            if name.eq_ignore_ascii_case("user-agent") {
+                // the user agent is handled by the client builder, not as a default header
+                if config.user_agent == user_agent() {
+                    config.user_agent = value;
+                }
+                continue;
+            }
+
+            if let Some(known) = from_file
+                .iter()
+                .find(|known| known.eq_ignore_ascii_case(&name))
+            {
+                let separator = if name.eq_ignore_ascii_case("cookie") {
+                    "; "
+                } else {
+                    ", "
+                };
+
+                if let Some(combined) = config.headers.get_mut(known) {
+                    combined.push_str(separator);
+                    combined.push_str(&value);
+                }
+                continue;
+            }
+
+            if !config
+                .headers
+                .keys()
+                .any(|known| known.eq_ignore_ascii_case(&name))
+            {
+                from_file.push(name.clone());
+                config.headers.insert(name, value);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the HAR file specified by `--har` and use its session headers (authorization,
+    /// cookies, etc...) for the scan when `--har-headers` was used
+    ///
+    /// only requests made to the target's origin are considered, see `same_origin_entries`
+    ///
+    /// headers explicitly set via the config file(s) or command line take precedence over those
+    /// found in the HAR file
+    pub(super) fn parse_har_headers(config: &mut Self) -> Result<()> {
+        if config.har.is_empty() || !config.har_headers {
+            return Ok(());
+        }
+
+        let entries = same_origin_entries(read_har_file(&config.har)?, &config.target_url);
+
+        for (name, value) in session_headers(&entries) {
+            if name.eq_ignore_ascii_case("user-agent") {
+                // the user agent is handled by the client builder, not as a default header
+                if config.user_agent == user_agent() {
+                    config.user_agent = value;
+                }
+                continue;
+            }
+
+            if !config
+                .headers
+                .keys()
+                .any(|known| known.eq_ignore_ascii_case(&name))
+            {
+                config.headers.insert(name, value);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Given a configuration file's location and an instance of `Configuration`, read in
     /// the config file if found and update the current settings with the settings found therein
     fn parse_and_merge_config(config_file: PathBuf, config: &mut Self) -> Result<()> {
@@ -1077,6 +1623,19 @@ impl Configuration {
         //  - resumed
         //  - config
         update_if_not_default!(&mut conf.target_url, new.target_url, "");
+        update_if_not_default!(&mut conf.request_file, new.request_file, "");
+        update_if_not_default!(&mut conf.targets_from, new.targets_from, "");
+        update_if_not_default!(&mut conf.har, new.har, "");
+        update_if_not_default!(&mut conf.har_headers, new.har_headers, false);
+        update_if_not_default!(&mut conf.openapi, new.openapi, "");
+        update_if_not_default!(&mut conf.openapi_methods, new.openapi_methods, false);
+        update_if_not_default!(&mut conf.discover, new.discover, false);
+        update_if_not_default!(&mut conf.source_map_dir, new.source_map_dir, "");
+        update_if_not_default!(&mut conf.param_mine, new.param_mine, "");
+        update_if_not_default!(&mut conf.vhost, new.vhost, false);
+        update_if_not_default!(&mut conf.vhost_domain, new.vhost_domain, "");
+        update_if_not_default!(&mut conf.detect_case, new.detect_case, false);
+        update_if_not_default!(&mut conf.protocol, new.protocol, request_protocol());
         update_if_not_default!(&mut conf.time_limit, new.time_limit, "");
         update_if_not_default!(&mut conf.proxy, new.proxy, "");
         update_if_not_default!(
@@ -1093,7 +1652,18 @@ impl Configuration {
         update_if_not_default!(&mut conf.auto_bail, new.auto_bail, false);
         update_if_not_default!(&mut conf.auto_tune, new.auto_tune, false);
         update_if_not_default!(&mut conf.collect_extensions, new.collect_extensions, false);
+        update_if_not_default!(&mut conf.auto_extensions, new.auto_extensions, false);
         update_if_not_default!(&mut conf.collect_backups, new.collect_backups, false);
+        update_if_not_default!(&mut conf.discover_methods, new.discover_methods, false);
+        update_if_not_default!(&mut conf.bypass_403, new.bypass_403, false);
+        update_if_not_default!(&mut conf.fingerprint, new.fingerprint, false);
+        update_if_not_default!(
+            &mut conf.fingerprint_signatures,
+            new.fingerprint_signatures,
+            ""
+        );
+        update_if_not_default!(&mut conf.secrets, new.secrets, false);
+        update_if_not_default!(&mut conf.secret_rules, new.secret_rules, secret_rules());
         update_if_not_default!(&mut conf.collect_words, new.collect_words, false);
         // use updated quiet/silent values to determine output level; same for requester policy
         conf.output_level = determine_output_level(conf.quiet, conf.silent, conf.json);
@@ -1153,6 +1723,12 @@ impl Configuration {
         update_if_not_default!(&mut conf.rate_limit, new.rate_limit, 0);
         update_if_not_default!(&mut conf.replay_proxy, new.replay_proxy, "");
         update_if_not_default!(&mut conf.debug_log, new.debug_log, "");
+        update_if_not_default!(&mut conf.save_responses, new.save_responses, "");
+        update_if_not_default!(&mut conf.save_headers, new.save_headers, false);
+        update_if_not_default!(&mut conf.warc, new.warc, "");
+        update_if_not_default!(&mut conf.warc_all, new.warc_all, false);
+        update_if_not_default!(&mut conf.har_out, new.har_out, "");
+        update_if_not_default!(&mut conf.har_bodies, new.har_bodies, false);
         update_if_not_default!(&mut conf.resume_from, new.resume_from, "");
 
         update_if_not_default!(&mut conf.timeout, new.timeout, timeout());
@@ -1162,10 +1738,22 @@ impl Configuration {
             new.backup_extensions,
             backup_extensions()
         );
+        update_if_not_default!(&mut conf.probe_methods, new.probe_methods, probe_methods());
         update_if_not_default!(&mut conf.random_agent, new.random_agent, false);
         update_if_not_default!(&mut conf.threads, new.threads, threads());
         update_if_not_default!(&mut conf.depth, new.depth, depth());
         update_if_not_default!(&mut conf.wordlist, new.wordlist, wordlist());
+        update_if_not_default!(
+            &mut conf.additional_wordlists,
+            new.additional_wordlists,
+            Vec::<String>::new()
+        );
+        update_if_not_default!(&mut conf.rules, new.rules, Rules::default());
+        update_if_not_default!(
+            &mut conf.wordlist_mode,
+            new.wordlist_mode,
+            WordlistMode::default()
+        );
         update_if_not_default!(&mut conf.status_codes, new.status_codes, status_codes());
         // status_codes() is the default for replay_codes, if they're not provided
         update_if_not_default!(&mut conf.replay_codes, new.replay_codes, status_codes());
@@ -1175,6 +1763,7 @@ impl Configuration {
             new.dont_collect,
             ignored_extensions()
         );
+        update_if_not_default!(&mut conf.extension_map, new.extension_map, extension_map());
     }
 
     /// If present, read in `DEFAULT_CONFIG_NAME` and deserialize the specified values
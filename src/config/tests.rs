@@ -27,6 +27,12 @@ fn setup_config_test() -> Configuration {
             time_limit = "10m"
             output = "/some/otherpath"
             debug_log = "/yet/anotherpath"
+            save_responses = "/some/responses"
+            save_headers = true
+            warc = "/some/archive.warc"
+            warc_all = true
+            har_out = "/some/findings.har"
+            har_bodies = true
             resume_from = "/some/state/file"
             redirects = true
             insecure = true
@@ -35,6 +41,8 @@ fn setup_config_test() -> Configuration {
             collect_words = true
             extensions = ["html", "php", "js"]
             dont_collect = ["png", "gif", "jpg", "jpeg"]
+            auto_extensions = true
+            extension_map = {express = ["js"]}
             methods = ["GET", "PUT", "DELETE"]
             data = [31, 32, 33, 34]
             url_denylist = ["http://dont-scan.me", "https://also-not.me"]
@@ -60,6 +68,29 @@ fn setup_config_test() -> Configuration {
             client_cert = "/some/client/cert.pem"
             client_key = "/some/client/key.pem"
             backup_extensions = [".save"]
+            discover_methods = true
+            probe_methods = ["PUT", "PROPFIND"]
+            bypass_403 = true
+            fingerprint = true
+            fingerprint_signatures = "/some/signatures.toml"
+            secrets = true
+            secret_rules = [{id = "internal-token", regex = "itk_[a-z0-9]{32}"}, {id = "high-entropy", min_entropy = 4.5}]
+            request_file = "/some/request/file"
+            targets_from = "/some/nmap.xml"
+            har = "/some/session.har"
+            har_headers = true
+            openapi = "/some/openapi.yaml"
+            openapi_methods = true
+            discover = true
+            source_map_dir = "/some/sources"
+            param_mine = "/some/params.txt"
+            vhost = true
+            vhost_domain = "example.com"
+            detect_case = true
+            protocol = "http"
+            additional_wordlists = ["/some/versions:VER"]
+            wordlist_mode = "pitchfork"
+            rules = [":", "c &[2019-2020]"]
         "#;
     let tmp_dir = TempDir::new().unwrap();
     let file = tmp_dir.path().join(DEFAULT_CONFIG_NAME);
@@ -77,6 +108,12 @@ fn default_configuration() {
     assert_eq!(config.time_limit, String::new());
     assert_eq!(config.resume_from, String::new());
     assert_eq!(config.debug_log, String::new());
+    assert_eq!(config.save_responses, String::new());
+    assert!(!config.save_headers);
+    assert_eq!(config.warc, String::new());
+    assert!(!config.warc_all);
+    assert_eq!(config.har_out, String::new());
+    assert!(!config.har_bodies);
     assert_eq!(config.config, String::new());
     assert_eq!(config.replay_proxy, String::new());
     assert_eq!(config.status_codes, status_codes());
@@ -115,6 +152,8 @@ fn default_configuration() {
     assert_eq!(config.data, Vec::<u8>::new());
     assert_eq!(config.url_denylist, Vec::<Url>::new());
     assert_eq!(config.dont_collect, ignored_extensions());
+    assert!(!config.auto_extensions);
+    assert_eq!(config.extension_map, extension_map());
     assert_eq!(config.filter_regex, Vec::<String>::new());
     assert_eq!(config.filter_similar, Vec::<String>::new());
     assert_eq!(config.filter_word_count, Vec::<usize>::new());
@@ -125,6 +164,29 @@ fn default_configuration() {
     assert_eq!(config.client_cert, String::new());
     assert_eq!(config.client_key, String::new());
     assert_eq!(config.backup_extensions, backup_extensions());
+    assert!(!config.discover_methods);
+    assert_eq!(config.probe_methods, probe_methods());
+    assert!(!config.bypass_403);
+    assert!(!config.fingerprint);
+    assert_eq!(config.fingerprint_signatures, String::new());
+    assert!(!config.secrets);
+    assert_eq!(config.secret_rules, secret_rules());
+    assert_eq!(config.request_file, String::new());
+    assert_eq!(config.targets_from, String::new());
+    assert_eq!(config.har, String::new());
+    assert!(!config.har_headers);
+    assert_eq!(config.openapi, String::new());
+    assert!(!config.openapi_methods);
+    assert!(!config.discover);
+    assert_eq!(config.source_map_dir, String::new());
+    assert_eq!(config.param_mine, String::new());
+    assert!(!config.vhost);
+    assert_eq!(config.vhost_domain, String::new());
+    assert!(!config.detect_case);
+    assert_eq!(config.protocol, request_protocol());
+    assert!(config.additional_wordlists.is_empty());
+    assert_eq!(config.wordlist_mode, WordlistMode::Clusterbomb);
+    assert!(config.rules.is_empty());
 }
 
 #[test]
@@ -141,6 +203,48 @@ fn config_reads_debug_log() {
     assert_eq!(config.debug_log, "/yet/anotherpath");
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_save_responses() {
+    let config = setup_config_test();
+    assert_eq!(config.save_responses, "/some/responses");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_save_headers() {
+    let config = setup_config_test();
+    assert!(config.save_headers);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_warc() {
+    let config = setup_config_test();
+    assert_eq!(config.warc, "/some/archive.warc");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_warc_all() {
+    let config = setup_config_test();
+    assert!(config.warc_all);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_har_out() {
+    let config = setup_config_test();
+    assert_eq!(config.har_out, "/some/findings.har");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_har_bodies() {
+    let config = setup_config_test();
+    assert!(config.har_bodies);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_status_codes() {
@@ -351,6 +455,21 @@ fn config_reads_dont_collect() {
     assert_eq!(config.dont_collect, vec!["png", "gif", "jpg", "jpeg"]);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_auto_extensions() {
+    let config = setup_config_test();
+    assert!(config.auto_extensions);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_extension_map() {
+    let config = setup_config_test();
+    assert_eq!(config.extension_map.len(), 1);
+    assert_eq!(config.extension_map["express"], ["js"]);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_methods() {
@@ -468,6 +587,69 @@ fn config_reads_backup_extensions() {
     assert_eq!(config.backup_extensions, [".save"]);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_discover_methods() {
+    let config = setup_config_test();
+    assert!(config.discover_methods);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_probe_methods() {
+    let config = setup_config_test();
+    assert_eq!(config.probe_methods, ["PUT", "PROPFIND"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_bypass_403() {
+    let config = setup_config_test();
+    assert!(config.bypass_403);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_fingerprint() {
+    let config = setup_config_test();
+    assert!(config.fingerprint);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_fingerprint_signatures() {
+    let config = setup_config_test();
+    assert_eq!(config.fingerprint_signatures, "/some/signatures.toml");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_secrets() {
+    let config = setup_config_test();
+    assert!(config.secrets);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_secret_rules() {
+    let config = setup_config_test();
+    assert_eq!(
+        config.secret_rules,
+        vec![
+            SecretRule {
+                id: String::from("internal-token"),
+                regex: String::from("itk_[a-z0-9]{32}"),
+                min_entropy: 0.0,
+            },
+            SecretRule {
+                id: String::from("high-entropy"),
+                regex: String::new(),
+                min_entropy: 4.5,
+            },
+        ]
+    );
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_client_cert() {
@@ -503,6 +685,264 @@ fn config_reads_queries() {
     assert_eq!(config.queries, queries);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_additional_wordlists() {
+    let config = setup_config_test();
+    assert_eq!(config.additional_wordlists, vec!["/some/versions:VER"]);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_wordlist_mode() {
+    let config = setup_config_test();
+    assert_eq!(config.wordlist_mode, WordlistMode::Pitchfork);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_rules() {
+    let config = setup_config_test();
+    assert_eq!(
+        config.rules,
+        crate::rules::Rules::parse(&[":", "c &[2019-2020]"]).unwrap()
+    );
+    assert_eq!(
+        config.rules.expand("admin").collect::<Vec<_>>(),
+        vec!["admin", "Admin2019", "Admin2020"]
+    );
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_request_file() {
+    let config = setup_config_test();
+    assert_eq!(config.request_file, "/some/request/file");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_targets_from() {
+    let config = setup_config_test();
+    assert_eq!(config.targets_from, "/some/nmap.xml");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_har() {
+    let config = setup_config_test();
+    assert_eq!(config.har, "/some/session.har");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_har_headers() {
+    let config = setup_config_test();
+    assert!(config.har_headers);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_openapi() {
+    let config = setup_config_test();
+    assert_eq!(config.openapi, "/some/openapi.yaml");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_openapi_methods() {
+    let config = setup_config_test();
+    assert!(config.openapi_methods);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_discover() {
+    let config = setup_config_test();
+    assert!(config.discover);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_source_map_dir() {
+    let config = setup_config_test();
+    assert_eq!(config.source_map_dir, "/some/sources");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_param_mine() {
+    let config = setup_config_test();
+    assert_eq!(config.param_mine, "/some/params.txt");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_vhost() {
+    let config = setup_config_test();
+    assert!(config.vhost);
+    assert_eq!(config.vhost_domain, "example.com");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_detect_case() {
+    let config = setup_config_test();
+    assert!(config.detect_case);
+}
+
+#[test]
+/// words are used as-is without a vhost domain and become subdomains of it otherwise
+fn config_vhost_for_appends_domain() {
+    let mut config = Configuration::new().unwrap();
+    assert_eq!(config.vhost_for("admin"), "admin");
+
+    config.vhost_domain = String::from("example.com");
+    assert_eq!(config.vhost_for("admin"), "admin.example.com");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_protocol() {
+    let config = setup_config_test();
+    assert_eq!(config.protocol, "http");
+}
+
+#[test]
+/// a raw request file should populate the request template, without overriding values that
+/// were explicitly set elsewhere
+fn parse_request_file_populates_unset_values() {
+    let tmp_dir = TempDir::new().unwrap();
+    let file = tmp_dir.path().join("request.txt");
+    write(
+        &file,
+        "PUT /api/?stuff=things HTTP/1.1\r\nHost: localhost\r\nUser-Agent: burp\r\nCookie: a=b\r\nX-Stuff: from-file\r\n\r\nbody",
+    )
+    .unwrap();
+
+    let mut config = Configuration {
+        request_file: file.to_string_lossy().to_string(),
+        protocol: String::from("http"),
+        ..Default::default()
+    };
+    config
+        .headers
+        .insert(String::from("x-stuff"), String::from("from-cli"));
+
+    Configuration::parse_request_file(&mut config, false).unwrap();
+
+    assert_eq!(config.target_url, "http://localhost/api/");
+    assert_eq!(config.methods, vec!["PUT"]);
+    assert_eq!(config.data, b"body");
+    assert_eq!(config.user_agent, "burp");
+    assert_eq!(
+        config.queries,
+        vec![(String::from("stuff"), String::from("things"))]
+    );
+    assert_eq!(config.headers.get("Cookie").unwrap(), "a=b");
+    assert_eq!(config.headers.get("x-stuff").unwrap(), "from-cli");
+    assert!(!config.headers.contains_key("X-Stuff"));
+}
+
+#[test]
+/// a missing request file should result in an error
+fn parse_request_file_errors_on_missing_file() {
+    let mut config = Configuration {
+        request_file: String::from("/definitely/doesnt/exist/request.txt"),
+        ..Default::default()
+    };
+
+    assert!(Configuration::parse_request_file(&mut config, false).is_err());
+}
+
+#[test]
+/// an explicit -m GET wins over the request file's method, and repeated headers from the
+/// request file are combined instead of dropped
+fn parse_request_file_keeps_cli_method_and_repeated_headers() {
+    let tmp_dir = TempDir::new().unwrap();
+    let file = tmp_dir.path().join("request.txt");
+    write(
+        &file,
+        "POST /api/ HTTP/1.1\r\nHost: localhost\r\nCookie: a=b\r\nCookie: c=d\r\nAccept: text/html\r\naccept: application/json\r\n\r\n",
+    )
+    .unwrap();
+
+    let mut config = Configuration {
+        request_file: file.to_string_lossy().to_string(),
+        protocol: String::from("http"),
+        ..Default::default()
+    };
+
+    Configuration::parse_request_file(&mut config, true).unwrap();
+
+    assert_eq!(config.methods, vec!["GET"]);
+    assert_eq!(config.headers.get("Cookie").unwrap(), "a=b; c=d");
+    assert_eq!(
+        config.headers.get("Accept").unwrap(),
+        "text/html, application/json"
+    );
+}
+
+#[test]
+/// the FUZZ keyword should be detected in the url, header values, queries and body
+fn uses_fuzz_keyword_checks_all_locations() {
+    let mut config = Configuration::default();
+    assert!(!config.uses_fuzz_keyword());
+
+    config.target_url = String::from("http://localhost/FUZZ/stuff");
+    config.cache_keywords();
+    assert!(config.uses_fuzz_keyword());
+
+    config.target_url = String::from("http://localhost");
+    config
+        .headers
+        .insert(String::from("X-Stuff"), String::from("FUZZ"));
+    config.cache_keywords();
+    assert!(config.uses_fuzz_keyword());
+
+    config.headers.clear();
+    config.queries = vec![(String::from("stuff"), String::from("FUZZ"))];
+    config.cache_keywords();
+    assert!(config.uses_fuzz_keyword());
+
+    config.queries.clear();
+    config.data = b"stuff=FUZZ".to_vec();
+    config.cache_keywords();
+    assert!(config.uses_fuzz_keyword());
+
+    config.data.clear();
+    config.cache_keywords();
+    assert!(!config.uses_fuzz_keyword());
+}
+
+#[test]
+/// each wordlist's keyword comes from its spec, defaulting to FUZZ
+fn keywords_are_parsed_from_wordlist_specs() {
+    let mut config = Configuration::default();
+    assert_eq!(config.keywords(), vec!["FUZZ"]);
+
+    config.wordlist = String::from("/some/dirs:DIR");
+    config.additional_wordlists = vec![String::from("/some/versions:VER")];
+    config.cache_keywords();
+    assert_eq!(config.keywords(), vec!["DIR", "VER"]);
+
+    config.target_url = String::from("http://localhost/DIR");
+    config.cache_keywords();
+    assert!(config.uses_keyword("DIR"));
+    assert!(!config.uses_keyword("VER"));
+    assert!(config.uses_fuzz_keyword());
+}
+
+#[test]
+/// targets that aren't the configured target url (i.e. from --stdin) are checked on their own
+fn is_keyword_target_checks_given_url() {
+    let config = Configuration::default();
+
+    assert!(config.is_keyword_target("http://localhost/FUZZ/stuff"));
+    assert!(!config.is_keyword_target("http://localhost/stuff"));
+    assert!(!config.uses_fuzz_keyword());
+}
+
 #[test]
 fn config_default_not_random_agent() {
     let config = setup_config_test();
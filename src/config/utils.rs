@@ -1,8 +1,12 @@
 use crate::{
     utils::{module_colorizer, status_colorizer},
-    DEFAULT_BACKUP_EXTENSIONS, DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD, DEFAULT_STATUS_CODES,
-    DEFAULT_WORDLIST, VERSION,
+    DEFAULT_BACKUP_EXTENSIONS, DEFAULT_EXTENSION_MAP, DEFAULT_IGNORED_EXTENSIONS, DEFAULT_METHOD,
+    DEFAULT_PROBE_METHODS, DEFAULT_SECRET_RULES, DEFAULT_STATUS_CODES, DEFAULT_WORDLIST, VERSION,
 };
+use anyhow::{anyhow, bail, Result};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 #[cfg(not(test))]
 use std::process::exit;
 
@@ -21,6 +25,17 @@ pub(super) fn report_and_exit(err: &str) -> ! {
     exit(1);
 }
 
+/// simple helper to tell the user about a setting that was changed for them; the logger isn't
+/// running yet while the configuration is built, so this goes straight to stderr
+pub(super) fn report_warning(msg: &str) {
+    eprintln!(
+        "{} {}: {}",
+        status_colorizer("WARNING"),
+        module_colorizer("Configuration::new"),
+        msg
+    );
+}
+
 // functions timeout, threads, status_codes, user_agent, wordlist, save_state, and depth are used to provide
 // defaults in the event that a ferox-config.toml is found but one or more of the values below
 // aren't listed in the config.  This way, we get the correct defaults upon Deserialization
@@ -78,6 +93,39 @@ pub(super) fn backup_extensions() -> Vec<String> {
         .collect()
 }
 
+/// default methods to probe when discovering methods
+pub(super) fn probe_methods() -> Vec<String> {
+    DEFAULT_PROBE_METHODS
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// default mapping of technology markers to extensions used by --auto-extensions
+pub(super) fn extension_map() -> HashMap<String, Vec<String>> {
+    DEFAULT_EXTENSION_MAP
+        .iter()
+        .map(|(marker, extensions)| {
+            (
+                marker.to_string(),
+                extensions.iter().map(|s| s.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// default rules run over response bodies by --secrets
+pub(super) fn secret_rules() -> Vec<SecretRule> {
+    DEFAULT_SECRET_RULES
+        .iter()
+        .map(|(id, regex, min_entropy)| SecretRule {
+            id: id.to_string(),
+            regex: regex.to_string(),
+            min_entropy: *min_entropy,
+        })
+        .collect()
+}
+
 /// default wordlist
 pub(super) fn wordlist() -> String {
     String::from(DEFAULT_WORDLIST)
@@ -98,6 +146,120 @@ pub(super) fn extract_links() -> bool {
     true
 }
 
+/// default protocol used when building a target url from a --request-file
+pub(super) fn request_protocol() -> String {
+    String::from("https")
+}
+
+/// the individual pieces of a raw HTTP request, as read in from --request-file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(super) struct RawRequest {
+    /// HTTP method from the request line
+    pub(super) method: String,
+
+    /// absolute url built from the request line and Host header, minus any query string
+    pub(super) target_url: String,
+
+    /// query parameters found on the request line
+    pub(super) queries: Vec<(String, String)>,
+
+    /// all headers, except for Host and Content-Length, in the order they were found
+    pub(super) headers: Vec<(String, String)>,
+
+    /// request body, if any
+    pub(super) body: Vec<u8>,
+}
+
+/// given the bytes of a raw HTTP/1.x request (as saved from an intercepting proxy), split it
+/// into its request line, headers and body
+///
+/// origin-form request lines (`GET /path HTTP/1.1`) are combined with the Host header and the
+/// given protocol to build the target url; absolute-form request lines are used as-is
+pub(super) fn parse_raw_request(raw: &[u8], protocol: &str) -> Result<RawRequest> {
+    // the head ends at the first empty line; accept both \r\n and bare \n line endings
+    let (head, body) = match raw.windows(4).position(|w| w == b"\r\n\r\n") {
+        Some(idx) => (&raw[..idx], &raw[idx + 4..]),
+        None => match raw.windows(2).position(|w| w == b"\n\n") {
+            Some(idx) => (&raw[..idx], &raw[idx + 2..]),
+            None => (raw, &raw[raw.len()..]),
+        },
+    };
+
+    let head = String::from_utf8_lossy(head);
+    let mut lines = head.lines().map(|line| line.trim_end_matches('\r'));
+
+    let request_line = lines.next().unwrap_or_default().trim();
+    let mut parts = request_line.split_whitespace();
+
+    let (method, target) = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => (method.to_uppercase(), target),
+        _ => bail!("Could not parse request line: {}", request_line),
+    };
+
+    let mut host = None;
+    let mut content_length = None;
+    let mut headers = Vec::new();
+
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Could not parse header: {}", line))?;
+
+        let (name, value) = (name.trim(), value.trim());
+
+        if name.eq_ignore_ascii_case("host") {
+            host = Some(value.to_string());
+        } else if name.eq_ignore_ascii_case("content-length") {
+            // reqwest calculates the length of whatever body we send; only used for trimming
+            content_length = value.parse::<usize>().ok();
+        } else {
+            headers.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    let url = if target.starts_with("http://") || target.starts_with("https://") {
+        Url::parse(target)?
+    } else {
+        let host = host.ok_or_else(|| {
+            anyhow!("Request file has no Host header and no absolute url in its request line")
+        })?;
+
+        Url::parse(&format!(
+            "{}://{}/{}",
+            protocol,
+            host,
+            target.trim_start_matches('/')
+        ))?
+    };
+
+    let queries = url
+        .query_pairs()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    let mut target_url = url;
+    target_url.set_query(None);
+    target_url.set_fragment(None);
+
+    let mut body = body.to_vec();
+
+    if let Some(length) = content_length {
+        body.truncate(length);
+    }
+
+    Ok(RawRequest {
+        method,
+        target_url: target_url.to_string(),
+        queries,
+        headers,
+        body,
+    })
+}
+
 /// enum representing the three possible states for informational output (not logging verbosity)
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum OutputLevel {
@@ -165,6 +327,41 @@ impl Default for RequesterPolicy {
     }
 }
 
+/// how multiple wordlists (-w list:KEYWORD) are combined into a single keyspace
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordlistMode {
+    /// every combination of words across all wordlists (cartesian product)
+    Clusterbomb,
+
+    /// words from each wordlist are used in lockstep, line by line (stops at the shortest list)
+    Pitchfork,
+}
+
+/// default implementation for WordlistMode
+impl Default for WordlistMode {
+    /// Clusterbomb as default
+    fn default() -> Self {
+        Self::Clusterbomb
+    }
+}
+
+/// a single rule used by --secrets to find sensitive data in response bodies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecretRule {
+    /// identifier reported along with each match, i.e. aws-access-key-id
+    pub id: String,
+
+    /// regex matched against the body; its first capture group, when present, is the secret.
+    /// an empty regex makes this an entropy rule, which checks every long, key-like string instead
+    #[serde(default)]
+    pub regex: String,
+
+    /// minimum shannon entropy (bits per character) of the secret; 0 disables the check
+    #[serde(default)]
+    pub min_entropy: f64,
+}
+
 /// given the current settings for quiet and silent, determine output_level (DRY helper)
 pub fn determine_requester_policy(auto_tune: bool, auto_bail: bool) -> RequesterPolicy {
     if auto_tune && auto_bail {
@@ -227,6 +424,60 @@ mod tests {
         assert_eq!(level, RequesterPolicy::AutoTune);
     }
 
+    #[test]
+    /// origin-form request with a body should be split into all of its pieces
+    fn parse_raw_request_splits_post_request() {
+        let raw = b"POST /api/users?id=1&name=ferris HTTP/1.1\r\nHost: localhost:8443\r\nContent-Type: application/json\r\nContent-Length: 14\r\nX-Thing: a:b\r\n\r\n{\"admin\":true}\r\n";
+
+        let req = parse_raw_request(raw, "https").unwrap();
+
+        assert_eq!(req.method, "POST");
+        assert_eq!(req.target_url, "https://localhost:8443/api/users");
+        assert_eq!(
+            req.queries,
+            vec![
+                (String::from("id"), String::from("1")),
+                (String::from("name"), String::from("ferris"))
+            ]
+        );
+        assert_eq!(
+            req.headers,
+            vec![
+                (
+                    String::from("Content-Type"),
+                    String::from("application/json")
+                ),
+                (String::from("X-Thing"), String::from("a:b"))
+            ]
+        );
+        assert_eq!(req.body, b"{\"admin\":true}");
+    }
+
+    #[test]
+    /// bare newlines, no body, and an absolute-form request line should all be handled
+    fn parse_raw_request_handles_absolute_form_and_bare_newlines() {
+        let raw = b"get http://example.com/stuff HTTP/1.1\nHost: ignored.com\nAccept: */*\n\n";
+
+        let req = parse_raw_request(raw, "https").unwrap();
+
+        assert_eq!(req.method, "GET");
+        assert_eq!(req.target_url, "http://example.com/stuff");
+        assert!(req.queries.is_empty());
+        assert_eq!(
+            req.headers,
+            vec![(String::from("Accept"), String::from("*/*"))]
+        );
+        assert!(req.body.is_empty());
+    }
+
+    #[test]
+    /// missing host / malformed request lines should produce errors
+    fn parse_raw_request_rejects_malformed_requests() {
+        assert!(parse_raw_request(b"GET /stuff HTTP/1.1\r\n\r\n", "https").is_err());
+        assert!(parse_raw_request(b"GET\r\nHost: localhost\r\n\r\n", "https").is_err());
+        assert!(parse_raw_request(b"GET / HTTP/1.1\r\nnope\r\n\r\n", "https").is_err());
+    }
+
     #[test]
     #[should_panic]
     /// report_and_exit should panic/exit when called
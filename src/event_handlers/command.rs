@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use reqwest::StatusCode;
-use tokio::sync::oneshot::Sender;
+use tokio::sync::{oneshot::Sender, OwnedSemaphorePermit};
 
+use crate::bypass::BypassFinding;
 use crate::response::FeroxResponse;
+use crate::secrets::SecretFinding;
+use crate::wordlists::Wordlist;
 use crate::{
     event_handlers::Handles,
     message::FeroxMessage,
@@ -62,7 +65,7 @@ pub enum Command {
     TryRecursion(Box<FeroxResponse>),
 
     /// Send a pointer to the wordlist to the recursion handler
-    UpdateWordlist(Arc<Vec<String>>),
+    UpdateWordlist(Wordlist),
 
     /// Instruct the ScanHandler to join on all known scans, use sender to notify main when done
     JoinTasks(Sender<bool>),
@@ -79,6 +82,16 @@ pub enum Command {
     /// Write an arbitrary string to disk
     WriteToDisk(Box<FeroxMessage>),
 
+    /// Write a variant of a 401/403 that got past it (--bypass-403) to disk
+    ReportBypass(Box<BypassFinding>),
+
+    /// Write a secret found in a response body (--secrets) to disk
+    ReportSecret(Box<SecretFinding>),
+
+    /// Write the records of an exchange that won't be reported (--warc-all) to the WARC file; the
+    /// permit is released once they're written
+    WriteWarc(Vec<u8>, OwnedSemaphorePermit),
+
     /// Break out of the (infinite) mpsc receive loop
     Exit,
 
@@ -2,6 +2,7 @@ use super::*;
 use crate::config::Configuration;
 use crate::event_handlers::scans::ScanHandle;
 use crate::scan_manager::FeroxScans;
+use crate::wordlists::Wordlist;
 use crate::Joiner;
 #[cfg(test)]
 use crate::{filters::FeroxFilters, statistics::Stats, Command};
@@ -59,7 +60,7 @@ pub struct Handles {
     pub scans: RwLock<Option<ScanHandle>>,
 
     /// Pointer to the list of words generated from reading in the wordlist
-    pub wordlist: Arc<Vec<String>>,
+    pub wordlist: Wordlist,
 }
 
 /// implementation of Handles
@@ -70,7 +71,7 @@ impl Handles {
         filters: FiltersHandle,
         output: TermOutHandle,
         config: Arc<Configuration>,
-        wordlist: Arc<Vec<String>>,
+        wordlist: Wordlist,
     ) -> Self {
         Self {
             stats,
@@ -93,7 +94,7 @@ impl Handles {
         let terminal_handle = TermOutHandle::new(tx.clone(), tx.clone());
         let stats_handle = StatsHandle::new(Arc::new(Stats::new(configuration.json)), tx.clone());
         let filters_handle = FiltersHandle::new(Arc::new(FeroxFilters::default()), tx.clone());
-        let wordlist = Arc::new(vec![String::from("this_is_a_test")]);
+        let wordlist = Wordlist::from(vec![String::from("this_is_a_test")]);
         let handles = Self::new(
             stats_handle,
             filters_handle,
@@ -131,8 +132,9 @@ impl Handles {
 
     /// wrapper to reach into `FeroxScans` and yank out the length of `collected_extensions`
     pub fn num_collected_extensions(&self) -> usize {
-        if !self.config.collect_extensions {
-            // if --collect-extensions wasn't used, simply return 0 and forego unlocking
+        if !self.config.collect_extensions && !self.config.auto_extensions {
+            // if neither --collect-extensions nor --auto-extensions were used, simply return 0
+            // and forego unlocking
             return 0;
         }
 
@@ -150,16 +152,26 @@ impl Handles {
         HashSet::new()
     }
 
-    /// number of words in the wordlist, multiplied by `expected_num_requests_multiplier`
+    /// number of words in the wordlist, multiplied by the number of candidates each word expands
+    /// into via `--rules` and `expected_num_requests_multiplier`
     pub fn expected_num_requests_per_dir(&self) -> usize {
-        let num_words = self.wordlist.len();
+        // num_candidates saturates for huge rule sets, so the products saturate too
+        let num_words = self
+            .wordlist
+            .len()
+            .saturating_mul(self.config.rules.num_candidates());
         let multiplier = self.expected_num_requests_multiplier();
-        multiplier * num_words
+        multiplier.saturating_mul(num_words)
     }
 
     /// number of extensions plus the number of request method types plus any dynamically collected
     /// extensions
     pub fn expected_num_requests_multiplier(&self) -> usize {
+        if self.config.vhost {
+            // words are only ever sent as the Host header, extensions aren't appended to them
+            return self.config.methods.len().max(1);
+        }
+
         let mut multiplier = self.config.extensions.len().max(1);
 
         if multiplier > 1 {
@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     progress::PROGRESS_PRINTER,
     scan_manager::{FeroxState, PAUSE_SCAN},
-    scanner::RESPONSES,
+    scanner::{PARAMETERS, RESPONSES, TECHNOLOGIES},
     statistics::StatError,
     utils::slugify_filename,
     utils::{open_file, write_to},
@@ -98,6 +98,8 @@ impl TermInputHandler {
             handles.ferox_scans()?,
             handles.config.clone(),
             &RESPONSES,
+            &PARAMETERS,
+            &TECHNOLOGIES,
             handles.stats.data.clone(),
             handles.filters.data.clone(),
         );
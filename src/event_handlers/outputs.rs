@@ -3,22 +3,36 @@ use super::*;
 
 use anyhow::{Context, Result};
 use futures::future::{BoxFuture, FutureExt};
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, Semaphore};
 
 use crate::{
-    config::Configuration,
+    bypass::{bypass_variants, differs_meaningfully, BypassFinding, BypassVariant},
+    config::{Configuration, OutputLevel},
+    har::{har_entry, HarWriter},
     progress::PROGRESS_PRINTER,
     response::FeroxResponse,
-    scanner::RESPONSES,
+    scanner::{PARAMETERS, RESPONSES, TECHNOLOGIES},
+    secrets::{find_secrets, SecretFinding},
     send_command, skip_fail,
     statistics::StatField::{ResourcesDiscovered, TotalExpected},
     traits::FeroxSerialize,
-    utils::{ferox_print, fmt_err, make_request, open_file, write_to},
+    utils::{
+        ferox_print, fmt_err, make_request, open_file, parse_url_with_raw_path, save_response,
+        write_to,
+    },
+    warc::{exchange, warcinfo},
     CommandReceiver, CommandSender, Joiner,
 };
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
 use std::sync::Arc;
 use url::Url;
 
+/// maximum number of --warc-all exchanges waiting to be written before requesters have to wait
+const MAX_PENDING_WARC_EXCHANGES: usize = 64;
+
 #[derive(Debug, Copy, Clone)]
 /// Simple enum for semantic clarity around calling expectations for `process_response`
 enum ProcessResponseCall {
@@ -37,13 +51,31 @@ pub struct TermOutHandle {
 
     /// Transmitter that sends to the FileOutHandler handler
     pub tx_file: CommandSender,
+
+    /// bounds the number of --warc-all exchanges waiting on the FileOutHandler
+    warc_permits: Arc<Semaphore>,
 }
 
 /// implementation of OutputHandle
 impl TermOutHandle {
     /// Given a CommandSender, create a new OutputHandle
     pub fn new(tx: CommandSender, tx_file: CommandSender) -> Self {
-        Self { tx, tx_file }
+        Self {
+            tx,
+            tx_file,
+            warc_permits: Arc::new(Semaphore::new(MAX_PENDING_WARC_EXCHANGES)),
+        }
+    }
+
+    /// Send the records of an exchange that won't be reported (--warc-all) to the FileOutHandler
+    ///
+    /// waits while too many exchanges are still waiting to be written, so that a slow disk slows
+    /// the scan down instead of piling up response bodies in memory
+    pub async fn archive(&self, response: &FeroxResponse, config: &Configuration) -> Result<()> {
+        let permit = self.warc_permits.clone().acquire_owned().await?;
+        self.tx_file
+            .send(Command::WriteWarc(exchange(response, config), permit))?;
+        Ok(())
     }
 
     /// Send the given Command over `tx`
@@ -90,24 +122,81 @@ impl FileOutHandler {
 
     /// Spawn a single consumer task (sc side of mpsc)
     ///
-    /// The consumer simply receives responses from the terminal handler and writes them to disk
+    /// The consumer simply receives responses from the terminal handler and writes them to disk,
+    /// to the -o file, the --warc archive and/or the --har-out file
     async fn start(&mut self, tx_stats: CommandSender) -> Result<()> {
         log::trace!("enter: start_file_handler({:?})", tx_stats);
 
-        let mut file = open_file(&self.config.output)?;
+        let mut file = if self.config.output.is_empty() {
+            None
+        } else {
+            log::info!("Writing scan results to {}", self.config.output);
+            Some(open_file(&self.config.output)?)
+        };
 
-        log::info!("Writing scan results to {}", self.config.output);
+        let mut warc = if self.config.warc.is_empty() {
+            None
+        } else {
+            log::info!("Archiving requests and responses to {}", self.config.warc);
+
+            let mut warc = open_file(&self.config.warc)?;
+            warc.write_all(&warcinfo())?;
+            warc.flush()?;
+            Some(warc)
+        };
+
+        let mut har = if self.config.har_out.is_empty() {
+            None
+        } else {
+            log::info!("Exporting findings to {}", self.config.har_out);
+            Some(HarWriter::create(&self.config.har_out)?)
+        };
 
         while let Some(command) = self.receiver.recv().await {
             match command {
                 Command::Report(response) => {
-                    skip_fail!(write_to(&*response, &mut file, self.config.json));
+                    if let Some(file) = file.as_mut() {
+                        skip_fail!(write_to(&*response, file, self.config.json));
+                    }
+
+                    if let Some(har) = har.as_mut() {
+                        skip_fail!(har.write(&har_entry(
+                            &response,
+                            &self.config,
+                            self.config.har_bodies
+                        )));
+                    }
+
+                    if let Some(warc) = warc.as_mut() {
+                        if !self.config.warc_all {
+                            // with --warc-all, findings were already archived by the requester
+                            skip_fail!(self.archive(&response, warc));
+                        }
+                    }
+                }
+                Command::WriteWarc(records, _permit) => {
+                    if let Some(warc) = warc.as_mut() {
+                        skip_fail!(warc.write_all(&records));
+                        skip_fail!(warc.flush());
+                    }
                 }
                 Command::WriteToDisk(message) => {
                     // todo consider making report accept dyn FeroxSerialize; would mean adding
                     //  as_any/box_eq/PartialEq to the trait and then adding them to the
                     //  implementing structs
-                    skip_fail!(write_to(&*message, &mut file, self.config.json));
+                    if let Some(file) = file.as_mut() {
+                        skip_fail!(write_to(&*message, file, self.config.json));
+                    }
+                }
+                Command::ReportBypass(finding) => {
+                    if let Some(file) = file.as_mut() {
+                        skip_fail!(write_to(&*finding, file, self.config.json));
+                    }
+                }
+                Command::ReportSecret(finding) => {
+                    if let Some(file) = file.as_mut() {
+                        skip_fail!(write_to(&*finding, file, self.config.json));
+                    }
                 }
                 Command::Exit => {
                     break;
@@ -119,14 +208,36 @@ impl FileOutHandler {
             }
         }
 
-        // close the file before we tell statistics to save current data to the same file
-        drop(file);
+        if let Some(mut file) = file {
+            // the parameter inventory is only complete once the scan is, so it's written last
+            for endpoint in PARAMETERS.snapshot() {
+                skip_fail!(write_to(&endpoint, &mut file, self.config.json));
+            }
+
+            // same goes for the technologies detected by --fingerprint
+            for host in TECHNOLOGIES.snapshot() {
+                skip_fail!(write_to(&host, &mut file, self.config.json));
+            }
+
+            // close the file before we tell statistics to save current data to the same file
+            drop(file);
 
-        send_command!(tx_stats, Command::Save);
+            send_command!(tx_stats, Command::Save);
+        }
 
         log::trace!("exit: start_file_handler");
         Ok(())
     }
+
+    /// write the request and response records of the given exchange to the WARC archive; the
+    /// archive is flushed after each exchange so that it's complete up to the last one, even
+    /// when the scan is cut short
+    fn archive(&self, response: &FeroxResponse, warc: &mut BufWriter<File>) -> Result<()> {
+        warc.write_all(&exchange(response, &self.config))
+            .with_context(|| fmt_err(&format!("Could not archive {}", response.url())))?;
+        warc.flush()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -181,14 +292,15 @@ impl TermOutHandler {
 
         let tx_stats_clone = tx_stats.clone();
 
-        let file_task = if !config.output.is_empty() {
-            // -o used, need to spawn the thread for writing to disk
-            Some(tokio::spawn(async move {
-                file_handler.start(tx_stats_clone).await
-            }))
-        } else {
-            None
-        };
+        let file_task =
+            if !config.output.is_empty() || !config.warc.is_empty() || !config.har_out.is_empty() {
+                // -o, --warc and/or --har-out used, need to spawn the thread for writing to disk
+                Some(tokio::spawn(async move {
+                    file_handler.start(tx_stats_clone).await
+                }))
+            } else {
+                None
+            };
 
         let mut term_handler = Self::new(rx_term, tx_file.clone(), file_task, config);
         let term_task = tokio::spawn(async move { term_handler.start(tx_stats).await });
@@ -223,6 +335,18 @@ impl TermOutHandler {
                     self.handles = Some(handles);
                 }
                 Command::Exit => {
+                    if self.config.fingerprint
+                        && matches!(
+                            self.config.output_level,
+                            OutputLevel::Default | OutputLevel::Quiet
+                        )
+                    {
+                        // end-of-scan summary of the technologies detected on each host
+                        for host in TECHNOLOGIES.snapshot() {
+                            ferox_print(&host.as_str(), &PROGRESS_PRINTER);
+                        }
+                    }
+
                     if self.file_task.is_some() && self.tx_file.send(Command::Exit).is_ok() {
                         self.file_task.as_mut().unwrap().await??; // wait for death
                     }
@@ -246,19 +370,24 @@ impl TermOutHandler {
         log::trace!("enter: process_response({:?}, {:?})", resp, call_type);
 
         async move {
-            let contains_sentry = if !self.config.filter_status.is_empty() {
-                // -C was used, meaning -s was not and we should ignore the defaults
-                // https://github.com/epi052/feroxbuster/issues/535
-                // -C indicates that we should filter that status code, but allow all others
-                !self.config.filter_status.contains(&resp.status().as_u16())
-            } else {
-                // -C wasn't used, so, we defer to checking the -s values
-                self.config.status_codes.contains(&resp.status().as_u16())
-            };
+            let contains_sentry = self.config.reports_status(resp.status().as_u16());
 
             let unknown_sentry = !RESPONSES.contains(&resp); // !contains == unknown
             let should_process_response = contains_sentry && unknown_sentry;
 
+            if should_process_response && !self.config.save_responses.is_empty() {
+                // --save-responses was used; the body is written out before the response is
+                // reported, so that the path it was saved to makes it into the report
+                match save_response(
+                    &resp,
+                    Path::new(&self.config.save_responses),
+                    self.config.save_headers,
+                ) {
+                    Ok(path) => resp.set_saved_to(&path.to_string_lossy()),
+                    Err(e) => log::warn!("Could not save the body of {}: {}", resp.url(), e),
+                }
+            }
+
             if should_process_response {
                 // print to stdout
                 ferox_print(&resp.as_str(), &PROGRESS_PRINTER);
@@ -266,7 +395,8 @@ impl TermOutHandler {
                 send_command!(tx_stats, AddToUsizeField(ResourcesDiscovered, 1));
 
                 if self.file_task.is_some() {
-                    // -o used, need to send the report to be written out to disk
+                    // -o, --warc and/or --har-out used, need to send the report to be written out
+                    // to disk
                     self.tx_file
                         .send(Command::Report(resp.clone()))
                         .with_context(|| {
@@ -290,6 +420,7 @@ impl TermOutHandler {
                     resp.url(),
                     resp.method().as_str(),
                     data,
+                    None,
                     self.config.output_level,
                     &self.config,
                     tx_stats.clone(),
@@ -315,6 +446,7 @@ impl TermOutHandler {
                         backup_url,
                         resp.method().as_str(),
                         None,
+                        None,
                         self.config.output_level,
                         &self.config,
                         tx_stats.clone(),
@@ -356,6 +488,23 @@ impl TermOutHandler {
                 }
             }
 
+            if self.config.bypass_403
+                && should_process_response
+                && matches!(call_type, ProcessResponseCall::Recursive)
+                && matches!(resp.status().as_u16(), 401 | 403)
+            {
+                // --bypass-403 was used and the response is a blocked one we care about
+                self.bypass_403(&resp, tx_stats.clone()).await?;
+            }
+
+            if self.config.secrets && should_process_response {
+                // --secrets was used, which keeps response bodies around until this point; they're
+                // dropped right after, unless something else still needs them
+                for finding in find_secrets(&resp) {
+                    self.report_secret(finding)?;
+                }
+            }
+
             if should_process_response {
                 // add response to RESPONSES for serialization in case of ctrl+c
                 // placed all by its lonesome like this so that RESPONSES can take ownership
@@ -373,6 +522,145 @@ impl TermOutHandler {
         .boxed()
     }
 
+    /// print a secret found in a response body (--secrets), sending it to the file handler too
+    /// when -o was used
+    fn report_secret(&self, finding: SecretFinding) -> Result<()> {
+        ferox_print(&finding.as_str(), &PROGRESS_PRINTER);
+
+        if self.file_task.is_some() {
+            // -o used, need to send the report to be written out to disk
+            self.tx_file
+                .send(Command::ReportSecret(Box::new(finding)))
+                .with_context(|| fmt_err("Could not send secret to file handler"))?;
+        }
+
+        Ok(())
+    }
+
+    /// internal helper to stay DRY; request the given bypass variant with the given method and
+    /// base headers, returning the response's status code and size
+    ///
+    /// the variant's path is kept as-is (i.e. `/x/../admin`), see `parse_url_with_raw_path`
+    async fn send_bypass_variant(
+        &self,
+        variant: &BypassVariant,
+        method: &str,
+        base_headers: &HashMap<String, String>,
+        tx_stats: CommandSender,
+    ) -> Option<(u16, u64)> {
+        let mut headers = base_headers.clone();
+        headers.extend(variant.headers.clone());
+
+        let url = parse_url_with_raw_path(&variant.url).ok()?;
+
+        let response = make_request(
+            &self.config.client,
+            &url,
+            method,
+            None,
+            Some(&headers),
+            self.config.output_level,
+            &self.config,
+            tx_stats,
+        )
+        .await
+        .map_err(|e| log::warn!("Could not request {}: {}", variant.url, e))
+        .ok()?;
+
+        let ferox_response =
+            FeroxResponse::from(response, url.as_str(), method, self.config.output_level).await;
+
+        Some((
+            ferox_response.status().as_u16(),
+            ferox_response.content_length(),
+        ))
+    }
+
+    /// given a 401/403 `FeroxResponse`, request each of its bypass variants (path mutations and
+    /// header tricks), then report the ones whose responses differ meaningfully from it
+    ///
+    /// variants relying on a rewrite header are sent to the site's root, so they also need to
+    /// differ from the root's own response
+    async fn bypass_403(&self, response: &FeroxResponse, tx_stats: CommandSender) -> Result<()> {
+        log::trace!("enter: bypass_403({})", response);
+
+        let variants = bypass_variants(response.url());
+        let method = response.method().as_str();
+
+        // need to manually adjust stats; the rewrite variants share a single request to the root
+        let root = variants
+            .iter()
+            .find(|variant| variant.rewrites)
+            .map(|variant| {
+                let mut root = variant.clone();
+                root.headers.clear();
+                root
+            });
+
+        send_command!(
+            tx_stats,
+            AddToUsizeField(TotalExpected, variants.len() + usize::from(root.is_some()))
+        );
+
+        // in --vhost mode, variants are sent to the same virtual host as the finding
+        let base_headers: HashMap<String, String> = response
+            .vhost()
+            .map(|host| (String::from("Host"), host.to_string()))
+            .into_iter()
+            .collect();
+
+        let root_result = match &root {
+            Some(root) => {
+                self.send_bypass_variant(root, method, &base_headers, tx_stats.clone())
+                    .await
+            }
+            None => None,
+        };
+
+        let blocked = (response.status().as_u16(), response.content_length());
+
+        for variant in &variants {
+            let Some(result) = self
+                .send_bypass_variant(variant, method, &base_headers, tx_stats.clone())
+                .await
+            else {
+                continue;
+            };
+
+            if !differs_meaningfully(blocked, result)
+                || (variant.rewrites
+                    && root_result.is_some_and(|root| !differs_meaningfully(root, result)))
+            {
+                continue;
+            }
+
+            let finding = BypassFinding::new(
+                variant,
+                response.url().as_str(),
+                method,
+                blocked.0,
+                result,
+                self.config.output_level,
+            );
+
+            ferox_print(&finding.as_str(), &PROGRESS_PRINTER);
+
+            if self.file_task.is_some() {
+                // -o used, need to send the report to be written out to disk
+                self.tx_file
+                    .send(Command::ReportBypass(Box::new(finding)))
+                    .with_context(|| {
+                        fmt_err(&format!(
+                            "Could not send bypass of {response} to file handler"
+                        ))
+                    })?;
+            }
+        }
+
+        log::trace!("exit: bypass_403");
+        Ok(())
+    }
+
     /// internal helper to stay DRY
     fn add_new_url_to_vec(&self, url: &Url, new_name: &str, urls: &mut Vec<Url>) {
         if let Ok(joined) = url.join(new_name) {
@@ -10,6 +10,7 @@ use crate::{
     statistics::StatField::TotalScans,
     url::FeroxUrl,
     utils::should_deny_url,
+    wordlists::Wordlist,
     CommandReceiver, CommandSender, FeroxChannel, Joiner, SLEEP_DURATION,
 };
 
@@ -56,7 +57,7 @@ pub struct ScanHandler {
     receiver: CommandReceiver,
 
     /// wordlist (re)used for each scan
-    wordlist: std::sync::Mutex<Option<Arc<Vec<String>>>>,
+    wordlist: std::sync::Mutex<Option<Wordlist>>,
 
     /// group of scans that need to be joined
     tasks: Vec<Arc<FeroxScan>>,
@@ -107,7 +108,7 @@ impl ScanHandler {
     }
 
     /// Set the wordlist
-    fn wordlist(&self, wordlist: Arc<Vec<String>>) {
+    fn wordlist(&self, wordlist: Wordlist) {
         if let Ok(mut guard) = self.wordlist.lock() {
             if guard.is_none() {
                 let _ = std::mem::replace(&mut *guard, Some(wordlist));
@@ -178,11 +179,12 @@ impl ScanHandler {
                     sender.send(true).unwrap_or_default();
                 }
                 Command::AddDiscoveredExtension(new_extension) => {
-                    // if --collect-extensions was used, AND the new extension isn't in
-                    // the --dont-collect list AND it's also not in the --extensions list, AND
-                    // we actually added a new extension (i.e. wasn't previously known), add
-                    // it to FeroxScans.collected_extensions
-                    if self.handles.config.collect_extensions
+                    // if --collect-extensions or --auto-extensions was used, AND the new
+                    // extension isn't in the --dont-collect list AND it's also not in the
+                    // --extensions list, AND we actually added a new extension (i.e. wasn't
+                    // previously known), add it to FeroxScans.collected_extensions
+                    if (self.handles.config.collect_extensions
+                        || self.handles.config.auto_extensions)
                         && !self.handles.config.dont_collect.contains(&new_extension)
                         && !self.handles.config.extensions.contains(&new_extension)
                         && self.data.add_discovered_extension(new_extension)
@@ -218,7 +220,10 @@ impl ScanHandler {
         // current number of requests expected per scan
         // ExpectedPerScan and TotalExpected are a += action, so we need the wordlist length to
         // update them while the other updates use expected_num_requests_per_dir
-        let num_words = self.get_wordlist(0)?.len();
+        let num_words = self
+            .get_wordlist(0)?
+            .len()
+            .saturating_mul(self.handles.config.rules.num_candidates());
         let current_expectation = self.handles.expected_num_requests_per_dir() as u64;
 
         // used in the calculation of bar width down below, see explanation there
@@ -289,15 +294,13 @@ impl ScanHandler {
         Ok(())
     }
 
-    /// Helper to easily get the (locked) underlying wordlist
-    pub fn get_wordlist(&self, offset: usize) -> Result<Arc<Vec<String>>> {
+    /// Helper to easily get the (locked) underlying wordlist, starting `offset` words in
+    ///
+    /// the returned wordlist shares its words with the original, no copies are made
+    pub fn get_wordlist(&self, offset: usize) -> Result<Wordlist> {
         if let Ok(guard) = self.wordlist.lock().as_ref() {
             if let Some(list) = guard.as_ref() {
-                return if offset > 0 {
-                    Ok(Arc::new(list[offset..].to_vec()))
-                } else {
-                    Ok(list.clone())
-                };
+                return Ok(list.skip(offset));
             }
         }
 
@@ -336,15 +339,22 @@ impl ScanHandler {
 
             let divisor = self.handles.expected_num_requests_multiplier();
 
+            // each word may have been expanded into several candidates by --rules, so the
+            // number of requests made needs to be scaled back down to a number of words
+            let num_candidates = self.handles.config.rules.num_candidates() as f64;
+
             let list = if divisor > 1 && scan.requests() > 0 {
                 // if there were extensions provided and/or more than a single method used, and some
                 // number of requests have already been sent, we need to adjust the offset into the
                 // wordlist to ensure we don't index out of bounds
 
-                let adjusted = scan.requests_made_so_far() as f64 / (divisor as f64 - 1.0).max(1.0);
+                let adjusted = scan.requests_made_so_far() as f64
+                    / (divisor as f64 - 1.0).max(1.0)
+                    / num_candidates;
                 self.get_wordlist(adjusted as usize)?
             } else {
-                self.get_wordlist(scan.requests_made_so_far() as usize)?
+                let adjusted = scan.requests_made_so_far() as f64 / num_candidates;
+                self.get_wordlist(adjusted as usize)?
             };
 
             log::info!("scan handler received {} - beginning scan", target);
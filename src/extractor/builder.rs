@@ -13,6 +13,11 @@ pub(super) const LINKFINDER_REGEX: &str = r#"(?:"|')(((?:[a-zA-Z]{1,10}://|//)[^
 pub(super) const ROBOTS_TXT_REGEX: &str =
     r#"(?m)^ *(Allow|Disallow): *(?P<url_path>[a-zA-Z0-9._/?#@!&'()+,;%=-]+?)$"#; // multi-line (?m)
 
+/// Regular expression to pull sitemap urls from robots.txt
+///
+/// ref: https://www.sitemaps.org/protocol.html#submit_robots
+pub(super) const ROBOTS_SITEMAP_REGEX: &str = r#"(?mi)^ *Sitemap: *(?P<url>\S+)"#; // multi-line (?m)
+
 /// Regular expression to filter bad characters from extracted url paths
 ///
 /// ref: https://www.rfc-editor.org/rfc/rfc3986#section-2
@@ -24,11 +29,25 @@ pub enum ExtractionTarget {
     /// Examine a response body and extract javascript and html links (multiple tags)
     ResponseBody,
 
-    /// Examine robots.txt (specifically) and extract links
+    /// Examine robots.txt (specifically), along with sitemap.xml and any sitemaps it points to,
+    /// and extract links
     RobotsTxt,
 
     /// Extract all <a> tags from a page
     DirectoryListing,
+
+    /// Examine an OpenAPI/Swagger specification and extract its endpoints
+    OpenApi,
+
+    /// Request a curated set of /.well-known/ resources, report them, and extract links
+    WellKnown,
+
+    /// Examine a javascript response's source map and extract links from the original sources
+    SourceMap,
+
+    /// Examine a response's headers (Location, Link, Content-Security-Policy, etc...) and extract
+    /// links
+    ResponseHeaders,
 }
 
 /// responsible for building an `Extractor`
@@ -95,6 +114,7 @@ impl<'a> ExtractorBuilder<'a> {
         Ok(Extractor {
             links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
             robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+            sitemap_regex: Regex::new(ROBOTS_SITEMAP_REGEX).unwrap(),
             url_regex: Regex::new(URL_CHARS_REGEX).unwrap(),
             response: if self.response.is_some() {
                 Some(self.response.unwrap())
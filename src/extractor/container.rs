@@ -1,3 +1,11 @@
+use super::headers::links_from_headers;
+use super::openapi::{
+    endpoints as endpoints_from_spec, parse_spec, OPENAPI_LOCATIONS, SAFE_METHODS,
+};
+use super::parameters::{params_from_forms, params_from_javascript, FoundParameters};
+use super::sitemap::{parse_sitemap, Sitemap, MAX_SITEMAPS, SITEMAP_LOCATION};
+use super::source_map::{is_javascript, parse_source_map, save_sources, source_map_location};
+use super::well_known::{links_from_well_known, WELL_KNOWN_LOCATIONS};
 use super::*;
 use crate::{
     client,
@@ -5,10 +13,11 @@ use crate::{
         Command::{AddError, AddToUsizeField},
         Handles,
     },
-    scan_manager::ScanOrder,
+    scan_manager::{ParameterSource, ScanOrder},
+    scanner::PARAMETERS,
     statistics::{
         StatError::Other,
-        StatField::{LinksExtracted, TotalExpected},
+        StatField::{LinksExtracted, SitemapLinksExtracted, TotalExpected},
     },
     url::FeroxUrl,
     utils::{
@@ -18,17 +27,25 @@ use crate::{
     ExtractionResult, DEFAULT_METHOD,
 };
 use anyhow::{bail, Context, Result};
-use futures::StreamExt;
-use reqwest::{Client, Response, StatusCode, Url};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use reqwest::{header::HeaderMap, Client, Response, StatusCode, Url};
 use scraper::{Html, Selector};
-use std::{borrow::Cow, collections::HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+    path::Path,
+};
 
 /// Wrapper around link extraction logic
 ///   - create a new Url object based on cli options/args
 ///   - check if the new Url has already been seen/scanned -> None
 ///   - make a request to the new Url ? -> Some(response) : None
-pub(super) async fn request_link(url: &str, handles: Arc<Handles>) -> Result<Response> {
-    log::trace!("enter: request_link({})", url);
+pub(super) async fn request_link(
+    url: &str,
+    method: &str,
+    handles: Arc<Handles>,
+) -> Result<Response> {
+    log::trace!("enter: request_link({}, {})", url, method);
 
     let ferox_url = FeroxUrl::from_string(url, handles.clone());
 
@@ -37,7 +54,8 @@ pub(super) async fn request_link(url: &str, handles: Arc<Handles>) -> Result<Res
 
     let scanned_urls = handles.ferox_scans()?;
 
-    if scanned_urls.get_scan_by_url(new_url.as_ref()).is_some() {
+    // FeroxScans only tracks urls, so any method other than the default is always requested
+    if method == DEFAULT_METHOD && scanned_urls.get_scan_by_url(new_url.as_ref()).is_some() {
         //we've seen the url before and don't need to scan again
         log::trace!("exit: request_link -> None");
         bail!("previously seen url");
@@ -56,13 +74,44 @@ pub(super) async fn request_link(url: &str, handles: Arc<Handles>) -> Result<Res
     }
 
     // make the request and store the response
-    let new_response = logged_request(&new_url, DEFAULT_METHOD, None, handles.clone()).await?;
+    let new_response = logged_request(&new_url, method, None, None, handles.clone()).await?;
 
     log::trace!("exit: request_link -> {:?}", new_response);
 
     Ok(new_response)
 }
 
+/// extract links from the source map of a script found during link extraction and request them
+///
+/// scripts found by the wordlist are handled by the `Requester`, but extracted scripts are only
+/// ever requested here
+///
+/// boxed, since it's called from within the task spawned by `Extractor::request_endpoints`, which
+/// it ends up calling itself
+fn request_source_map_links<'a>(
+    script: &'a FeroxResponse,
+    url: &'a str,
+    handles: Arc<Handles>,
+) -> BoxFuture<'a, Result<()>> {
+    async move {
+        let mut extractor = ExtractorBuilder::default()
+            .target(ExtractionTarget::SourceMap)
+            .response(script)
+            .url(url)
+            .handles(handles)
+            .build()?;
+
+        let links = extractor.extract().await?;
+
+        if let Some(task) = extractor.request_links(links).await? {
+            task.await?;
+        }
+
+        Ok(())
+    }
+    .boxed()
+}
+
 /// Whether an active scan is recursive or not
 #[derive(Debug, Copy, Clone)]
 enum RecursionStatus {
@@ -82,6 +131,9 @@ pub struct Extractor<'a> {
     /// `ROBOTS_TXT_REGEX` as a regex::Regex type
     pub(super) robots_regex: Regex,
 
+    /// `ROBOTS_SITEMAP_REGEX` as a regex::Regex type
+    pub(super) sitemap_regex: Regex,
+
     /// regex to validate a url
     pub(super) url_regex: Regex,
 
@@ -110,6 +162,10 @@ impl<'a> Extractor<'a> {
             ExtractionTarget::ResponseBody => Ok(self.extract_from_body().await?),
             ExtractionTarget::RobotsTxt => Ok(self.extract_from_robots().await?),
             ExtractionTarget::DirectoryListing => Ok(self.extract_from_dir_listing().await?),
+            ExtractionTarget::OpenApi => Ok(self.extract_from_openapi().await?.0),
+            ExtractionTarget::WellKnown => Ok(self.extract_from_well_known().await?),
+            ExtractionTarget::SourceMap => Ok(self.extract_from_source_map().await?),
+            ExtractionTarget::ResponseHeaders => Ok(self.extract_from_headers().await?),
         }
     }
 
@@ -125,6 +181,17 @@ impl<'a> Extractor<'a> {
     ) -> Result<()> {
         log::trace!("enter: parse_url_and_add_subpaths({:?})", links);
 
+        if url_to_parse.contains('?') {
+            // query strings are discarded below, so their keys are recorded first
+            let link = FoundParameters {
+                url: url_to_parse.to_string(),
+                method: DEFAULT_METHOD.to_string(),
+                names: Vec::new(),
+            };
+
+            self.record_parameters(link, original_url, ParameterSource::Query);
+        }
+
         match parse_url_with_raw_path(url_to_parse) {
             Ok(absolute) => {
                 if absolute.domain() != original_url.domain()
@@ -163,13 +230,51 @@ impl<'a> Extractor<'a> {
         Ok(())
     }
 
+    /// add the given parameters, along with any query string keys on their url, to the parameter
+    /// inventory
+    ///
+    /// the url is resolved against `base_url` and must belong to the same host
+    fn record_parameters(&self, found: FoundParameters, base_url: &Url, source: ParameterSource) {
+        let Ok(mut url) = base_url.join(&found.url) else {
+            log::debug!("could not join {} with {}", base_url, found.url);
+            return;
+        };
+
+        if url.domain() != base_url.domain() || url.host() != base_url.host() {
+            return;
+        }
+
+        let mut names = found.names;
+        names.extend(url.query_pairs().map(|(key, _)| key.into_owned()));
+
+        url.set_query(None);
+        url.set_fragment(None);
+
+        PARAMETERS.add(url.as_str(), &found.method, names, source);
+    }
+
     /// given a set of links from a normal http body response, task the request handler to make
     /// the requests
     pub async fn request_links(
         &mut self,
         links: HashSet<String>,
     ) -> Result<Option<tokio::task::JoinHandle<()>>> {
-        log::trace!("enter: request_links({:?})", links);
+        let endpoints = links
+            .into_iter()
+            .map(|link| (link, DEFAULT_METHOD.to_string()))
+            .collect();
+
+        self.request_endpoints(endpoints).await
+    }
+
+    /// given a set of (link, http method) pairs, task the request handler to make the requests
+    ///
+    /// only responses to the default method are considered for recursion
+    pub async fn request_endpoints(
+        &mut self,
+        links: HashSet<(String, String)>,
+    ) -> Result<Option<tokio::task::JoinHandle<()>>> {
+        log::trace!("enter: request_endpoints({:?})", links);
 
         if links.is_empty() {
             return Ok(None);
@@ -182,7 +287,9 @@ impl<'a> Extractor<'a> {
         let cloned_handles = self.handles.clone();
         let cloned_url = self.url.clone();
         let threads = self.handles.config.threads;
-        let recursive = if self.handles.config.no_recursion {
+        let recursive = if self.handles.config.no_recursion
+            || self.handles.config.is_keyword_target(&self.url)
+        {
             RecursionStatus::NotRecursive
         } else {
             RecursionStatus::Recursive
@@ -190,12 +297,17 @@ impl<'a> Extractor<'a> {
 
         let link_request_task = tokio::spawn(async move {
             let producers = futures::stream::iter(links.into_iter())
-                .map(|link| {
+                .map(|(link, method)| {
                     // another clone to satisfy the async move block
                     let inner_clone = cloned_handles.clone();
 
                     (
-                        tokio::spawn(async move { request_link(&link, inner_clone).await }),
+                        tokio::spawn(async move {
+                            // the method is handed back along with the response, as it's needed
+                            // to build the FeroxResponse
+                            let response = request_link(&link, &method, inner_clone).await?;
+                            Ok::<_, anyhow::Error>((response, method))
+                        }),
                         cloned_handles.clone(),
                         cloned_scanned_urls.clone(),
                         recursive,
@@ -206,11 +318,11 @@ impl<'a> Extractor<'a> {
                     threads,
                     |(join_handle, c_handles, c_scanned_urls, c_recursive, og_url)| async move {
                         match join_handle.await {
-                            Ok(Ok(reqwest_response)) => {
+                            Ok(Ok((reqwest_response, method))) => {
                                 let mut resp = FeroxResponse::from(
                                     reqwest_response,
                                     &og_url,
-                                    DEFAULT_METHOD,
+                                    &method,
                                     c_handles.config.output_level,
                                 )
                                 .await;
@@ -224,8 +336,13 @@ impl<'a> Extractor<'a> {
                                     return;
                                 }
 
-                                // request and report assumed file
-                                if resp.is_file() || !resp.is_directory() {
+                                // request and report assumed file; api endpoints requested with
+                                // something other than the default method are always treated as
+                                // files
+                                if resp.is_file()
+                                    || !resp.is_directory()
+                                    || method != DEFAULT_METHOD
+                                {
                                     log::debug!("Extracted File: {}", resp);
 
                                     c_scanned_urls
@@ -236,7 +353,22 @@ impl<'a> Extractor<'a> {
                                         resp.parse_extension(c_handles.clone()).unwrap();
                                     }
 
-                                    if let Err(e) = resp.send_report(c_handles.output.tx.clone()) {
+                                    if c_handles.config.extract_links && is_javascript(&resp) {
+                                        if let Err(e) = request_source_map_links(
+                                            &resp,
+                                            &og_url,
+                                            c_handles.clone(),
+                                        )
+                                        .await
+                                        {
+                                            log::warn!("Error during source map extraction: {}", e);
+                                        }
+                                    }
+
+                                    if let Err(e) = resp.send_report(
+                                        c_handles.output.tx.clone(),
+                                        c_handles.config.keep_response_bodies(),
+                                    ) {
                                         log::warn!(
                                             "Could not send FeroxResponse to output handler: {}",
                                             e
@@ -297,7 +429,7 @@ impl<'a> Extractor<'a> {
             producers.await;
         });
 
-        log::trace!("exit: request_links");
+        log::trace!("exit: request_endpoints");
         Ok(Some(link_request_task))
     }
 
@@ -329,6 +461,7 @@ impl<'a> Extractor<'a> {
     ///         - homepage/assets/img/
     ///         - homepage/assets/
     ///         - homepage/
+    ///   - record the parameters sent by any fetch/XHR/axios/jQuery requests in the inventory
     fn extract_all_links_from_javascript(
         &self,
         response_body: &str,
@@ -354,6 +487,10 @@ impl<'a> Extractor<'a> {
             }
         }
 
+        for request in params_from_javascript(response_body) {
+            self.record_parameters(request, response_url, ParameterSource::JavaScript);
+        }
+
         log::trace!("exit: extract_all_links_from_javascript");
     }
 
@@ -478,7 +615,18 @@ impl<'a> Extractor<'a> {
             ExtractionTarget::ResponseBody | ExtractionTarget::DirectoryListing => {
                 self.response.unwrap().url().clone()
             }
-            ExtractionTarget::RobotsTxt => match parse_url_with_raw_path(&self.url) {
+            ExtractionTarget::SourceMap | ExtractionTarget::ResponseHeaders => {
+                // routes in the original sources are relative to the application, not to the
+                // directory the bundled script happens to be served from; header links are
+                // already resolved against the response's url by the time they get here
+                let mut root = self.response.unwrap().url().clone();
+                root.set_path("/");
+                root.set_query(None);
+                root
+            }
+            ExtractionTarget::RobotsTxt
+            | ExtractionTarget::OpenApi
+            | ExtractionTarget::WellKnown => match parse_url_with_raw_path(&self.url) {
                 Ok(u) => u,
                 Err(e) => {
                     bail!("Could not parse {}: {}", self.url, e);
@@ -516,13 +664,27 @@ impl<'a> Extractor<'a> {
     ///     http://localhost/stuff/things
     /// this function requests:
     ///     http://localhost/robots.txt
+    ///
+    /// sitemaps are extracted along with robots.txt, using its Sitemap directives
+    ///
+    /// when the `Extractor` was built with a `response`, it's used as robots.txt instead
     pub(super) async fn extract_from_robots(&self) -> Result<ExtractionResult> {
         log::trace!("enter: extract_robots_txt");
 
         let mut result: HashSet<_> = ExtractionResult::new();
 
-        // request
-        let response = self.make_extract_request("/robots.txt").await?;
+        // request, if necessary
+        let requested;
+        let response = match self.response {
+            Some(response) => response,
+            None => {
+                requested = self.make_extract_request("/robots.txt").await?;
+                &requested
+            }
+        };
+
+        self.add_links_from_headers(response.url(), response.headers(), &mut result);
+
         let body = response.text();
 
         for capture in self.robots_regex.captures_iter(body) {
@@ -537,16 +699,358 @@ impl<'a> Extractor<'a> {
             }
         }
 
+        self.add_links_from_sitemaps(body, &mut result).await?;
+
         log::trace!("exit: extract_robots_txt -> {:?}", result);
         Ok(result)
     }
 
+    /// link extraction from sitemaps, performed as part of robots.txt extraction
+    ///
+    /// /sitemap.xml and any sitemaps referenced by the given robots.txt body are requested;
+    /// sitemap indexes are followed (up to `MAX_SITEMAPS` sitemaps in total), gzipped sitemaps
+    /// are decompressed, and every page listed that belongs to the target is added to `result`
+    /// along with its sub-paths
+    async fn add_links_from_sitemaps(
+        &self,
+        robots: &str,
+        result: &mut ExtractionResult,
+    ) -> Result<()> {
+        let base_url = parse_url_with_raw_path(&self.url)?;
+
+        let mut queue = VecDeque::new();
+        queue.push_back(base_url.join(SITEMAP_LOCATION)?);
+
+        for capture in self.sitemap_regex.captures_iter(robots) {
+            if let Ok(sitemap_url) = base_url.join(&capture["url"]) {
+                queue.push_back(sitemap_url);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut num_pages = 0;
+
+        while let Some(sitemap_url) = queue.pop_front() {
+            if sitemap_url.host() != base_url.host() || seen.contains(&sitemap_url) {
+                continue;
+            }
+
+            if seen.len() >= MAX_SITEMAPS {
+                log::warn!(
+                    "stopped following sitemaps for {} after {} sitemaps",
+                    self.url,
+                    MAX_SITEMAPS
+                );
+                break;
+            }
+
+            seen.insert(sitemap_url.clone());
+
+            let body = match self.request_sitemap(&sitemap_url, result).await {
+                Ok(body) => body,
+                Err(e) => {
+                    log::debug!("could not request sitemap {}: {}", sitemap_url, e);
+                    continue;
+                }
+            };
+
+            match parse_sitemap(&body) {
+                Ok(Sitemap::Index(sitemaps)) => {
+                    queue.extend(sitemaps.iter().filter_map(|loc| base_url.join(loc).ok()));
+                }
+                Ok(Sitemap::UrlSet(pages)) => {
+                    num_pages += pages.len();
+
+                    for page in pages {
+                        if self
+                            .parse_url_and_add_subpaths(&page, &base_url, result)
+                            .is_err()
+                        {
+                            log::debug!("link didn't belong to the target domain/host: {}", page);
+                        }
+                    }
+                }
+                Err(e) => log::debug!("could not parse sitemap {}: {}", sitemap_url, e),
+            }
+        }
+
+        self.handles
+            .stats
+            .send(AddToUsizeField(SitemapLinksExtracted, num_pages))?;
+
+        Ok(())
+    }
+
+    /// request the sitemap at the given url and return its raw body; gzipped sitemaps are
+    /// usually served as-is, so the body can't go through `FeroxResponse`'s text handling
+    ///
+    /// links found in the response's headers are added to `result`, whether or not the sitemap
+    /// was found
+    async fn request_sitemap(&self, url: &Url, result: &mut ExtractionResult) -> Result<Vec<u8>> {
+        let response = make_request(
+            &self.handles.config.client,
+            url,
+            DEFAULT_METHOD,
+            None,
+            None,
+            self.handles.config.output_level,
+            &self.handles.config,
+            self.handles.stats.tx.clone(),
+        )
+        .await?;
+
+        self.add_links_from_headers(response.url(), response.headers(), result);
+
+        if !response.status().is_success() {
+            bail!("{} returned {}", url, response.status());
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Entry point to perform discovery of /.well-known/ resources
+    ///
+    /// each of `WELL_KNOWN_LOCATIONS` is requested from the root of the url; the ones that aren't
+    /// filtered are reported like any other response, and the urls found within them (i.e.
+    /// security.txt's Policy, openid-configuration's jwks_uri) are added along with their
+    /// sub-paths
+    pub(super) async fn extract_from_well_known(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_well_known");
+
+        let mut result = ExtractionResult::new();
+        let base_url = parse_url_with_raw_path(&self.url)?;
+        let scanned_urls = self.handles.ferox_scans()?;
+
+        for location in WELL_KNOWN_LOCATIONS {
+            let response = match self.make_extract_request(location).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::debug!("could not request {}: {}", location, e);
+                    continue;
+                }
+            };
+
+            if self
+                .handles
+                .filters
+                .data
+                .should_filter_response(&response, self.handles.stats.tx.clone())
+            {
+                continue;
+            }
+
+            self.add_links_from_headers(response.url(), response.headers(), &mut result);
+
+            if response.status().is_success() {
+                for link in links_from_well_known(response.text()) {
+                    if self
+                        .parse_url_and_add_subpaths(&link, &base_url, &mut result)
+                        .is_err()
+                    {
+                        log::debug!("link didn't belong to the target domain/host: {}", link);
+                    }
+                }
+            }
+
+            // the wordlist/extractor shouldn't request it again
+            scanned_urls.add_file_scan(response.url().as_str(), ScanOrder::Latest);
+
+            if let Err(e) = response.send_report(
+                self.handles.output.tx.clone(),
+                self.handles.config.keep_response_bodies(),
+            ) {
+                log::warn!("Could not send FeroxResponse to output handler: {}", e);
+            }
+        }
+
+        log::trace!("exit: extract_from_well_known -> {:?}", result);
+        Ok(result)
+    }
+
+    /// Entry point to perform link extraction from a javascript response's source map
+    ///
+    /// the map's location comes from the `SourceMap` header or `sourceMappingURL` comment, falling
+    /// back to `<script>.map`. When found, the map is reported like any other response, the
+    /// original sources in its `sourcesContent` are searched with the linkfinder regex (relative
+    /// to the root of the script's host), and the sources are written to `--source-map-dir` when
+    /// given
+    pub(super) async fn extract_from_source_map(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_source_map");
+
+        let mut result = ExtractionResult::new();
+
+        let script = self.response.unwrap();
+        let scanned_urls = self.handles.ferox_scans()?;
+
+        let map_url = match source_map_location(script) {
+            Some(url) if url.host() == script.url().host() && url.port() == script.url().port() => {
+                url
+            }
+            _ => {
+                log::trace!("exit: extract_from_source_map -> {:?}", result);
+                return Ok(result);
+            }
+        };
+
+        if scanned_urls.get_scan_by_url(map_url.as_str()).is_some() {
+            // already requested, either by the wordlist or by another script sharing the map
+            log::trace!("exit: extract_from_source_map -> {:?}", result);
+            return Ok(result);
+        }
+
+        // the map is requested at most once, whether or not it turns out to be valid
+        scanned_urls.add_file_scan(map_url.as_str(), ScanOrder::Latest);
+
+        let response = match logged_request(
+            &map_url,
+            DEFAULT_METHOD,
+            None,
+            None,
+            self.handles.clone(),
+        )
+        .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                log::debug!("could not request {}: {}", map_url, e);
+                log::trace!("exit: extract_from_source_map -> {:?}", result);
+                return Ok(result);
+            }
+        };
+
+        let response = FeroxResponse::from(
+            response,
+            script.url().as_str(),
+            DEFAULT_METHOD,
+            self.handles.config.output_level,
+        )
+        .await;
+
+        let map = match parse_source_map(response.text()) {
+            Ok(map) if response.status().is_success() => map,
+            _ => {
+                log::debug!("{} is not a source map", map_url);
+                log::trace!("exit: extract_from_source_map -> {:?}", result);
+                return Ok(result);
+            }
+        };
+
+        log::info!("found source map at {}", map_url);
+
+        for (_, source) in map.sources() {
+            self.extract_all_links_from_javascript(source, script.url(), &mut result);
+        }
+
+        if !self.handles.config.source_map_dir.is_empty() {
+            match save_sources(
+                &map,
+                &map_url,
+                Path::new(&self.handles.config.source_map_dir),
+            ) {
+                Ok(written) => log::info!("saved {} sources from {}", written, map_url),
+                Err(e) => log::warn!("Could not save sources from {}: {}", map_url, e),
+            }
+        }
+
+        if !self
+            .handles
+            .filters
+            .data
+            .should_filter_response(&response, self.handles.stats.tx.clone())
+        {
+            if let Err(e) = response.send_report(
+                self.handles.output.tx.clone(),
+                self.handles.config.keep_response_bodies(),
+            ) {
+                log::warn!("Could not send FeroxResponse to output handler: {}", e);
+            }
+        }
+
+        log::trace!("exit: extract_from_source_map -> {:?}", result);
+        Ok(result)
+    }
+
+    /// Entry point to perform endpoint extraction from an OpenAPI/Swagger specification
+    ///
+    /// the spec is read from `--openapi` when given, otherwise it's requested from a handful of
+    /// well-known locations at the root of the url (i.e. /swagger.json, /openapi.yaml)
+    ///
+    /// returns every sub-path of the spec's endpoints, which are requested with GET and
+    /// considered for recursion, along with the (url, method) pairs of endpoints declaring any
+    /// other methods
+    pub async fn extract_from_openapi(
+        &self,
+    ) -> Result<(ExtractionResult, HashSet<(String, String)>)> {
+        log::trace!("enter: extract_from_openapi");
+
+        let mut result = ExtractionResult::new();
+        let mut endpoints = HashSet::new();
+
+        let spec = if !self.handles.config.openapi.is_empty() {
+            let contents = std::fs::read_to_string(&self.handles.config.openapi)
+                .with_context(|| format!("Could not read {}", self.handles.config.openapi))?;
+
+            Some(
+                parse_spec(&contents)
+                    .with_context(|| format!("Could not parse {}", self.handles.config.openapi))?,
+            )
+        } else {
+            let mut found = None;
+
+            for location in OPENAPI_LOCATIONS {
+                let Ok(response) = self.make_extract_request(location).await else {
+                    continue;
+                };
+
+                self.add_links_from_headers(response.url(), response.headers(), &mut result);
+
+                if !response.status().is_success() {
+                    continue;
+                }
+
+                if let Ok(spec) = parse_spec(response.text()) {
+                    log::info!("found api specification at {}", response.url());
+                    found = Some(spec);
+                    break;
+                }
+            }
+
+            found
+        };
+
+        for endpoint in spec.iter().flat_map(endpoints_from_spec) {
+            if self.add_all_sub_paths(&endpoint.path, &mut result).is_err() {
+                log::warn!(
+                    "could not add sub-paths from {} to {:?}",
+                    endpoint.path,
+                    result
+                );
+            }
+
+            // GET is already covered by the sub-paths, and methods that may change data on the
+            // target are only used when asked for
+            for method in endpoint.methods.iter().filter(|method| {
+                *method != DEFAULT_METHOD
+                    && (self.handles.config.openapi_methods
+                        || SAFE_METHODS.contains(&method.as_str()))
+            }) {
+                let mut url = parse_url_with_raw_path(&self.url)?;
+                url.set_path(&endpoint.path);
+                endpoints.insert((url.to_string(), method.to_owned()));
+            }
+        }
+
+        log::trace!("exit: extract_from_openapi -> {:?} {:?}", result, endpoints);
+        Ok((result, endpoints))
+    }
+
     /// outer-most wrapper for parsing html response bodies in search of additional content.
     /// performs the following high-level steps:
     /// - requests the page, if necessary
     /// - checks the page to see if directory listing is enabled and sucks up all the links, if so
     /// - uses the linkfinder regex to grab links from embedded javascript/javascript files
     /// - extracts many different types of link sources from the html itself
+    /// - records each form's action, method, and fields in the parameter inventory
     pub(super) async fn extract_from_body(&self) -> Result<ExtractionResult> {
         log::trace!("enter: extract_from_body");
 
@@ -561,10 +1065,58 @@ impl<'a> Extractor<'a> {
         self.extract_all_links_from_html_tags(resp_url, &mut result, &html);
         self.extract_all_links_from_javascript(body, resp_url, &mut result);
 
+        for form in params_from_forms(&html) {
+            self.record_parameters(form, resp_url, ParameterSource::Form);
+        }
+
         log::trace!("exit: extract_from_body -> {:?}", result);
         Ok(result)
     }
 
+    /// Entry point to perform link extraction from a response's headers
+    ///
+    /// each link is resolved against the response's url before being scoped to the original
+    /// host and split into its sub-paths, the same as links found in the body
+    pub(super) async fn extract_from_headers(&self) -> Result<ExtractionResult> {
+        log::trace!("enter: extract_from_headers");
+
+        let mut result = ExtractionResult::new();
+
+        let response = self.response.unwrap();
+
+        self.add_links_from_headers(response.url(), response.headers(), &mut result);
+
+        log::trace!("exit: extract_from_headers -> {:?}", result);
+        Ok(result)
+    }
+
+    /// add the links found in the given response headers (Location, Link, etc...), along with
+    /// their sub-paths; relative links are resolved against the response's url
+    ///
+    /// used for the responses handled by the `Requester` (via `extract_from_headers`), as well as
+    /// those requested by the extractor itself (robots.txt, sitemaps, well-known resources and
+    /// api specifications)
+    fn add_links_from_headers(
+        &self,
+        url: &Url,
+        headers: &HeaderMap,
+        result: &mut ExtractionResult,
+    ) {
+        for link in links_from_headers(headers) {
+            let Ok(absolute) = url.join(&link) else {
+                log::debug!("could not join {} with {}", url, link);
+                continue;
+            };
+
+            if self
+                .parse_url_and_add_subpaths(absolute.as_str(), url, result)
+                .is_err()
+            {
+                log::debug!("link didn't belong to the target domain/host: {}", link);
+            }
+        }
+    }
+
     /// parses html response bodies in search of <a> tags.
     ///
     /// the assumption is that directory listing is turned on and this extraction target simply
@@ -621,7 +1173,7 @@ impl<'a> Extractor<'a> {
     ///
     /// example:
     ///     http://localhost/api/users -> http://localhost/<location>
-    pub(super) async fn make_extract_request(&self, location: &str) -> Result<FeroxResponse> {
+    pub async fn make_extract_request(&self, location: &str) -> Result<FeroxResponse> {
         log::trace!("enter: make_extract_request");
 
         // need late binding here to avoid 'creates a temporary which is freed...' in the
@@ -683,6 +1235,7 @@ impl<'a> Extractor<'a> {
             &url,
             DEFAULT_METHOD,
             None,
+            None,
             self.handles.config.output_level,
             &self.handles.config,
             self.handles.stats.tx.clone(),
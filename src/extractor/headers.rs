@@ -0,0 +1,91 @@
+//! pull links out of the response headers that commonly reference other resources on a site
+use reqwest::header::HeaderMap;
+
+/// headers whose entire value is a single url
+const URL_HEADERS: [&str; 2] = ["location", "content-location"];
+
+/// content security policy headers; both are parsed the same way
+const CSP_HEADERS: [&str; 2] = [
+    "content-security-policy",
+    "content-security-policy-report-only",
+];
+
+/// collect every url or path found in the given headers
+///
+/// - `Location` / `Content-Location`: the entire value
+/// - `Link`: each `<url>` (i.e. rel=preload, rel=next)
+/// - `Refresh`: the `url=` portion
+/// - `Content-Security-Policy`: source expressions that are urls or paths (i.e. report-uri,
+///   script-src), with wildcard hosts and keywords ignored
+/// - `Set-Cookie`: the `Path=` attribute, when it's not the root
+///
+/// links are returned as they appear in the headers, meaning they may be relative to the
+/// response's url
+pub(super) fn links_from_headers(headers: &HeaderMap) -> Vec<String> {
+    let mut links = Vec::new();
+
+    let values = |name: &str| {
+        headers
+            .get_all(name)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .map(str::trim)
+            .collect::<Vec<_>>()
+    };
+
+    for name in URL_HEADERS {
+        links.extend(values(name).into_iter().map(String::from));
+    }
+
+    for link in values("link") {
+        for entry in link.split(',') {
+            if let Some((_, rest)) = entry.split_once('<') {
+                if let Some((url, _)) = rest.split_once('>') {
+                    links.push(url.trim().to_string());
+                }
+            }
+        }
+    }
+
+    for refresh in values("refresh") {
+        if let Some(index) = refresh.to_lowercase().find("url=") {
+            let url = refresh[index + 4..]
+                .trim()
+                .trim_matches(|c| c == '\'' || c == '"');
+            links.push(url.to_string());
+        }
+    }
+
+    for name in CSP_HEADERS {
+        for policy in values(name) {
+            for directive in policy.split(';') {
+                // the first token is the directive's name, the rest are its sources
+                for source in directive.split_whitespace().skip(1) {
+                    let is_url = source.starts_with("http://") || source.starts_with("https://");
+
+                    if (is_url || (source.starts_with('/') && !source.starts_with("//")))
+                        && !source.contains('*')
+                    {
+                        links.push(source.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    for cookie in values("set-cookie") {
+        for attribute in cookie.split(';').skip(1) {
+            if let Some((name, path)) = attribute.split_once('=') {
+                let path = path.trim();
+
+                if name.trim().eq_ignore_ascii_case("path") && path.starts_with('/') && path != "/"
+                {
+                    links.push(path.to_string());
+                }
+            }
+        }
+    }
+
+    links.retain(|link| !link.is_empty());
+    links
+}
@@ -1,12 +1,20 @@
-//! extract links from html source and robots.txt
+//! extract links from html source, response headers, robots.txt, sitemaps, /.well-known/
+//! resources, javascript source maps, and OpenAPI/Swagger specifications
 mod builder;
 mod container;
+mod headers;
+mod openapi;
+mod parameters;
+mod sitemap;
+mod source_map;
+mod well_known;
 #[cfg(test)]
 mod tests;
 
 pub use self::builder::ExtractionTarget;
 pub use self::builder::ExtractorBuilder;
 pub use self::container::Extractor;
+pub(crate) use self::source_map::is_javascript;
 
 use crate::response::FeroxResponse;
 use regex::Regex;
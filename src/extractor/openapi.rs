@@ -0,0 +1,155 @@
+//! parse OpenAPI (v3) and Swagger (v2) specifications into the endpoints they describe
+use anyhow::{bail, Result};
+use reqwest::Url;
+use serde_json::Value;
+
+/// well-known locations of api specifications, requested from the root of the target
+pub(super) const OPENAPI_LOCATIONS: [&str; 4] = [
+    "/swagger.json",
+    "/openapi.json",
+    "/swagger.yaml",
+    "/openapi.yaml",
+];
+
+/// keys of a path item object that represent http methods
+const OPERATIONS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// methods that are requested without --openapi-methods; the rest may change data on the target
+pub(super) const SAFE_METHODS: [&str; 3] = ["GET", "HEAD", "OPTIONS"];
+
+/// single endpoint described by an api specification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ApiEndpoint {
+    /// absolute url path of the endpoint, truncated before the first templated segment
+    pub(super) path: String,
+
+    /// http methods declared for the endpoint; always GET for truncated paths
+    pub(super) methods: Vec<String>,
+}
+
+/// parse the given json or yaml text as an OpenAPI/Swagger specification
+pub(super) fn parse_spec(contents: &str) -> Result<Value> {
+    let spec: Value = match serde_json::from_str(contents) {
+        Ok(spec) => spec,
+        Err(_) => serde_norway::from_str(contents)?,
+    };
+
+    if (spec.get("openapi").is_none() && spec.get("swagger").is_none())
+        || !spec.get("paths").is_some_and(Value::is_object)
+    {
+        bail!("not an OpenAPI/Swagger specification");
+    }
+
+    Ok(spec)
+}
+
+/// base paths under which the spec's `paths` are served
+///
+/// - swagger v2: `basePath`
+/// - openapi v3: the path portion of each `servers` url, with server variables replaced by their
+///   default values
+fn base_paths(spec: &Value) -> Vec<String> {
+    let mut bases = Vec::new();
+
+    if let Some(base_path) = spec.get("basePath").and_then(Value::as_str) {
+        bases.push(base_path.to_string());
+    }
+
+    for server in spec
+        .get("servers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let Some(mut url) = server.get("url").and_then(Value::as_str).map(String::from) else {
+            continue;
+        };
+
+        if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+            for (name, variable) in variables {
+                if let Some(default) = variable.get("default").and_then(Value::as_str) {
+                    url = url.replace(&format!("{{{name}}}"), default);
+                }
+            }
+        }
+
+        let path = match Url::parse(&url) {
+            Ok(absolute) => absolute.path().to_string(),
+            Err(_) => url,
+        };
+
+        bases.push(path);
+    }
+
+    if bases.is_empty() {
+        bases.push(String::new());
+    }
+
+    bases
+        .iter()
+        .map(|base| format!("/{}", base.trim_matches('/')))
+        .collect()
+}
+
+/// every endpoint described by the given specification, prefixed by its base path(s)
+///
+/// paths containing a template (i.e. `/users/{id}`) can't be requested as-is, so they're cut off
+/// before the first templated segment and only the resulting directory is returned
+pub(super) fn endpoints(spec: &Value) -> Vec<ApiEndpoint> {
+    let mut endpoints: Vec<ApiEndpoint> = Vec::new();
+
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return endpoints;
+    };
+
+    for base in base_paths(spec) {
+        for (path, item) in paths {
+            let full = format!(
+                "{}/{}",
+                base.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            );
+
+            let endpoint = match full.find('{') {
+                Some(index) => ApiEndpoint {
+                    path: full[..=full[..index].rfind('/').unwrap_or(0)].to_string(),
+                    methods: vec![String::from("GET")],
+                },
+                None => {
+                    let mut methods: Vec<_> = OPERATIONS
+                        .iter()
+                        .filter(|operation| item.get(**operation).is_some())
+                        .map(|operation| operation.to_uppercase())
+                        .collect();
+
+                    if methods.is_empty() {
+                        methods.push(String::from("GET"));
+                    }
+
+                    ApiEndpoint {
+                        path: full,
+                        methods,
+                    }
+                }
+            };
+
+            match endpoints
+                .iter_mut()
+                .find(|known| known.path == endpoint.path)
+            {
+                Some(known) => {
+                    for method in endpoint.methods {
+                        if !known.methods.contains(&method) {
+                            known.methods.push(method);
+                        }
+                    }
+                }
+                None => endpoints.push(endpoint),
+            }
+        }
+    }
+
+    endpoints
+}
@@ -0,0 +1,157 @@
+//! find the parameters accepted by endpoints referenced in html forms and javascript requests
+use lazy_static::lazy_static;
+use regex::Regex;
+use scraper::{Html, Selector};
+
+/// parameters found for a single endpoint, before being resolved against the response's url
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FoundParameters {
+    /// endpoint as it appears in the source; may be relative
+    pub(super) url: String,
+
+    /// http method the parameters are sent with
+    pub(super) method: String,
+
+    /// names of the parameters
+    pub(super) names: Vec<String>,
+}
+
+lazy_static! {
+    /// Regular expression to find requests made via fetch, axios, or jQuery, along with the
+    /// object passed as the second argument (one level of nesting is allowed)
+    static ref JS_REQUEST_REGEX: Regex = Regex::new(
+        r#"(?P<call>fetch|axios\.(?:get|post|put|patch|delete)|\$\.(?:get|post))\(\s*['"`](?P<url>[^'"`]+)['"`]\s*(?:,\s*(?P<object>\{(?:[^{}]|\{[^{}]*\})*\}))?"#
+    )
+    .unwrap();
+
+    /// Regular expression to find XMLHttpRequest.open calls
+    static ref XHR_OPEN_REGEX: Regex = Regex::new(
+        r#"\.open\(\s*['"](?P<method>[A-Za-z]+)['"]\s*,\s*['"`](?P<url>[^'"`]+)['"`]"#
+    )
+    .unwrap();
+
+    /// Regular expression to pull the method out of a fetch options object
+    static ref JS_METHOD_REGEX: Regex =
+        Regex::new(r#"method\s*:\s*['"](?P<method>[A-Za-z]+)['"]"#).unwrap();
+
+    /// Regular expression to pull the body/data/params object out of a request options object,
+    /// i.e. `body: JSON.stringify({ ... })`
+    static ref JS_BODY_REGEX: Regex =
+        Regex::new(r#"(?:body|data|params)\s*:\s*[^{},]*?(?P<object>\{[^{}]*\})"#).unwrap();
+
+    /// Regular expression to validate an object key as something that could be a parameter name
+    static ref JS_KEY_REGEX: Regex = Regex::new(r#"^[A-Za-z_$][\w$.\-\[\]]*$"#).unwrap();
+}
+
+/// every `<form>` in the given document, along with the names of its inputs, selects, and
+/// textareas; forms without an action submit to the page they're on (an empty url)
+pub(super) fn params_from_forms(html: &Html) -> Vec<FoundParameters> {
+    let forms = Selector::parse("form").unwrap();
+    let fields =
+        Selector::parse("input[name], select[name], textarea[name], button[name]").unwrap();
+
+    html.select(&forms)
+        .map(|form| FoundParameters {
+            url: form
+                .value()
+                .attr("action")
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            method: form
+                .value()
+                .attr("method")
+                .unwrap_or("GET")
+                .trim()
+                .to_uppercase(),
+            names: form
+                .select(&fields)
+                .filter_map(|field| field.value().attr("name"))
+                .map(String::from)
+                .collect(),
+        })
+        .collect()
+}
+
+/// keys of the given object literal (including shorthand properties, i.e. `{ username }`),
+/// ignoring the contents of any nested objects
+fn object_keys(object: &str) -> Vec<String> {
+    let mut flat = String::with_capacity(object.len());
+    let mut depth = 0;
+
+    for char in object.chars() {
+        match char {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ if depth == 1 => flat.push(char),
+            _ => {}
+        }
+    }
+
+    flat.split(',')
+        .filter_map(|property| {
+            let key = property
+                .split_once(':')
+                .map_or(property, |(key, _)| key)
+                .trim()
+                .trim_matches(|c| c == '\'' || c == '"');
+
+            JS_KEY_REGEX.is_match(key).then(|| key.to_string())
+        })
+        .collect()
+}
+
+/// every request made from the given javascript via fetch, axios, jQuery, or XMLHttpRequest,
+/// along with the keys of the object sent with it
+///
+/// template literals are cut off before their first substitution, i.e. `/users/${id}` is
+/// returned as `/users/`
+pub(super) fn params_from_javascript(body: &str) -> Vec<FoundParameters> {
+    let mut found = Vec::new();
+
+    for capture in JS_REQUEST_REGEX.captures_iter(body) {
+        let call = &capture["call"];
+        let object = capture.name("object").map_or("", |object| object.as_str());
+
+        let method = match call {
+            "fetch" => JS_METHOD_REGEX
+                .captures(object)
+                .map_or(String::from("GET"), |method| {
+                    method["method"].to_uppercase()
+                }),
+            _ => call.rsplit('.').next().unwrap_or_default().to_uppercase(),
+        };
+
+        let names = match call {
+            // fetch(url, options) / axios.get(url, config) / axios.delete(url, config); the
+            // parameters are nested within the body/data/params of the options
+            "fetch" | "axios.get" | "axios.delete" => JS_BODY_REGEX
+                .captures_iter(object)
+                .flat_map(|body| object_keys(&body["object"]))
+                .collect(),
+            // axios.post(url, data) / $.get(url, data) / etc...
+            _ => object_keys(object),
+        };
+
+        found.push(FoundParameters {
+            url: trim_template(&capture["url"]),
+            method,
+            names,
+        });
+    }
+
+    for capture in XHR_OPEN_REGEX.captures_iter(body) {
+        found.push(FoundParameters {
+            url: trim_template(&capture["url"]),
+            method: capture["method"].to_uppercase(),
+            names: Vec::new(),
+        });
+    }
+
+    found
+}
+
+/// cut a template literal off before its first substitution
+fn trim_template(url: &str) -> String {
+    url.split("${").next().unwrap_or_default().to_string()
+}
@@ -0,0 +1,94 @@
+//! parse sitemaps and sitemap indexes, as described at https://www.sitemaps.org/protocol.html
+use std::io::Read;
+
+use anyhow::{bail, Result};
+use flate2::read::MultiGzDecoder;
+use quick_xml::{events::Event, Reader};
+
+/// default location of a site's sitemap, requested from the root of the target
+pub(super) const SITEMAP_LOCATION: &str = "/sitemap.xml";
+
+/// upper bound on the number of sitemaps requested per target, as sitemap indexes can reference
+/// an enormous number of sitemaps (or each other)
+pub(super) const MAX_SITEMAPS: usize = 50;
+
+/// upper bound on the decompressed size of a gzipped sitemap; the protocol caps sitemaps at 50MB
+/// uncompressed, and anything larger is more likely to be a decompression bomb than a sitemap
+const MAX_SITEMAP_BYTES: u64 = 50 * 1024 * 1024;
+
+/// the two kinds of sitemap documents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Sitemap {
+    /// `<urlset>`; each `<loc>` is a page on the site
+    UrlSet(Vec<String>),
+
+    /// `<sitemapindex>`; each `<loc>` is another sitemap
+    Index(Vec<String>),
+}
+
+/// parse the given (possibly gzipped) bytes as a sitemap
+pub(super) fn parse_sitemap(body: &[u8]) -> Result<Sitemap> {
+    let mut decompressed = Vec::new();
+
+    let xml = if body.starts_with(&[0x1f, 0x8b]) {
+        // one byte past the limit is read, so that a sitemap of exactly the maximum size is
+        // still allowed
+        MultiGzDecoder::new(body)
+            .take(MAX_SITEMAP_BYTES + 1)
+            .read_to_end(&mut decompressed)?;
+
+        if decompressed.len() as u64 > MAX_SITEMAP_BYTES {
+            log::warn!(
+                "stopped decompressing a sitemap after {} bytes",
+                MAX_SITEMAP_BYTES
+            );
+            bail!("decompressed sitemap is larger than {MAX_SITEMAP_BYTES} bytes");
+        }
+
+        decompressed.as_slice()
+    } else {
+        body
+    };
+
+    let mut reader = Reader::from_reader(xml);
+    reader.trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut is_index = None;
+    let mut in_loc = false;
+    let mut locations = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(tag) => match tag.local_name() {
+                b"sitemapindex" => is_index = Some(true),
+                b"urlset" => is_index = Some(false),
+                b"loc" => in_loc = true,
+                _ => {}
+            },
+            Event::End(tag) if tag.local_name() == b"loc" => in_loc = false,
+            Event::Text(text) if in_loc => {
+                locations.push(text.unescape_and_decode(&reader)?);
+            }
+            Event::CData(text) if in_loc => {
+                locations.push(String::from_utf8_lossy(&text).to_string());
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+
+        buf.clear();
+    }
+
+    let locations = locations
+        .iter()
+        .map(|location| location.trim().to_string())
+        .filter(|location| !location.is_empty())
+        .collect();
+
+    match is_index {
+        Some(true) => Ok(Sitemap::Index(locations)),
+        Some(false) => Ok(Sitemap::UrlSet(locations)),
+        None => bail!("not a sitemap"),
+    }
+}
@@ -0,0 +1,150 @@
+//! find, parse, and save javascript source maps, as described at https://sourcemaps.info/spec.html
+use std::{
+    fs::{create_dir_all, write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::response::FeroxResponse;
+
+lazy_static! {
+    /// Regular expression to pull the source map's location out of a `//# sourceMappingURL=`
+    /// comment (or the deprecated `//@` form)
+    static ref SOURCE_MAPPING_URL_REGEX: Regex =
+        Regex::new(r"(?m)^[ \t]*//[#@][ \t]*sourceMappingURL=(?P<url>\S+)").unwrap();
+}
+
+/// the parts of a source map that are of interest
+#[derive(Debug, Default, Deserialize)]
+pub(super) struct SourceMap {
+    /// names of the original sources, i.e. webpack:///./src/api/users.js
+    #[serde(default)]
+    sources: Vec<Option<String>>,
+
+    /// contents of the original sources, in the same order as `sources`
+    #[serde(default, rename = "sourcesContent")]
+    sources_content: Vec<Option<String>>,
+}
+
+impl SourceMap {
+    /// (name, contents) of every source whose contents were included in the map
+    pub(super) fn sources(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.sources
+            .iter()
+            .zip(self.sources_content.iter())
+            .filter_map(|(name, content)| {
+                Some((name.as_deref().unwrap_or(""), content.as_deref()?))
+            })
+    }
+}
+
+/// whether the given response looks like javascript, based on its content-type or extension
+pub(crate) fn is_javascript(response: &FeroxResponse) -> bool {
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    content_type.contains("javascript")
+        || response.url().path().ends_with(".js")
+        || response.url().path().ends_with(".mjs")
+}
+
+/// location of the given javascript response's source map, in order of preference
+///
+/// - `SourceMap` (or the deprecated `X-SourceMap`) header
+/// - `//# sourceMappingURL=` comment
+/// - the script's url with `.map` appended
+///
+/// inline (`data:`) source maps aren't supported and result in `None`
+pub(super) fn source_map_location(response: &FeroxResponse) -> Option<Url> {
+    let header = ["sourcemap", "x-sourcemap"].iter().find_map(|name| {
+        response
+            .headers()
+            .get(*name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from)
+    });
+
+    let comment = || {
+        SOURCE_MAPPING_URL_REGEX
+            .captures_iter(response.text())
+            .last()
+            .map(|capture| capture["url"].to_string())
+    };
+
+    match header.or_else(comment) {
+        Some(location) if location.starts_with("data:") => None,
+        Some(location) => response.url().join(&location).ok(),
+        None => {
+            let mut url = response.url().clone();
+            url.set_query(None);
+            url.set_fragment(None);
+            url.set_path(&format!("{}.map", url.path()));
+            Some(url)
+        }
+    }
+}
+
+/// parse the given text as a source map
+pub(super) fn parse_source_map(text: &str) -> Result<SourceMap> {
+    // maps may be prefixed with )]}' to prevent xssi, which must be skipped
+    let json = text.strip_prefix(")]}'").unwrap_or(text);
+
+    Ok(serde_json::from_str(json)?)
+}
+
+/// turn a source's name (i.e. webpack:///./src/api.js) into a relative path that can't escape
+/// the directory it's joined to
+pub(super) fn sanitize_source_path(name: &str) -> PathBuf {
+    let without_scheme = name.split_once("://").map_or(name, |(_, rest)| rest);
+
+    let path: PathBuf = without_scheme
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty() && *part != "." && *part != "..")
+        .map(|part| part.replace(':', "_"))
+        .collect();
+
+    if path.as_os_str().is_empty() {
+        PathBuf::from("unnamed")
+    } else {
+        path
+    }
+}
+
+/// write the original sources contained in the source map found at `map_url` to
+/// `directory/<host>/<source path>`, returning the number of files written
+pub(super) fn save_sources(map: &SourceMap, map_url: &Url, directory: &Path) -> Result<usize> {
+    let host = match map_url.port() {
+        Some(port) => format!("{}_{}", map_url.host_str().unwrap_or_default(), port),
+        None => map_url.host_str().unwrap_or_default().to_string(),
+    };
+
+    let base = directory.join(sanitize_source_path(&host));
+    let mut written = 0;
+
+    for (index, (name, content)) in map.sources().enumerate() {
+        let relative = if name.is_empty() {
+            PathBuf::from(format!("unnamed-{index}"))
+        } else {
+            sanitize_source_path(name)
+        };
+
+        let path = base.join(relative);
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write(&path, content)?;
+        written += 1;
+    }
+
+    Ok(written)
+}
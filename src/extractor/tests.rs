@@ -1,5 +1,11 @@
-use super::builder::{LINKFINDER_REGEX, ROBOTS_TXT_REGEX, URL_CHARS_REGEX};
+use super::builder::{LINKFINDER_REGEX, ROBOTS_SITEMAP_REGEX, ROBOTS_TXT_REGEX, URL_CHARS_REGEX};
 use super::container::request_link;
+use super::headers::links_from_headers;
+use super::openapi::{endpoints, parse_spec, ApiEndpoint};
+use super::parameters::{params_from_forms, params_from_javascript, FoundParameters};
+use super::sitemap::{parse_sitemap, Sitemap};
+use super::source_map::{parse_source_map, sanitize_source_path, source_map_location};
+use super::well_known::links_from_well_known;
 use super::*;
 use crate::config::{Configuration, OutputLevel};
 use crate::scan_manager::ScanOrder;
@@ -11,7 +17,8 @@ use anyhow::Result;
 use httpmock::{Method::GET, MockServer};
 use lazy_static::lazy_static;
 use reqwest::{Client, StatusCode, Url};
-use std::collections::HashSet;
+use scraper::Html;
+use std::{collections::HashSet, path::PathBuf};
 use tokio::sync::mpsc;
 
 lazy_static! {
@@ -49,6 +56,18 @@ fn setup_extractor(target: ExtractionTarget, scanned_urls: Arc<FeroxScans>) -> E
         ExtractionTarget::DirectoryListing => builder
             .url("http://localhost")
             .target(ExtractionTarget::DirectoryListing),
+        ExtractionTarget::OpenApi => builder
+            .url("http://localhost")
+            .target(ExtractionTarget::OpenApi),
+        ExtractionTarget::WellKnown => builder
+            .url("http://localhost")
+            .target(ExtractionTarget::WellKnown),
+        ExtractionTarget::SourceMap => builder
+            .target(ExtractionTarget::SourceMap)
+            .response(&RESPONSE),
+        ExtractionTarget::ResponseHeaders => builder
+            .target(ExtractionTarget::ResponseHeaders)
+            .response(&RESPONSE),
     };
 
     let config = Arc::new(Configuration::new().unwrap());
@@ -259,6 +278,7 @@ async fn extractor_get_links_with_absolute_url_that_differs_from_target_domain()
         &url,
         DEFAULT_METHOD,
         None,
+        None,
         OutputLevel::Default,
         &config,
         tx_stats.clone(),
@@ -274,6 +294,7 @@ async fn extractor_get_links_with_absolute_url_that_differs_from_target_domain()
     let extractor = Extractor {
         links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
         robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+        sitemap_regex: Regex::new(ROBOTS_SITEMAP_REGEX).unwrap(),
         url_regex: Regex::new(URL_CHARS_REGEX).unwrap(),
         response: Some(&ferox_response),
         url: String::new(),
@@ -303,6 +324,7 @@ async fn request_robots_txt_without_proxy() -> Result<()> {
     let extractor = Extractor {
         links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
         robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+        sitemap_regex: Regex::new(ROBOTS_SITEMAP_REGEX).unwrap(),
         url_regex: Regex::new(URL_CHARS_REGEX).unwrap(),
         response: None,
         url: srv.url("/api/users/stuff/things"),
@@ -361,8 +383,18 @@ async fn request_link_happy_path() -> Result<()> {
         then.status(200).body("this is a test");
     });
 
-    let r_resp = request_link(&srv.url("/login.php"), ROBOTS_EXT.handles.clone()).await?;
-    let b_resp = request_link(&srv.url("/login.php"), BODY_EXT.handles.clone()).await?;
+    let r_resp = request_link(
+        &srv.url("/login.php"),
+        DEFAULT_METHOD,
+        ROBOTS_EXT.handles.clone(),
+    )
+    .await?;
+    let b_resp = request_link(
+        &srv.url("/login.php"),
+        DEFAULT_METHOD,
+        BODY_EXT.handles.clone(),
+    )
+    .await?;
 
     assert!(matches!(r_resp.status(), StatusCode::OK));
     assert!(matches!(b_resp.status(), StatusCode::OK));
@@ -391,11 +423,431 @@ async fn request_link_bails_on_seen_url() -> Result<()> {
     let robots = setup_extractor(ExtractionTarget::RobotsTxt, scans.clone());
     let body = setup_extractor(ExtractionTarget::ResponseBody, scans);
 
-    let r_resp = request_link(&served, robots.handles.clone()).await;
-    let b_resp = request_link(&served, body.handles.clone()).await;
+    let r_resp = request_link(&served, DEFAULT_METHOD, robots.handles.clone()).await;
+    let b_resp = request_link(&served, DEFAULT_METHOD, body.handles.clone()).await;
 
     assert!(r_resp.is_err());
     assert!(b_resp.is_err());
     assert_eq!(mock.hits(), 0); // function exits before requests can happen
     Ok(())
 }
+
+#[test]
+/// swagger v2 specs are read from json, prefixed with basePath, and templated paths are cut off
+/// at the first template
+fn openapi_endpoints_from_swagger_json() {
+    let spec = parse_spec(
+        r#"{
+            "swagger": "2.0",
+            "basePath": "/api/v1/",
+            "paths": {
+                "/users": {"get": {}, "post": {}, "parameters": []},
+                "/users/{id}": {"delete": {}},
+                "/users/{id}/avatar": {"put": {}},
+                "/health": {}
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        endpoints(&spec),
+        vec![
+            ApiEndpoint {
+                path: String::from("/api/v1/health"),
+                methods: vec![String::from("GET")],
+            },
+            ApiEndpoint {
+                path: String::from("/api/v1/users"),
+                methods: vec![String::from("GET"), String::from("POST")],
+            },
+            ApiEndpoint {
+                path: String::from("/api/v1/users/"),
+                methods: vec![String::from("GET")],
+            },
+        ]
+    );
+}
+
+#[test]
+/// openapi v3 specs are read from yaml, and every server's path (with variables expanded) is
+/// used as a base path
+fn openapi_endpoints_from_openapi_yaml() {
+    let spec = parse_spec(
+        r#"
+openapi: 3.0.1
+servers:
+  - url: https://api.example.com/{version}
+    variables:
+      version:
+        default: v2
+  - url: /internal
+paths:
+  /pets:
+    patch:
+      summary: update a pet
+"#,
+    )
+    .unwrap();
+
+    let paths: Vec<_> = endpoints(&spec)
+        .into_iter()
+        .map(|endpoint| (endpoint.path, endpoint.methods))
+        .collect();
+
+    assert_eq!(
+        paths,
+        vec![
+            (String::from("/v2/pets"), vec![String::from("PATCH")]),
+            (String::from("/internal/pets"), vec![String::from("PATCH")]),
+        ]
+    );
+}
+
+#[test]
+/// documents that aren't api specifications are rejected
+fn openapi_parse_spec_rejects_other_documents() {
+    assert!(parse_spec(r#"{"paths": {}}"#).is_err());
+    assert!(parse_spec(r#"{"openapi": "3.0.0"}"#).is_err());
+    assert!(parse_spec("<html>not a spec</html>").is_err());
+}
+
+#[test]
+/// urlsets and sitemap indexes are told apart and their <loc> values returned
+fn parse_sitemap_reads_urlsets_and_indexes() {
+    let urlset = br#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc> http://localhost/about </loc><lastmod>2020-01-01</lastmod></url>
+  <url><loc><![CDATA[http://localhost/blog/post?id=1&amp;x=2]]></loc></url>
+  <url><loc>http://localhost/a?b=1&amp;c=2</loc></url>
+</urlset>"#;
+
+    assert_eq!(
+        parse_sitemap(urlset).unwrap(),
+        Sitemap::UrlSet(vec![
+            String::from("http://localhost/about"),
+            String::from("http://localhost/blog/post?id=1&amp;x=2"),
+            String::from("http://localhost/a?b=1&c=2"),
+        ])
+    );
+
+    let index = br#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>http://localhost/sitemap-pages.xml.gz</loc></sitemap>
+</sitemapindex>"#;
+
+    assert_eq!(
+        parse_sitemap(index).unwrap(),
+        Sitemap::Index(vec![String::from("http://localhost/sitemap-pages.xml.gz")])
+    );
+
+    assert!(parse_sitemap(b"<html><body>not found</body></html>").is_err());
+}
+
+#[test]
+/// gzipped sitemaps are decompressed before being parsed
+fn parse_sitemap_decompresses_gzip() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(b"<urlset><url><loc>http://localhost/gzipped</loc></url></urlset>")
+        .unwrap();
+
+    assert_eq!(
+        parse_sitemap(&encoder.finish().unwrap()).unwrap(),
+        Sitemap::UrlSet(vec![String::from("http://localhost/gzipped")])
+    );
+}
+
+#[test]
+/// gzipped sitemaps that decompress past the size limit are an error instead of being read into
+/// memory in full
+fn parse_sitemap_rejects_oversized_gzip() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(b"<urlset>").unwrap();
+
+    let padding = vec![b' '; 1024 * 1024];
+    for _ in 0..51 {
+        encoder.write_all(&padding).unwrap();
+    }
+
+    encoder.write_all(b"</urlset>").unwrap();
+
+    let error = parse_sitemap(&encoder.finish().unwrap()).unwrap_err();
+    assert!(error.to_string().contains("larger than"));
+}
+
+#[test]
+/// urls are pulled from json well-known resources, including absolute paths with their
+/// wildcards removed
+fn links_from_well_known_json() {
+    let openid = r#"{
+        "issuer": "https://login.example.com",
+        "jwks_uri": "https://login.example.com/oauth/jwks",
+        "scopes_supported": ["openid", "profile"],
+        "applinks": {"details": [{"paths": ["/buy/*", "NOT /private/*", "*"]}]}
+    }"#;
+
+    assert_eq!(
+        links_from_well_known(openid),
+        vec![
+            String::from("/buy/"),
+            String::from("https://login.example.com"),
+            String::from("https://login.example.com/oauth/jwks"),
+        ]
+    );
+}
+
+#[test]
+/// urls are pulled from plain text well-known resources, i.e. security.txt
+fn links_from_well_known_text() {
+    let security_txt = "Contact: mailto:security@example.com\nPolicy: https://example.com/security/policy.html\nHiring: https://example.com/jobs\n";
+
+    assert_eq!(
+        links_from_well_known(security_txt),
+        vec![
+            String::from("https://example.com/security/policy.html"),
+            String::from("https://example.com/jobs"),
+        ]
+    );
+}
+
+#[test]
+/// a script's source map is found via its SourceMap header first, then its sourceMappingURL
+/// comment, and finally by appending .map to the script's url
+fn source_map_location_prefers_header_then_comment() {
+    let mut script: FeroxResponse = serde_json::from_value(serde_json::json!({
+        "url": "http://localhost/static/js/main.js?v=3",
+        "headers": {"sourcemap": "/maps/main.js.map"}
+    }))
+    .unwrap();
+    script.set_text("console.log(1);\n//# sourceMappingURL=main.abc123.js.map\n");
+
+    assert_eq!(
+        source_map_location(&script).unwrap().as_str(),
+        "http://localhost/maps/main.js.map"
+    );
+
+    let mut script: FeroxResponse = serde_json::from_value(serde_json::json!({
+        "url": "http://localhost/static/js/main.js?v=3",
+    }))
+    .unwrap();
+    script.set_text("console.log(1);\n//# sourceMappingURL=main.abc123.js.map\n");
+
+    assert_eq!(
+        source_map_location(&script).unwrap().as_str(),
+        "http://localhost/static/js/main.abc123.js.map"
+    );
+
+    script.set_text("console.log(1);");
+
+    assert_eq!(
+        source_map_location(&script).unwrap().as_str(),
+        "http://localhost/static/js/main.js.map"
+    );
+
+    script.set_text("//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozfQ==");
+
+    assert!(source_map_location(&script).is_none());
+}
+
+#[test]
+/// sources without contents are skipped and an xssi prefix is ignored
+fn parse_source_map_pairs_sources_with_contents() {
+    let map = parse_source_map(
+        r#")]}'{"version":3,"sources":["webpack:///./src/api.js","webpack:///./src/missing.js"],"sourcesContent":["fetch('/api/v2/users')",null],"mappings":""}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        map.sources().collect::<Vec<_>>(),
+        vec![("webpack:///./src/api.js", "fetch('/api/v2/users')")]
+    );
+
+    assert!(parse_source_map("<html></html>").is_err());
+}
+
+#[test]
+/// source names can't be used to write outside of the source map directory
+fn sanitize_source_path_stays_relative() {
+    assert_eq!(
+        sanitize_source_path("webpack:///./src/components/App.jsx"),
+        PathBuf::from("src/components/App.jsx")
+    );
+    assert_eq!(
+        sanitize_source_path("../../../etc/passwd"),
+        PathBuf::from("etc/passwd")
+    );
+    assert_eq!(
+        sanitize_source_path("C:\\project\\index.ts"),
+        PathBuf::from("C_/project/index.ts")
+    );
+    assert_eq!(
+        sanitize_source_path("webpack:///"),
+        PathBuf::from("unnamed")
+    );
+}
+
+#[test]
+/// links are pulled from each of the supported headers, ignoring csp keywords/wildcards and
+/// cookies scoped to the root
+fn links_from_headers_reads_supported_headers() {
+    let mut headers = reqwest::header::HeaderMap::new();
+
+    headers.insert("location", "/login?next=%2F".parse().unwrap());
+    headers.insert("content-location", "index.en.html".parse().unwrap());
+    headers.insert(
+        "link",
+        "</static/app.css>; rel=preload; as=style, <https://localhost/page/2>; rel=\"next\""
+            .parse()
+            .unwrap(),
+    );
+    headers.insert("refresh", "5; URL='/maintenance/'".parse().unwrap());
+    headers.insert(
+        "content-security-policy",
+        "default-src 'self'; script-src 'self' /js/ https://*.cdn.com; report-uri /csp/report"
+            .parse()
+            .unwrap(),
+    );
+    headers.append(
+        "set-cookie",
+        "session=abc; Path=/; HttpOnly".parse().unwrap(),
+    );
+    headers.append(
+        "set-cookie",
+        "admin=1; path=/admin/panel; Secure".parse().unwrap(),
+    );
+
+    assert_eq!(
+        links_from_headers(&headers),
+        vec![
+            String::from("/login?next=%2F"),
+            String::from("index.en.html"),
+            String::from("/static/app.css"),
+            String::from("https://localhost/page/2"),
+            String::from("/maintenance/"),
+            String::from("/js/"),
+            String::from("/csp/report"),
+            String::from("/admin/panel"),
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// header links are resolved against the response's url and scoped to the original host
+async fn extractor_extract_from_headers_resolves_and_scopes_links() {
+    let mut response: FeroxResponse = serde_json::from_value(serde_json::json!({
+        "url": "http://localhost/docs/guide",
+        "headers": {
+            "location": "intro/",
+            "content-security-policy": "report-uri https://csp.example.com/report"
+        }
+    }))
+    .unwrap();
+    response.set_text("");
+
+    let config = Arc::new(Configuration::new().unwrap());
+    let handles = Arc::new(Handles::for_testing(None, Some(config)).0);
+
+    let extractor = ExtractorBuilder::default()
+        .target(ExtractionTarget::ResponseHeaders)
+        .response(&response)
+        .handles(handles)
+        .build()
+        .unwrap();
+
+    let links = extractor.extract().await.unwrap();
+
+    assert_eq!(
+        links,
+        HashSet::from([
+            String::from("http://localhost/docs/"),
+            String::from("http://localhost/docs/intro"),
+        ])
+    );
+}
+
+#[test]
+/// forms are returned with their action, method (defaulting to GET), and named fields
+fn params_from_forms_reads_actions_methods_and_fields() {
+    let html = Html::parse_document(
+        r#"<form action="/login" method="post">
+            <input name="username"><input type="password" name="password">
+            <input type="submit" value="go"><select name="remember"></select>
+        </form>
+        <form><textarea name="comment"></textarea></form>"#,
+    );
+
+    assert_eq!(
+        params_from_forms(&html),
+        vec![
+            FoundParameters {
+                url: String::from("/login"),
+                method: String::from("POST"),
+                names: vec![
+                    String::from("username"),
+                    String::from("password"),
+                    String::from("remember"),
+                ],
+            },
+            FoundParameters {
+                url: String::new(),
+                method: String::from("GET"),
+                names: vec![String::from("comment")],
+            },
+        ]
+    );
+}
+
+#[test]
+/// requests made via fetch, axios, jQuery, and XMLHttpRequest are found along with the keys of
+/// the objects sent with them
+fn params_from_javascript_reads_request_objects() {
+    let js = r#"
+        fetch("/api/login", { method: "POST", headers: {"Content-Type": "application/json"}, body: JSON.stringify({ username: u, "password": p }) });
+        axios.get(`/api/users/${id}`, { params: { expand: true, fields } });
+        axios.post('/api/comments', { text: t, post_id: 1 });
+        $.post("/legacy/save.php", { item: 1 });
+        xhr.open("DELETE", "/api/session?token=abc");
+    "#;
+
+    let found = params_from_javascript(js);
+
+    let summary: Vec<_> = found
+        .iter()
+        .map(|found| {
+            (
+                found.url.as_str(),
+                found.method.as_str(),
+                found.names.clone(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "/api/login",
+                "POST",
+                vec![String::from("username"), String::from("password")]
+            ),
+            (
+                "/api/users/",
+                "GET",
+                vec![String::from("expand"), String::from("fields")]
+            ),
+            (
+                "/api/comments",
+                "POST",
+                vec![String::from("text"), String::from("post_id")]
+            ),
+            ("/legacy/save.php", "POST", vec![String::from("item")]),
+            ("/api/session?token=abc", "DELETE", vec![]),
+        ]
+    );
+}
@@ -0,0 +1,75 @@
+//! curated set of well-known uris (rfc 8615) and the logic to pull links out of their contents
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+
+/// well-known resources requested from the root of each initial target
+pub(super) const WELL_KNOWN_LOCATIONS: [&str; 7] = [
+    "/.well-known/security.txt",
+    "/.well-known/openid-configuration",
+    "/.well-known/oauth-authorization-server",
+    "/.well-known/assetlinks.json",
+    "/.well-known/apple-app-site-association",
+    "/.well-known/change-password",
+    "/.well-known/jwks.json",
+];
+
+lazy_static! {
+    /// Regular expression to pull absolute urls out of plain text well-known resources, i.e. the
+    /// Contact/Policy/Hiring fields of security.txt
+    static ref TEXT_URL_REGEX: Regex = Regex::new(r#"https?://[^\s<>"']+"#).unwrap();
+}
+
+/// collect every url or absolute path found in the given well-known resource
+///
+/// json documents (openid-configuration, assetlinks.json, etc...) are walked and any string that
+/// looks like a url or an absolute path is returned, with wildcards (i.e. `/buy/*` in an
+/// apple-app-site-association) cut off; anything else is searched for absolute urls
+pub(super) fn links_from_well_known(body: &str) -> Vec<String> {
+    let mut links = Vec::new();
+
+    match serde_json::from_str::<Value>(body) {
+        Ok(json) => collect_json_links(&json, &mut links),
+        Err(_) => {
+            for link in TEXT_URL_REGEX.find_iter(body) {
+                links.push(link.as_str().to_string());
+            }
+        }
+    }
+
+    links
+}
+
+/// recursively collect url-looking strings from a json value
+fn collect_json_links(value: &Value, links: &mut Vec<String>) {
+    match value {
+        Value::String(string) => {
+            let string = string.trim();
+
+            let candidate = match string.find(['*', '?']) {
+                Some(index) if string.starts_with('/') => &string[..index],
+                _ => string,
+            };
+
+            if candidate.starts_with("http://")
+                || candidate.starts_with("https://")
+                || (candidate.starts_with('/')
+                    && !candidate.starts_with("//")
+                    && candidate.len() > 1)
+            {
+                links.push(candidate.to_string());
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_json_links(value, links);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values() {
+                collect_json_links(value, links);
+            }
+        }
+        _ => {}
+    }
+}
@@ -25,7 +25,7 @@ pub(crate) async fn create_similarity_filter(
     let url = parse_url_with_raw_path(similarity_filter)?;
 
     // attempt to request the given url
-    let resp = logged_request(&url, DEFAULT_METHOD, None, handles.clone()).await?;
+    let resp = logged_request(&url, DEFAULT_METHOD, None, None, handles.clone()).await?;
 
     // if successful, create a filter based on the response's body
     let mut fr = FeroxResponse::from(
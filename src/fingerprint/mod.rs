@@ -0,0 +1,403 @@
+//! technology fingerprinting (--fingerprint); every response is matched against a database of
+//! signatures that ships with feroxbuster and can be extended with a user-supplied TOML file
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::{Arc, OnceLock};
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use regex::Regex;
+use reqwest::{header::SET_COOKIE, Url};
+use scraper::{Html, Selector};
+use serde::Deserialize;
+
+use crate::{
+    event_handlers::Handles,
+    response::FeroxResponse,
+    scan_manager::{Evidence, Technology},
+    scanner::TECHNOLOGIES,
+    utils::make_request,
+};
+
+/// signature database that ships with feroxbuster
+const EMBEDDED_SIGNATURES: &str = include_str!("signatures.toml");
+
+/// number of base64 characters per line when hashing a favicon; matches python's
+/// `base64.encodebytes`, which is what Shodan's favicon hashes are built from
+const FAVICON_LINE_LENGTH: usize = 76;
+
+/// signatures loaded by `load_signatures`, empty when --fingerprint wasn't used
+static SIGNATURES: OnceLock<Vec<Signature>> = OnceLock::new();
+
+/// a file full of signatures, as written in signatures.toml
+#[derive(Debug, Default, Deserialize)]
+struct SignatureFile {
+    /// every signature in the file
+    #[serde(default)]
+    signature: Vec<SignatureSpec>,
+}
+
+/// a single signature, as written in signatures.toml
+#[derive(Debug, Default, Deserialize)]
+struct SignatureSpec {
+    /// name of the technology
+    name: String,
+
+    /// header name => regex matched against its value
+    #[serde(default)]
+    headers: HashMap<String, String>,
+
+    /// names of cookies set by the technology
+    #[serde(default)]
+    cookies: Vec<String>,
+
+    /// regexes matched against the body
+    #[serde(default)]
+    body: Vec<String>,
+
+    /// regex matched against the content of `<meta name="generator">`
+    meta: Option<String>,
+
+    /// mmh3 hashes of the base64 encoded favicon
+    #[serde(default)]
+    favicon: Vec<i32>,
+}
+
+/// a signature, ready to be matched against responses
+#[derive(Debug)]
+pub(crate) struct Signature {
+    /// name of the technology
+    name: String,
+
+    /// lowercased header name => regex matched against its value
+    headers: Vec<(String, Regex)>,
+
+    /// lowercased names of cookies set by the technology
+    cookies: Vec<String>,
+
+    /// regexes matched against the body
+    body: Vec<Regex>,
+
+    /// regex matched against the content of `<meta name="generator">`
+    meta: Option<Regex>,
+
+    /// mmh3 hashes of the base64 encoded favicon
+    favicon: Vec<i32>,
+}
+
+/// compile a signature's regex; matching is always case-insensitive
+fn compile(name: &str, pattern: &str) -> Result<Regex> {
+    Regex::new(&format!("(?i){pattern}"))
+        .with_context(|| format!("Invalid regex in the {name} signature: {pattern}"))
+}
+
+/// implementation of Signature
+impl Signature {
+    /// compile the regexes of the given spec
+    fn from_spec(spec: SignatureSpec) -> Result<Self> {
+        if spec.name.is_empty() {
+            bail!("Every signature needs a name");
+        }
+
+        let name = spec.name;
+
+        let headers = spec
+            .headers
+            .iter()
+            .map(|(header, pattern)| Ok((header.to_lowercase(), compile(&name, pattern)?)))
+            .collect::<Result<_>>()?;
+
+        let body = spec
+            .body
+            .iter()
+            .map(|pattern| compile(&name, pattern))
+            .collect::<Result<_>>()?;
+
+        let meta = spec
+            .meta
+            .as_deref()
+            .map(|pattern| compile(&name, pattern))
+            .transpose()?;
+
+        Ok(Self {
+            headers,
+            cookies: spec.cookies.iter().map(|c| c.to_lowercase()).collect(),
+            body,
+            meta,
+            favicon: spec.favicon,
+            name,
+        })
+    }
+}
+
+/// parse and compile every signature in the given TOML
+pub(crate) fn parse_signatures(toml: &str) -> Result<Vec<Signature>> {
+    let file: SignatureFile = toml::from_str(toml)?;
+
+    file.signature
+        .into_iter()
+        .map(Signature::from_spec)
+        .collect()
+}
+
+/// load the embedded signatures along with any found in the file at `path`; only the first call
+/// has any effect
+pub fn load_signatures(path: &str) -> Result<()> {
+    let mut signatures =
+        parse_signatures(EMBEDDED_SIGNATURES).context("Could not parse embedded signatures")?;
+
+    if !path.is_empty() {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read signatures from {path}"))?;
+
+        signatures.extend(
+            parse_signatures(&contents)
+                .with_context(|| format!("Could not parse signatures from {path}"))?,
+        );
+    }
+
+    let _ = SIGNATURES.set(signatures);
+    Ok(())
+}
+
+/// signatures to match responses against, empty when --fingerprint wasn't used
+fn signatures() -> &'static [Signature] {
+    SIGNATURES.get().map_or(&[], Vec::as_slice)
+}
+
+/// the version captured by a regex's first group, if it captured anything
+fn captured_version(regex: &Regex, haystack: &str) -> Option<Option<String>> {
+    let captures = regex.captures(haystack)?;
+
+    Some(
+        captures
+            .get(1)
+            .map(|version| version.as_str().to_string())
+            .filter(|version| !version.is_empty()),
+    )
+}
+
+/// content of every `<meta name="generator">` tag in the given html
+fn meta_generators(html: &str) -> Vec<String> {
+    if !html.to_lowercase().contains("generator") {
+        // skip parsing the document when it can't possibly have a generator tag
+        return Vec::new();
+    }
+
+    let selector = Selector::parse("meta[name][content]").unwrap();
+
+    Html::parse_document(html)
+        .select(&selector)
+        .filter(|meta| {
+            meta.value()
+                .attr("name")
+                .is_some_and(|name| name.eq_ignore_ascii_case("generator"))
+        })
+        .filter_map(|meta| meta.value().attr("content"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// every technology the given response matches, along with the version it disclosed, if any
+pub(crate) fn detect(
+    signatures: &[Signature],
+    response: &FeroxResponse,
+) -> Vec<(String, Option<String>, Evidence)> {
+    let cookies: Vec<String> = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| value.split_once('='))
+        .map(|(name, _)| name.trim().to_lowercase())
+        .collect();
+
+    let generators = meta_generators(response.text());
+
+    let mut detected = Vec::new();
+
+    for signature in signatures {
+        for (header, regex) in &signature.headers {
+            for value in response.headers().get_all(header.as_str()) {
+                let Ok(value) = value.to_str() else {
+                    continue;
+                };
+
+                if let Some(version) = captured_version(regex, value) {
+                    detected.push((signature.name.clone(), version, Evidence::Header));
+                }
+            }
+        }
+
+        if signature
+            .cookies
+            .iter()
+            .any(|cookie| cookies.contains(cookie))
+        {
+            detected.push((signature.name.clone(), None, Evidence::Cookie));
+        }
+
+        for regex in &signature.body {
+            if let Some(version) = captured_version(regex, response.text()) {
+                detected.push((signature.name.clone(), version, Evidence::Body));
+            }
+        }
+
+        if let Some(regex) = &signature.meta {
+            for generator in &generators {
+                if let Some(version) = captured_version(regex, generator) {
+                    detected.push((signature.name.clone(), version, Evidence::Meta));
+                }
+            }
+        }
+    }
+
+    detected
+}
+
+/// the host (and port, when it isn't the scheme's default) technologies are aggregated by
+fn host_of(url: &Url) -> String {
+    match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        _ => url.to_string(),
+    }
+}
+
+/// match the given response against every signature, adding what's detected to the technology
+/// inventory
+pub(crate) fn fingerprint(response: &FeroxResponse) {
+    let host = match response.vhost() {
+        Some(vhost) => vhost.to_string(),
+        None => host_of(response.url()),
+    };
+
+    for (name, version, evidence) in detect(signatures(), response) {
+        log::debug!("detected {name} {version:?} on {host} by its {evidence:?}");
+        TECHNOLOGIES.add(&host, Technology::new(&name, version.as_deref(), evidence));
+    }
+}
+
+/// hash favicon bytes the same way Shodan does, i.e. mmh3 of the base64 encoded bytes, with a
+/// newline after every 76 characters and at the end
+pub(crate) fn favicon_hash(bytes: &[u8]) -> i32 {
+    let encoded = STANDARD.encode(bytes);
+
+    let mut wrapped =
+        String::with_capacity(encoded.len() + encoded.len() / FAVICON_LINE_LENGTH + 1);
+
+    for line in encoded.as_bytes().chunks(FAVICON_LINE_LENGTH) {
+        wrapped.push_str(&String::from_utf8_lossy(line));
+        wrapped.push('\n');
+    }
+
+    // reading from an in-memory buffer can't fail
+    murmur3::murmur3_32(&mut Cursor::new(wrapped), 0).unwrap_or_default() as i32
+}
+
+/// request the favicon at the root of the given target and match its hash against every
+/// signature, adding what's detected to the technology inventory
+pub(crate) async fn fingerprint_favicon(target_url: &str, handles: Arc<Handles>) -> Result<()> {
+    let url = Url::parse(target_url)?.join("/favicon.ico")?;
+
+    let response = make_request(
+        &handles.config.client,
+        &url,
+        "GET",
+        None,
+        None,
+        handles.config.output_level,
+        &handles.config,
+        handles.stats.tx.clone(),
+    )
+    .await?;
+
+    if !response.status().is_success() {
+        return Ok(());
+    }
+
+    let hash = favicon_hash(&response.bytes().await?);
+    let host = host_of(&url);
+
+    for signature in signatures() {
+        if signature.favicon.contains(&hash) {
+            log::debug!("detected {} on {host} by its favicon", signature.name);
+            TECHNOLOGIES.add(
+                &host,
+                Technology::new(&signature.name, None, Evidence::Favicon),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// the embedded database is valid, which is otherwise only found out at runtime
+    fn embedded_signatures_parse() {
+        let signatures = parse_signatures(EMBEDDED_SIGNATURES).unwrap();
+        assert!(signatures.iter().any(|s| s.name == "nginx"));
+    }
+
+    #[test]
+    /// a bad regex is reported along with the name of the signature it's in
+    fn parse_signatures_reports_invalid_regex() {
+        let err = parse_signatures("[[signature]]\nname = \"broken\"\nbody = ['(']\n")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("broken"));
+    }
+
+    #[test]
+    /// versions come from the first capture group, when it captured something
+    fn captured_version_uses_first_group() {
+        let regex = compile("test", r"nginx(?:/([\d.]+))?").unwrap();
+
+        assert_eq!(
+            captured_version(&regex, "nginx/1.18.0"),
+            Some(Some(String::from("1.18.0")))
+        );
+        assert_eq!(captured_version(&regex, "NGINX"), Some(None));
+        assert_eq!(captured_version(&regex, "Apache"), None);
+    }
+
+    #[test]
+    /// generator tags are found regardless of the case of their name attribute
+    fn meta_generators_finds_generator_tags() {
+        let html = r#"<html><head><meta NAME="Generator" content="WordPress 6.1.1"><meta name="description" content="x"></head></html>"#;
+
+        assert_eq!(meta_generators(html), ["WordPress 6.1.1"]);
+    }
+
+    #[test]
+    /// the base64 is wrapped the same way python's base64.encodebytes wraps it, and the hash is
+    /// signed, the same as python's mmh3.hash
+    fn favicon_hash_matches_mmh3_of_wrapped_base64() {
+        /// hash an already wrapped string
+        fn mmh3(data: &str) -> i32 {
+            murmur3::murmur3_32(&mut Cursor::new(data), 0).unwrap() as i32
+        }
+
+        assert_eq!(mmh3("foo"), -156908512);
+        assert_eq!(favicon_hash(b"hello"), mmh3("aGVsbG8=\n"));
+        assert_eq!(
+            favicon_hash(&[0; 60]),
+            mmh3(&format!("{}\nAAAA\n", "A".repeat(76)))
+        );
+    }
+
+    #[test]
+    /// the default port isn't part of the host
+    fn host_of_only_includes_explicit_port() {
+        let url = Url::parse("https://localhost/admin").unwrap();
+        assert_eq!(host_of(&url), "localhost");
+
+        let url = Url::parse("http://localhost:8080/admin").unwrap();
+        assert_eq!(host_of(&url), "localhost:8080");
+    }
+}
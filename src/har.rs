@@ -0,0 +1,620 @@
+//! read HTTP Archive (HAR) files, i.e. the network log exported from a browser or proxy, and turn
+//! the requests found within into scan targets and already-known responses; reported findings
+//! can also be exported as a HAR file of their own (--har-out)
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufReader, Seek, SeekFrom, Write},
+    path::Path,
+    time::SystemTime,
+};
+
+use anyhow::{Context, Result};
+use reqwest::{
+    header::{CONTENT_TYPE, LOCATION},
+    Url,
+};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::{config::Configuration, response::FeroxResponse, VERSION};
+
+/// closes the `entries` array and the rest of the document, see `HarWriter`
+const HAR_TRAILER: &[u8] = b"]}}\n";
+
+/// request headers that describe a single request/connection rather than the session, and
+/// shouldn't be copied into every request made during the scan
+const SKIPPED_HEADERS: [&str; 10] = [
+    "host",
+    "cookie",
+    "content-length",
+    "content-type",
+    "connection",
+    "accept-encoding",
+    "if-none-match",
+    "if-modified-since",
+    "range",
+    "upgrade-insecure-requests",
+];
+
+/// top-level HAR document
+#[derive(Debug, Default, Deserialize)]
+struct Har {
+    /// the only member of a HAR document
+    #[serde(default)]
+    log: HarLog,
+}
+
+/// `log` object of a HAR document
+#[derive(Debug, Default, Deserialize)]
+struct HarLog {
+    /// every request/response pair recorded
+    #[serde(default)]
+    entries: Vec<HarEntry>,
+}
+
+/// name/value pair used by HAR for headers, cookies, and query strings
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HarPair {
+    /// header/cookie name
+    #[serde(default)]
+    pub name: String,
+
+    /// header/cookie value
+    #[serde(default)]
+    pub value: String,
+}
+
+/// `request` object of a HAR entry
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HarRequest {
+    /// HTTP method, i.e. GET
+    #[serde(default)]
+    pub method: String,
+
+    /// absolute url of the request
+    #[serde(default)]
+    pub url: String,
+
+    /// request headers
+    #[serde(default)]
+    pub headers: Vec<HarPair>,
+
+    /// request cookies
+    #[serde(default)]
+    pub cookies: Vec<HarPair>,
+}
+
+/// `content` object of a HAR response
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HarContent {
+    /// length of the returned content in bytes
+    #[serde(default)]
+    pub size: i64,
+}
+
+/// `response` object of a HAR entry
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HarResponse {
+    /// response status; 0 when the request never completed
+    #[serde(default)]
+    pub status: u16,
+
+    /// response headers
+    #[serde(default)]
+    pub headers: Vec<HarPair>,
+
+    /// details about the response body
+    #[serde(default)]
+    pub content: HarContent,
+}
+
+/// single request/response pair recorded in a HAR file
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct HarEntry {
+    /// the request that was made
+    #[serde(default)]
+    pub request: HarRequest,
+
+    /// the response that was received
+    #[serde(default)]
+    pub response: HarResponse,
+}
+
+impl HarEntry {
+    /// parsed request url, only `Some` for http(s) urls (i.e. `data:` and `blob:` are skipped)
+    pub fn url(&self) -> Option<Url> {
+        let url = Url::parse(&self.request.url).ok()?;
+
+        matches!(url.scheme(), "http" | "https").then_some(url)
+    }
+
+    /// url of the directory containing the requested resource, i.e. `/js/app.js` -> `/js/`
+    pub fn parent_directory(&self) -> Option<String> {
+        let mut url = self.url()?;
+
+        url.set_query(None);
+        url.set_fragment(None);
+
+        if !url.path().ends_with('/') {
+            // join with `.` drops the final path segment
+            url = url.join(".").ok()?;
+        }
+
+        Some(url.to_string())
+    }
+
+    /// convert the recorded response into a `FeroxResponse` so it can be treated as already
+    /// known; `None` when the request never received a response
+    pub fn to_response(&self) -> Option<FeroxResponse> {
+        if self.response.status == 0 {
+            return None;
+        }
+
+        let url = self.url()?;
+
+        let headers: Map<String, Value> = self
+            .response
+            .headers
+            .iter()
+            .map(|header| (header.name.to_lowercase(), json!(header.value)))
+            .collect();
+
+        let value = json!({
+            "url": url.as_str(),
+            "original_url": url.as_str(),
+            "status": self.response.status,
+            "method": self.request.method.to_uppercase(),
+            "content_length": self.response.content.size.max(0),
+            "headers": headers,
+        });
+
+        serde_json::from_value(value).ok()
+    }
+}
+
+/// read the HAR file at the given path and return all of its entries
+pub fn read_har_file<P: AsRef<Path>>(path: P) -> Result<Vec<HarEntry>> {
+    let path = path.as_ref();
+    log::trace!("enter: read_har_file({})", path.display());
+
+    let file =
+        File::open(path).with_context(|| format!("Could not open HAR file {}", path.display()))?;
+
+    let har: Har = serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("Could not parse HAR file {}", path.display()))?;
+
+    log::trace!("exit: read_har_file -> {} entries", har.log.entries.len());
+    Ok(har.log.entries)
+}
+
+/// only keep the entries whose request was made to the same origin (scheme, host and port) as
+/// `target_url`; when `target_url` is empty, the origin with the most requests is used instead,
+/// ties going to the origin seen first
+///
+/// browser sessions are full of requests to CDNs, analytics and SSO providers; they shouldn't be
+/// scanned, and their headers shouldn't be sent to the target (or vice versa)
+pub fn same_origin_entries(entries: Vec<HarEntry>, target_url: &str) -> Vec<HarEntry> {
+    let origin = match Url::parse(target_url) {
+        Ok(url) => Some(url.origin()),
+        Err(_) => {
+            let mut counts = HashMap::new();
+
+            for (idx, origin) in entries
+                .iter()
+                .filter_map(|entry| Some(entry.url()?.origin()))
+                .enumerate()
+            {
+                // the first index is kept so that ties go to the first origin seen
+                counts.entry(origin).or_insert((0, idx)).0 += 1;
+            }
+
+            counts
+                .into_iter()
+                .max_by_key(|(_, (count, first))| (*count, std::cmp::Reverse(*first)))
+                .map(|(origin, _)| origin)
+        }
+    };
+
+    let Some(origin) = origin else {
+        return Vec::new();
+    };
+
+    log::info!("using HAR entries for {}", origin.ascii_serialization());
+
+    entries
+        .into_iter()
+        .filter(|entry| entry.url().is_some_and(|url| url.origin() == origin))
+        .collect()
+}
+
+/// unique parent directories of every http(s) request in the given entries, in the order in
+/// which they were first seen
+pub fn parent_directories(entries: &[HarEntry]) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    entries
+        .iter()
+        .filter_map(HarEntry::parent_directory)
+        .filter(|directory| seen.insert(directory.clone()))
+        .collect()
+}
+
+/// headers worth reusing for the rest of the scan, the first value seen for each name wins
+///
+/// cookies from every entry are combined into a single `Cookie` header, again with the first
+/// value seen for a given cookie name winning. The entries should already be limited to the
+/// target's origin, see [`same_origin_entries`]
+pub fn session_headers(entries: &[HarEntry]) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+    let mut headers = Vec::new();
+    let mut cookies = Vec::new();
+
+    for entry in entries.iter().filter(|entry| entry.url().is_some()) {
+        for header in &entry.request.headers {
+            let name = header.name.to_lowercase();
+
+            if name.starts_with(':') || SKIPPED_HEADERS.contains(&name.as_str()) {
+                // http/2 pseudo-headers and per-request headers
+                continue;
+            }
+
+            if seen.insert(name) {
+                headers.push((header.name.clone(), header.value.clone()));
+            }
+        }
+
+        for cookie in &entry.request.cookies {
+            if !cookies
+                .iter()
+                .any(|(name, _): &(String, String)| *name == cookie.name)
+            {
+                cookies.push((cookie.name.clone(), cookie.value.clone()));
+            }
+        }
+    }
+
+    if !cookies.is_empty() {
+        let cookie = cookies
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        headers.push((String::from("Cookie"), cookie));
+    }
+
+    headers
+}
+
+/// name/value pairs as HAR represents them, i.e. `[{"name": "Host", "value": "localhost"}]`
+fn har_pairs<N, V>(pairs: impl IntoIterator<Item = (N, V)>) -> Vec<Value>
+where
+    N: AsRef<str>,
+    V: AsRef<str>,
+{
+    pairs
+        .into_iter()
+        .map(|(name, value)| json!({"name": name.as_ref(), "value": value.as_ref()}))
+        .collect()
+}
+
+/// HAR entry of the given response, along with the request that produced it; the body is only
+/// included when `include_body` is true
+///
+/// feroxbuster doesn't time its requests, so `time` and `timings` are always 0, and
+/// `startedDateTime` is when the entry was built
+pub(crate) fn har_entry(
+    response: &FeroxResponse,
+    config: &Configuration,
+    include_body: bool,
+) -> Value {
+    let url = response.url();
+
+    let request_headers = response.request_headers(config);
+    let body = response.sent_body().unwrap_or_default();
+
+    let mut request = json!({
+        "method": response.method().as_str(),
+        "url": url.as_str(),
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": har_pairs(request_headers.iter().map(|(name, value)| (name, value))),
+        "queryString": har_pairs(url.query_pairs()),
+        "headersSize": -1,
+        "bodySize": body.len(),
+    });
+
+    if !body.is_empty() {
+        let mime_type = request_headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+            .map_or("", |(_, value)| value.as_str());
+
+        request["postData"] = json!({
+            "mimeType": mime_type,
+            "text": String::from_utf8_lossy(body),
+        });
+    }
+
+    let header_value = |name| {
+        response
+            .headers()
+            .get(name)
+            .map(|value| String::from_utf8_lossy(value.as_bytes()).to_string())
+            .unwrap_or_default()
+    };
+
+    let mut content = json!({
+        "size": response.content_length(),
+        "mimeType": header_value(CONTENT_TYPE),
+    });
+
+    if include_body {
+        content["text"] = json!(response.text());
+    }
+
+    json!({
+        "startedDateTime": humantime::format_rfc3339_millis(SystemTime::now()).to_string(),
+        "time": 0,
+        "request": request,
+        "response": {
+            "status": response.status().as_u16(),
+            "statusText": response.status().canonical_reason().unwrap_or_default(),
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": har_pairs(response.headers().iter().map(|(name, value)| {
+                (name.as_str(), String::from_utf8_lossy(value.as_bytes()))
+            })),
+            "content": content,
+            "redirectURL": header_value(LOCATION),
+            "headersSize": -1,
+            "bodySize": response.content_length(),
+        },
+        "cache": {},
+        "timings": {"send": 0, "wait": 0, "receive": 0},
+    })
+}
+
+/// Writes a HAR 1.2 log (--har-out) one entry at a time
+///
+/// the document is closed after every entry, and the next entry is written over the closing
+/// brackets, so the file on disk is always a valid HAR document, even when the scan is cut short
+#[derive(Debug)]
+pub(crate) struct HarWriter {
+    /// the HAR file
+    file: File,
+
+    /// offset of the closing brackets, where the next entry goes
+    end: u64,
+
+    /// number of entries written so far
+    entries: usize,
+}
+
+/// implementation of HarWriter
+impl HarWriter {
+    /// create (or truncate) the HAR file at the given path and write an empty log to it
+    pub(crate) fn create(path: &str) -> Result<Self> {
+        let mut file =
+            File::create(path).with_context(|| format!("Could not create HAR file {path}"))?;
+
+        let creator = json!({"name": "feroxbuster", "version": VERSION});
+        write!(
+            file,
+            r#"{{"log":{{"version":"1.2","creator":{creator},"entries":["#
+        )?;
+
+        let end = file.stream_position()?;
+        file.write_all(HAR_TRAILER)?;
+        file.flush()?;
+
+        Ok(Self {
+            file,
+            end,
+            entries: 0,
+        })
+    }
+
+    /// append the given entry to the log, closing the document behind it
+    pub(crate) fn write(&mut self, entry: &Value) -> Result<()> {
+        let mut serialized = if self.entries == 0 {
+            Vec::new()
+        } else {
+            vec![b',']
+        };
+
+        serialized.extend(serde_json::to_vec(entry)?);
+
+        // entries only ever make the document longer, so the old closing brackets are always
+        // entirely overwritten
+        self.file.seek(SeekFrom::Start(self.end))?;
+        self.file.write_all(&serialized)?;
+
+        self.end = self.file.stream_position()?;
+        self.file.write_all(HAR_TRAILER)?;
+        self.file.flush()?;
+
+        self.entries += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// small HAR document with a couple of scripts, a page, and a request that never completed
+    const HAR: &str = r#"{
+        "log": {
+            "version": "1.2",
+            "entries": [
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://app.example.com/js/app.js?v=3",
+                        "headers": [
+                            {"name": ":authority", "value": "app.example.com"},
+                            {"name": "Authorization", "value": "Bearer abc"},
+                            {"name": "Cookie", "value": "session=1"}
+                        ],
+                        "cookies": [{"name": "session", "value": "1"}]
+                    },
+                    "response": {
+                        "status": 200,
+                        "headers": [{"name": "Content-Type", "value": "application/javascript"}],
+                        "content": {"size": 1234}
+                    }
+                },
+                {
+                    "request": {
+                        "method": "post",
+                        "url": "https://app.example.com/api/v1/login",
+                        "headers": [{"name": "Authorization", "value": "Bearer other"}],
+                        "cookies": [{"name": "session", "value": "2"}, {"name": "csrf", "value": "x"}]
+                    },
+                    "response": {"status": 302, "content": {"size": -1}}
+                },
+                {
+                    "request": {"method": "GET", "url": "https://app.example.com/js/vendor.js"},
+                    "response": {"status": 0}
+                },
+                {
+                    "request": {"method": "GET", "url": "data:image/png;base64,AAAA"},
+                    "response": {"status": 200}
+                },
+                {
+                    "request": {
+                        "method": "GET",
+                        "url": "https://sso.example.net/oauth/authorize",
+                        "headers": [{"name": "X-Sso-Token", "value": "secret"}],
+                        "cookies": [{"name": "sso", "value": "secret"}]
+                    },
+                    "response": {"status": 302}
+                }
+            ]
+        }
+    }"#;
+
+    /// write the test HAR document to disk and read it back in
+    fn entries() -> Vec<HarEntry> {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "{HAR}").unwrap();
+        read_har_file(file.path()).unwrap()
+    }
+
+    #[test]
+    /// entries are limited to the given origin, or the most common one when none is given
+    fn same_origin_entries_keeps_one_origin() {
+        let urls = |entries: Vec<HarEntry>| -> Vec<String> {
+            entries.into_iter().map(|entry| entry.request.url).collect()
+        };
+
+        assert_eq!(
+            urls(same_origin_entries(entries(), "")),
+            vec![
+                "https://app.example.com/js/app.js?v=3",
+                "https://app.example.com/api/v1/login",
+                "https://app.example.com/js/vendor.js",
+            ]
+        );
+        assert_eq!(
+            urls(same_origin_entries(entries(), "https://sso.example.net/")),
+            vec!["https://sso.example.net/oauth/authorize"]
+        );
+        assert!(same_origin_entries(entries(), "http://app.example.com").is_empty());
+    }
+
+    #[test]
+    /// parent directories are unique, ordered, and skip non-http(s) urls
+    fn parent_directories_are_unique_http_directories() {
+        assert_eq!(
+            parent_directories(&entries()),
+            vec![
+                "https://app.example.com/js/",
+                "https://app.example.com/api/v1/",
+                "https://sso.example.net/oauth/"
+            ]
+        );
+    }
+
+    #[test]
+    /// only completed requests become known responses, using the recorded method and status
+    fn completed_entries_become_known_responses() {
+        let responses: Vec<_> = entries().iter().filter_map(HarEntry::to_response).collect();
+
+        assert_eq!(responses.len(), 3);
+        assert_eq!(
+            responses[0].url().as_str(),
+            "https://app.example.com/js/app.js?v=3"
+        );
+        assert_eq!(responses[0].status().as_u16(), 200);
+        assert_eq!(responses[0].content_length(), 1234);
+        assert_eq!(responses[1].method().as_str(), "POST");
+        assert_eq!(responses[1].status().as_u16(), 302);
+        assert_eq!(responses[1].content_length(), 0);
+    }
+
+    #[test]
+    /// the file is a valid HAR document before and after every entry, and reads back in
+    fn har_writer_keeps_document_valid() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let path = file.path().to_string_lossy().to_string();
+
+        let mut writer = HarWriter::create(&path).unwrap();
+        assert!(read_har_file(&path).unwrap().is_empty());
+
+        let config = Configuration::new().unwrap();
+
+        for url in ["http://localhost/js/app.js?v=3", "http://localhost/admin"] {
+            let mut response = FeroxResponse::default();
+            response.set_url(url);
+            writer.write(&har_entry(&response, &config, false)).unwrap();
+
+            let har: Value =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            assert_eq!(har["log"]["version"], "1.2");
+        }
+
+        let entries = read_har_file(&path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].request.url, "http://localhost/js/app.js?v=3");
+        assert_eq!(entries[1].response.status, 200);
+    }
+
+    #[test]
+    /// the query string is broken out, and the body is only included when asked for
+    fn har_entry_includes_body_only_when_asked() {
+        let config = Configuration::new().unwrap();
+
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/search?q=ferox");
+        response.set_text("results");
+
+        let entry = har_entry(&response, &config, false);
+        assert_eq!(
+            entry["request"]["queryString"],
+            json!([{"name": "q", "value": "ferox"}])
+        );
+        assert_eq!(entry["request"]["headers"][0]["name"], "Host");
+        assert_eq!(entry["response"]["statusText"], "OK");
+        assert!(entry["response"]["content"].get("text").is_none());
+
+        let entry = har_entry(&response, &config, true);
+        assert_eq!(entry["response"]["content"]["text"], "results");
+    }
+
+    #[test]
+    /// per-request headers are dropped and cookies are merged into a single header; headers from
+    /// other origins aren't included
+    fn session_headers_skip_request_specific_headers() {
+        assert_eq!(
+            session_headers(&same_origin_entries(entries(), "")),
+            vec![
+                (String::from("Authorization"), String::from("Bearer abc")),
+                (String::from("Cookie"), String::from("session=1; csrf=x")),
+            ]
+        );
+    }
+}
@@ -2,14 +2,17 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::{bail, Result};
+use console::style;
 use futures::future;
 use scraper::{Html, Selector};
+use tokio::sync::oneshot;
 use uuid::Uuid;
 
 use crate::filters::{SimilarityFilter, WildcardFilter, SIM_HASHER};
 use crate::message::FeroxMessage;
 use crate::nlp::preprocess;
 use crate::scanner::RESPONSES;
+use crate::traits::FeroxFilter;
 use crate::{
     config::OutputLevel,
     event_handlers::{Command, Handles},
@@ -17,10 +20,113 @@ use crate::{
     response::FeroxResponse,
     skip_fail,
     url::FeroxUrl,
-    utils::{ferox_print, fmt_err, logged_request},
+    utils::{create_report_string, ferox_print, fmt_err, fuzz_data, fuzz_headers, logged_request},
     DEFAULT_METHOD,
 };
 
+/// given a response and a mapping of technology markers to extensions, determine which extensions
+/// the response points to, along with the reason each was picked (i.e. `X-Powered-By: PHP/8.1`)
+///
+/// markers are matched against Server and X-Powered-By values and Set-Cookie names, while the
+/// extensions of the Location header and any links in the body are picked when the map contains
+/// them as a value
+fn technology_extensions(
+    response: &FeroxResponse,
+    extension_map: &HashMap<String, Vec<String>>,
+) -> Vec<(String, String)> {
+    let mut picked: Vec<(String, String)> = Vec::new();
+
+    let mut pick = |extension: &str, reason: &str| {
+        if !picked.iter().any(|(known, _)| known == extension) {
+            picked.push((extension.to_string(), reason.to_string()));
+        }
+    };
+
+    let mut markers = Vec::new();
+
+    for header in ["Server", "X-Powered-By"] {
+        for value in response.headers().get_all(header) {
+            if let Ok(value) = value.to_str() {
+                markers.push((value.to_lowercase(), format!("{header}: {value}")));
+            }
+        }
+    }
+
+    for cookie in response.headers().get_all("Set-Cookie") {
+        if let Some((name, _)) = cookie.to_str().unwrap_or_default().split_once('=') {
+            let name = name.trim();
+            markers.push((name.to_lowercase(), format!("Set-Cookie: {name}")));
+        }
+    }
+
+    // sorted so that the same response always produces the same reasons
+    let mut entries: Vec<_> = extension_map.iter().collect();
+    entries.sort();
+
+    for (value, reason) in &markers {
+        for (marker, extensions) in &entries {
+            if value.contains(&marker.to_lowercase()) {
+                for extension in extensions.iter() {
+                    pick(extension, reason);
+                }
+            }
+        }
+    }
+
+    let mut seen = Vec::new();
+
+    if let Some(location) = response.headers().get("Location") {
+        if let Ok(location) = location.to_str() {
+            seen.push((location.to_string(), format!("Location: {location}")));
+        }
+    }
+
+    let html = Html::parse_document(response.text());
+    let selector = Selector::parse("[href], [src], [action]").expect("couldn't parse selector");
+
+    for element in html.select(&selector) {
+        for attribute in ["href", "src", "action"] {
+            if let Some(link) = element.value().attr(attribute) {
+                seen.push((link.to_string(), format!("link to {link}")));
+            }
+        }
+    }
+
+    for (link, reason) in &seen {
+        let path = link.split(['?', '#']).next().unwrap_or_default();
+        let filename = path.rsplit('/').next().unwrap_or_default();
+
+        let Some((_, extension)) = filename.rsplit_once('.') else {
+            continue;
+        };
+
+        let extension = extension.to_lowercase();
+
+        if entries
+            .iter()
+            .any(|(_, extensions)| extensions.contains(&extension))
+        {
+            pick(&extension, reason);
+        }
+    }
+
+    picked
+}
+
+/// swap the case of every letter in the given string, i.e. `/Admin` becomes `/aDMIN`
+fn swap_case(string: &str) -> String {
+    string
+        .chars()
+        .flat_map(|c| {
+            if c.is_uppercase() {
+                c.to_lowercase().collect::<Vec<_>>()
+            } else {
+                c.to_uppercase().collect()
+            }
+        })
+        .collect()
+}
+
 /// enum representing the different servers that `parse_html` can detect when directory listing is
 /// enabled
 #[derive(Copy, Debug, Clone)]
@@ -107,7 +213,8 @@ impl HeuristicTests {
             let url = FeroxUrl::from_string(target_url, self.handles.clone());
             let request = skip_fail!(url.format("", None));
 
-            let result = logged_request(&request, DEFAULT_METHOD, None, self.handles.clone()).await;
+            let result =
+                logged_request(&request, DEFAULT_METHOD, None, None, self.handles.clone()).await;
 
             match result {
                 Ok(_) => {
@@ -164,7 +271,8 @@ impl HeuristicTests {
         let url = FeroxUrl::from_string(&tgt, self.handles.clone());
         let request = url.format("", None)?;
 
-        let result = logged_request(&request, DEFAULT_METHOD, None, self.handles.clone()).await?;
+        let result =
+            logged_request(&request, DEFAULT_METHOD, None, None, self.handles.clone()).await?;
 
         let ferox_response = FeroxResponse::from(
             result,
@@ -251,6 +359,149 @@ impl HeuristicTests {
         None
     }
 
+    /// heuristic designed to detect case-insensitive servers (i.e. IIS); the url of a resource
+    /// that was found is requested again with the case of its path swapped, and if the server
+    /// responds the same way, the target is considered case-insensitive
+    ///
+    /// returns None when the url's path has no letters to swap
+    pub async fn case_insensitivity(&self, response: &FeroxResponse) -> Result<Option<bool>> {
+        log::trace!("enter: case_insensitivity({})", response);
+
+        let swapped_path = swap_case(response.url().path());
+
+        if swapped_path == response.url().path() {
+            log::trace!("exit: case_insensitivity -> None");
+            return Ok(None);
+        }
+
+        let mut swapped_url = response.url().clone();
+        swapped_url.set_path(&swapped_path);
+
+        let method = response.method().as_str();
+
+        let result = logged_request(&swapped_url, method, None, None, self.handles.clone()).await?;
+
+        let swapped = FeroxResponse::from(
+            result,
+            swapped_url.as_str(),
+            method,
+            self.handles.config.output_level,
+        )
+        .await;
+
+        // the same fields the wildcard and similarity filters compare, so that a page whose
+        // content changes between requests isn't taken for the same resource
+        let similarity = SimilarityFilter {
+            hash: SIM_HASHER.create_signature(preprocess(response.text()).iter()),
+            original_url: response.url().to_string(),
+        };
+
+        let insensitive = swapped.status() == response.status()
+            && swapped.content_length() == response.content_length()
+            && swapped.line_count() == response.line_count()
+            && swapped.word_count() == response.word_count()
+            && similarity.should_filter_response(&swapped);
+
+        if insensitive {
+            // same reasoning as with directory listing; the decision changes which requests are
+            // made for the rest of the scan, so it shouldn't only be visible on the bar
+            let msg = format!(
+                "detected case-insensitive target: {}",
+                response.url().origin().ascii_serialization()
+            );
+            let ferox_msg = FeroxMessage {
+                kind: "log".to_string(),
+                message: msg.clone(),
+                level: "MSG".to_string(),
+                time_offset: 0.0,
+                module: "feroxbuster::heuristics".to_string(),
+            };
+            self.handles
+                .output
+                .tx_file
+                .send(Command::WriteToDisk(Box::new(ferox_msg)))
+                .unwrap_or_default();
+
+            log::info!("{}", msg);
+        }
+
+        log::trace!("exit: case_insensitivity -> {}", insensitive);
+        Ok(Some(insensitive))
+    }
+
+    /// heuristic designed to pick the extensions a target is likely to use (--auto-extensions);
+    /// the target's base url is requested and its headers, cookies and links are compared against
+    /// the configured `extension_map`, adding any matches to the collected extensions
+    pub async fn auto_extensions(&self, target_url: &str) -> Result<()> {
+        log::trace!("enter: auto_extensions({})", target_url);
+
+        let url = FeroxUrl::from_string(target_url, self.handles.clone());
+        let request = url.format("", None)?;
+
+        let result =
+            logged_request(&request, DEFAULT_METHOD, None, None, self.handles.clone()).await?;
+
+        let ferox_response = FeroxResponse::from(
+            result,
+            &url.target,
+            DEFAULT_METHOD,
+            self.handles.config.output_level,
+        )
+        .await;
+
+        let config = &self.handles.config;
+        let collected = self.handles.collected_extensions();
+        let mut added = false;
+
+        for (extension, reason) in technology_extensions(&ferox_response, &config.extension_map) {
+            if config.extensions.contains(&extension)
+                || config.dont_collect.contains(&extension)
+                || collected.contains(&extension)
+            {
+                // already part of the scan, or never supposed to be
+                continue;
+            }
+
+            if matches!(
+                config.output_level,
+                OutputLevel::Default | OutputLevel::Quiet
+            ) {
+                let msg = create_report_string(
+                    "MSG",
+                    DEFAULT_METHOD,
+                    "-",
+                    "-",
+                    "-",
+                    &format!(
+                        "{} added {} based on {}",
+                        style("Auto-extensions").bright().green(),
+                        style(&extension).blue(),
+                        reason
+                    ),
+                    OutputLevel::Default,
+                );
+                ferox_print(&msg, &PROGRESS_PRINTER);
+            }
+
+            log::info!("auto-extensions: {} based on {}", extension, reason);
+
+            self.handles
+                .send_scan_command(Command::AddDiscoveredExtension(extension))?;
+            added = true;
+        }
+
+        if added {
+            // wait for the scan handler to pick up the new extensions, so that they're in place
+            // before the wordlist is streamed
+            let (tx, rx) = oneshot::channel::<bool>();
+            self.handles.send_scan_command(Command::Sync(tx))?;
+            rx.await?;
+        }
+
+        log::trace!("exit: auto_extensions");
+        Ok(())
+    }
+
     /// given a target's base url, attempt to automatically detect its 404 response
     /// pattern(s), and then set filters that will exclude those patterns from future
     /// responses
@@ -274,6 +525,8 @@ impl HeuristicTests {
             Some(self.handles.config.data.as_slice())
         };
 
+        let fuzzing = self.handles.config.uses_fuzz_keyword();
+
         // To take care of slash when needed
         let slash = if self.handles.config.add_slash {
             Some("/")
@@ -331,8 +584,25 @@ impl HeuristicTests {
                     // - http://localhost/.htaccess92969beae6bf4beb855d1622406d87e395c87387a9ad432e8a11245002b709b03cf609d471004154b83bcc1c6ec49f6f
                     // - http://localhost/adminf1d2541e73c44dcb9d1fb7d93334b280
                     // - http://localhost/admin92969beae6bf4beb855d1622406d87e395c87387a9ad432e8a11245002b709b03cf609d471004154b83bcc1c6ec49f6f
-                    let Ok(response) =
-                        logged_request(&nonexistent_url, method, data, self.handles.clone()).await
+                    // when the FUZZ keyword is in use, the random string goes wherever the
+                    // keyword was placed; the url was handled by FeroxUrl::format above
+                    let (fuzzed_data, fuzzed_headers) = if fuzzing {
+                        (
+                            fuzz_data(&path, &self.handles.config),
+                            fuzz_headers(&path, &self.handles.config),
+                        )
+                    } else {
+                        (None, None)
+                    };
+
+                    let Ok(response) = logged_request(
+                        &nonexistent_url,
+                        method,
+                        fuzzed_data.as_deref().or(data),
+                        fuzzed_headers.as_ref(),
+                        self.handles.clone(),
+                    )
+                    .await
                     else {
                         return None;
                     };
@@ -380,61 +650,9 @@ impl HeuristicTests {
                     continue;
                 };
 
-                // report to the user, if appropriate
-                if matches!(
-                    self.handles.config.output_level,
-                    OutputLevel::Default | OutputLevel::Quiet
-                ) {
-                    // sentry value to control whether or not to print the filter
-                    // used because we only want to print the same filter once
-                    let mut print_sentry;
-
-                    if let Ok(filters) = self.handles.filters.data.filters.read() {
-                        for new_wildcard in &wildcard_filters {
-                            // reset the sentry for every new wildcard produced by examine_404_like_responses
-                            print_sentry = true;
-
-                            for other in filters.iter() {
-                                if let Some(other_wildcard) =
-                                    other.as_any().downcast_ref::<WildcardFilter>()
-                                {
-                                    // check the new wildcard against all existing wildcards, if it was added
-                                    // on the cli or by a previous directory, don't print it
-                                    if new_wildcard.as_ref() == other_wildcard {
-                                        print_sentry = false;
-                                        break;
-                                    }
-                                }
-                            }
-
-                            // if we're here, we've found a new wildcard that we didn't previously display, print it
-                            if print_sentry {
-                                ferox_print(&format!("{}", new_wildcard), &PROGRESS_PRINTER);
-                            }
-                        }
-                    }
-                }
-
-                // create the new filter
-                for wildcard in wildcard_filters {
-                    self.handles.filters.send(Command::AddFilter(wildcard))?;
-                }
+                self.add_wildcard_filters(wildcard_filters, &wildcard_responses)?;
 
-                // if we're here, we've detected a 404-like response pattern, and we're already filtering for size/word/line
-                //
-                // in addition, we'll create a similarity filter as a fallback
                 for resp in wildcard_responses {
-                    let hash = SIM_HASHER.create_signature(preprocess(resp.text()).iter());
-
-                    let sim_filter = SimilarityFilter {
-                        hash,
-                        original_url: resp.url().to_string(),
-                    };
-
-                    self.handles
-                        .filters
-                        .send(Command::AddFilter(Box::new(sim_filter)))?;
-
                     if resp.is_directory() {
                         // response is either a 3XX with a Location header that matches url + '/'
                         // or it's a 2XX that ends with a '/'
@@ -470,6 +688,155 @@ impl HeuristicTests {
         Ok(Some(retval))
     }
 
+    /// report any of the given wildcard filters that haven't been seen before (if appropriate),
+    /// then add them to the scan's filters; a similarity filter is added for each of the given
+    /// responses as a fallback
+    #[allow(clippy::vec_box)] // matches the return value of examine_404_like_responses
+    fn add_wildcard_filters(
+        &self,
+        wildcard_filters: Vec<Box<WildcardFilter>>,
+        wildcard_responses: &[&FeroxResponse],
+    ) -> Result<()> {
+        // report to the user, if appropriate
+        if matches!(
+            self.handles.config.output_level,
+            OutputLevel::Default | OutputLevel::Quiet
+        ) {
+            // sentry value to control whether or not to print the filter
+            // used because we only want to print the same filter once
+            let mut print_sentry;
+
+            if let Ok(filters) = self.handles.filters.data.filters.read() {
+                for new_wildcard in &wildcard_filters {
+                    // reset the sentry for every new wildcard produced by examine_404_like_responses
+                    print_sentry = true;
+
+                    for other in filters.iter() {
+                        if let Some(other_wildcard) =
+                            other.as_any().downcast_ref::<WildcardFilter>()
+                        {
+                            // check the new wildcard against all existing wildcards, if it was added
+                            // on the cli or by a previous directory, don't print it
+                            if new_wildcard.as_ref() == other_wildcard {
+                                print_sentry = false;
+                                break;
+                            }
+                        }
+                    }
+
+                    // if we're here, we've found a new wildcard that we didn't previously display, print it
+                    if print_sentry {
+                        ferox_print(&format!("{}", new_wildcard), &PROGRESS_PRINTER);
+                    }
+                }
+            }
+        }
+
+        // create the new filter
+        for wildcard in wildcard_filters {
+            self.handles.filters.send(Command::AddFilter(wildcard))?;
+        }
+
+        // if we're here, we've detected a 404-like response pattern, and we're already filtering for size/word/line
+        //
+        // in addition, we'll create a similarity filter as a fallback
+        for resp in wildcard_responses {
+            let hash = SIM_HASHER.create_signature(preprocess(resp.text()).iter());
+
+            let sim_filter = SimilarityFilter {
+                hash,
+                original_url: resp.url().to_string(),
+            };
+
+            self.handles
+                .filters
+                .send(Command::AddFilter(Box::new(sim_filter)))?;
+        }
+
+        Ok(())
+    }
+
+    /// given the target url of a --vhost scan, request it using a handful of random hostnames
+    /// and filter out whatever the server responds with for hosts it doesn't know about (i.e.
+    /// the default virtual host)
+    pub async fn detect_vhost_baseline(&self, target_url: &str) -> Result<()> {
+        log::trace!("enter: detect_vhost_baseline({:?})", target_url);
+
+        if self.handles.config.dont_filter {
+            // early return, dont_filter scans don't need tested
+            log::trace!("exit: detect_vhost_baseline -> dont_filter is true");
+            return Ok(());
+        }
+
+        let url = FeroxUrl::from_string(target_url, self.handles.clone()).format("", None)?;
+
+        let data = if self.handles.config.data.is_empty() {
+            None
+        } else {
+            Some(self.handles.config.data.as_slice())
+        };
+
+        for method in self.handles.config.methods.iter() {
+            // hostnames of differing lengths, so that a default page which reflects the
+            // requested host can still be filtered on its word/line counts
+            //
+            // example Host headers (w/ --vhost example.com):
+            // - 2fc10778.example.com
+            // - 2fc1077836ad43ab98b7.example.com
+            let hostnames = [8, 12, 20, 32].map(|length| {
+                self.handles
+                    .config
+                    .vhost_for(&self.unique_string(1)[..length])
+            });
+
+            let responses = future::join_all(hostnames.into_iter().map(|hostname| {
+                let url = &url;
+
+                async move {
+                    let headers = HashMap::from([(String::from("Host"), hostname.clone())]);
+
+                    let response =
+                        logged_request(url, method, data, Some(&headers), self.handles.clone())
+                            .await
+                            .ok()?;
+
+                    let mut ferox_response = FeroxResponse::from(
+                        response,
+                        target_url,
+                        method,
+                        self.handles.config.output_level,
+                    )
+                    .await;
+
+                    ferox_response.set_vhost(&hostname);
+
+                    Some(ferox_response)
+                }
+            }))
+            .await
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+            if responses.len() < 2 {
+                log::debug!("not enough responses to determine the default virtual host");
+                continue;
+            }
+
+            let Some((wildcard_filters, wildcard_responses)) =
+                self.examine_404_like_responses(&responses)
+            else {
+                log::warn!("no match found for the default virtual host");
+                continue;
+            };
+
+            self.add_wildcard_filters(wildcard_filters, &wildcard_responses)?;
+        }
+
+        log::trace!("exit: detect_vhost_baseline");
+        Ok(())
+    }
+
     /// for all responses, group them by status code, then examine chars/words/lines.
     /// if all responses' respective lengths within a status code grouping match
     /// each other, we can assume that will remain true for subsequent non-existent urls
@@ -609,6 +976,49 @@ impl HeuristicTests {
 mod tests {
     use super::*;
 
+    #[test]
+    /// markers are matched against the Server and X-Powered-By headers and cookie names, while
+    /// extensions seen in links are only picked when the map knows about them
+    fn technology_extensions_picks_from_headers_cookies_and_links() {
+        let json = r#"{"type":"response","url":"http://localhost/","path":"/","wildcard":false,"status":200,"content_length":0,"line_count":0,"word_count":0,"headers":{"server":"Microsoft-IIS/10.0","set-cookie":"JSESSIONID=abc; Path=/"},"extension":""}"#;
+        let mut response: FeroxResponse = serde_json::from_str(json).unwrap();
+        response
+            .set_text(r#"<a href="/login.cfm?next=1">login</a><script src="/app.js"></script>"#);
+
+        let extension_map: HashMap<String, Vec<String>> = [
+            ("iis", vec!["aspx", "asp"]),
+            ("jsessionid", vec!["jsp"]),
+            ("coldfusion", vec!["cfm"]),
+        ]
+        .into_iter()
+        .map(|(marker, extensions)| {
+            (
+                marker.to_string(),
+                extensions.into_iter().map(String::from).collect(),
+            )
+        })
+        .collect();
+
+        let picked = technology_extensions(&response, &extension_map);
+
+        assert_eq!(
+            picked,
+            [
+                ("aspx".to_string(), "Server: Microsoft-IIS/10.0".to_string()),
+                ("asp".to_string(), "Server: Microsoft-IIS/10.0".to_string()),
+                ("jsp".to_string(), "Set-Cookie: JSESSIONID".to_string()),
+                ("cfm".to_string(), "link to /login.cfm?next=1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    /// every letter's case is swapped, anything else is left alone
+    fn swap_case_swaps_letters_only() {
+        assert_eq!(swap_case("/Admin/login.ASPX"), "/aDMIN/LOGIN.aspx");
+        assert_eq!(swap_case("/123/"), "/123/");
+    }
+
     #[test]
     /// request a unique string of 32bytes * a value returns correct result
     fn heuristics_unique_string_returns_correct_length() {
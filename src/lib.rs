@@ -15,21 +15,29 @@ pub mod config;
 mod client;
 pub mod event_handlers;
 pub mod filters;
+pub mod fingerprint;
+pub mod har;
 pub mod heuristics;
 pub mod logger;
 mod parser;
 pub mod progress;
 pub mod scan_manager;
 pub mod scanner;
+pub mod secrets;
+pub mod targets;
 pub mod statistics;
 mod traits;
 pub mod utils;
 mod extractor;
 mod macros;
 mod url;
+pub mod rules;
+pub mod wordlists;
 mod response;
+mod bypass;
 mod message;
 mod nlp;
+mod warc;
 
 /// Alias for tokio::sync::mpsc::UnboundedSender<Command>
 pub(crate) type CommandSender = UnboundedSender<Command>;
@@ -62,6 +70,68 @@ pub(crate) const DEFAULT_IGNORED_EXTENSIONS: [&str; 38] = [
 /// Default set of extensions to search for when auto-collecting backups during scans
 pub(crate) const DEFAULT_BACKUP_EXTENSIONS: [&str; 5] = ["~", ".bak", ".bak2", ".old", ".1"];
 
+/// Default set of methods to send to "found" urls when discovering the methods they allow;
+/// DELETE is only sent when it's given to --discover-methods explicitly
+pub(crate) const DEFAULT_PROBE_METHODS: [&str; 4] = ["PUT", "PATCH", "TRACE", "FOO"];
+
+/// Default mapping of technology markers (matched against Server and X-Powered-By values and
+/// Set-Cookie names) to the extensions `--auto-extensions` adds for them
+pub(crate) const DEFAULT_EXTENSION_MAP: [(&str, &[&str]); 8] = [
+    ("php", &["php"]),
+    ("asp.net", &["aspx", "asp"]),
+    ("iis", &["aspx", "asp"]),
+    ("jsessionid", &["jsp"]),
+    ("servlet", &["jsp"]),
+    ("tomcat", &["jsp"]),
+    ("coldfusion", &["cfm"]),
+    ("cfid", &["cfm"]),
+];
+
+/// Default rules `--secrets` runs over response bodies: (id, regex, minimum entropy)
+///
+/// when a regex has a capture group, the group is the secret, otherwise it's the entire match; a
+/// minimum entropy above 0 discards secrets whose shannon entropy (bits per character) is lower
+pub(crate) const DEFAULT_SECRET_RULES: [(&str, &str, f64); 10] = [
+    (
+        "aws-access-key-id",
+        r"\b((?:A3T[A-Z0-9]|AKIA|ASIA|ABIA|ACCA)[A-Z0-9]{16})\b",
+        0.0,
+    ),
+    (
+        "aws-secret-access-key",
+        r#"(?i)aws.{0,20}?(?:secret|key).{0,20}?['"]([A-Za-z0-9/+=]{40})['"]"#,
+        4.0,
+    ),
+    (
+        "private-key",
+        r"(?s)-----BEGIN (?:RSA |EC |DSA |OPENSSH |PGP |ENCRYPTED )?PRIVATE KEY(?: BLOCK)?-----.*?(?:-----END (?:RSA |EC |DSA |OPENSSH |PGP |ENCRYPTED )?PRIVATE KEY(?: BLOCK)?-----|\z)",
+        0.0,
+    ),
+    (
+        "jwt",
+        r"\b(eyJ[A-Za-z0-9_-]{10,}\.eyJ[A-Za-z0-9_-]{10,}\.[A-Za-z0-9_-]{10,})",
+        0.0,
+    ),
+    (
+        "database-connection-string",
+        r#"(?i)\b((?:mongodb(?:\+srv)?|postgres(?:ql)?|mysql|mssql|sqlserver|redis|amqp)://[^\s:@/'"]+:[^\s@/'"]+@[^\s'"<>]+)"#,
+        0.0,
+    ),
+    ("github-token", r"\b(gh[pousr]_[A-Za-z0-9]{36})\b", 0.0),
+    ("slack-token", r"\b(xox[baprs]-[A-Za-z0-9-]{10,})\b", 0.0),
+    ("google-api-key", r"\b(AIza[0-9A-Za-z_-]{35})\b", 0.0),
+    (
+        "stripe-secret-key",
+        r"\b((?:sk|rk)_live_[0-9a-zA-Z]{24,})\b",
+        0.0,
+    ),
+    (
+        "generic-secret",
+        r#"(?i)(?:api[_-]?key|secret|token|passw(?:or)?d)['"]?\s*[:=]\s*['"]([A-Za-z0-9_\-+/=]{16,})['"]"#,
+        3.5,
+    ),
+];
+
 /// Default wordlist to use when `-w|--wordlist` isn't specified and not `wordlist` isn't set
 /// in a [ferox-config.toml](constant.DEFAULT_CONFIG_NAME.html) config file.
 ///
@@ -157,6 +227,10 @@ pub const DEFAULT_STATUS_CODES: [StatusCode; 60] = [
 /// Default method for requests
 pub(crate) const DEFAULT_METHOD: &str = "GET";
 
+/// Keyword that marks where a word from the wordlist is placed in the url, headers, queries or
+/// body; when absent, words are appended to the end of the target url
+pub const FUZZ_KEYWORD: &str = "FUZZ";
+
 /// Default filename for config file settings
 ///
 /// Expected location is in the same directory as the feroxbuster binary.
@@ -4,8 +4,8 @@ use std::{
         args,
         consts::{ARCH, OS},
     },
-    fs::{create_dir, remove_file, File},
-    io::{stderr, BufRead, BufReader},
+    fs::{create_dir, remove_file},
+    io::{stderr, BufRead},
     ops::Index,
     path::Path,
     process::{exit, Command},
@@ -30,11 +30,18 @@ use feroxbuster::{
         FiltersHandler, Handles, ScanHandler, StatsHandler, Tasks, TermInputHandler,
         TermOutHandler, SCAN_COMPLETE,
     },
-    filters, heuristics, logger,
+    filters, fingerprint,
+    har::{parent_directories, read_har_file, same_origin_entries},
+    heuristics, logger,
     progress::PROGRESS_PRINTER,
-    scan_manager::{self, ScanType},
-    scanner,
+    scan_manager::{self, ScanOrder, ScanType},
+    scanner::{self, RESPONSES},
+    secrets,
+    targets::read_targets_file,
     utils::{fmt_err, slugify_filename},
+    wordlists::{
+        combine_wordlists, download_wordlist, parse_wordlist_spec, wordlist_cache_dir, Wordlist,
+    },
     SECONDARY_WORDLIST,
 };
 #[cfg(not(target_os = "windows"))]
@@ -48,45 +55,17 @@ lazy_static! {
     static ref PARALLEL_LIMITER: Semaphore = Semaphore::new(0);
 }
 
-/// Create a Vec of Strings from the given wordlist then stores it inside an Arc
-fn get_unique_words_from_wordlist(path: &str) -> Result<Arc<Vec<String>>> {
-    log::trace!("enter: get_unique_words_from_wordlist({})", path);
-    let mut trimmed_word = false;
-
-    let file = File::open(path).with_context(|| format!("Could not open {path}"))?;
-
-    let reader = BufReader::new(file);
-
-    // this empty string ensures that we call Requester::request with the base url, i.e.
-    // `http://localhost/` instead of going straight into `http://localhost/WORD.EXT`.
-    // for vanilla scans, it doesn't matter all that much, but it can be a significant difference
-    // when `-e` is used, depending on the content at the base url.
-    let mut words = vec![String::from("")];
-
-    for line in reader.lines() {
-        line.map(|result| {
-            if !result.starts_with('#') && !result.is_empty() {
-                if result.starts_with('/') {
-                    words.push(result.trim_start_matches('/').to_string());
-                    trimmed_word = true;
-                } else {
-                    words.push(result);
-                }
-            }
-        })
-        .ok();
-    }
-
-    if trimmed_word {
-        log::warn!("Some words in the wordlist started with a leading forward-slash; those words were trimmed (i.e. /word -> word)");
+/// Read the wordlist at the given path, downloading it into the wordlist cache first if the path
+/// is a url
+async fn load_wordlist(path: &str, config: &Configuration) -> Result<Wordlist> {
+    if !path.starts_with("http") {
+        return Wordlist::from_file(path);
     }
 
-    log::trace!(
-        "exit: get_unique_words_from_wordlist -> Arc<wordlist[{} words...]>",
-        words.len()
-    );
+    // found a url scheme, attempt to download the wordlist (or reuse a cached copy)
+    let cached = download_wordlist(&config.client, path, &wordlist_cache_dir()).await?;
 
-    Ok(Arc::new(words))
+    Wordlist::from_file(cached)
 }
 
 /// Determine whether it's a single url scan or urls are coming from stdin, then scan as needed
@@ -171,6 +150,60 @@ async fn get_targets(handles: Arc<Handles>) -> Result<Vec<String>> {
                 targets.push(scan.url().to_owned());
             }
         };
+    } else if !handles.config.targets_from.is_empty() {
+        // got targets from port scanner output, i.e. nmap -oX / masscan -oJ / naabu -json; any
+        // that aren't actually serving web content are weeded out by the connectivity check
+        targets.extend(read_targets_file(&handles.config.targets_from)?);
+
+        if targets.is_empty() {
+            bail!(
+                "Did not find any web ports in {}",
+                handles.config.targets_from
+            );
+        }
+    } else if !handles.config.har.is_empty() {
+        // got targets from a HAR file, i.e. a browser's exported network log; each request's
+        // parent directory is scanned, and the requests themselves are already known, so they're
+        // neither re-reported nor re-requested by the extractor
+        //
+        // only the target's origin (-u, or the most common origin in the file) is used
+        let entries = same_origin_entries(
+            read_har_file(&handles.config.har)?,
+            &handles.config.target_url,
+        );
+        let ferox_scans = handles.ferox_scans()?;
+
+        if !handles.config.target_url.is_empty() {
+            targets.push(handles.config.target_url.clone());
+        }
+
+        for entry in &entries {
+            let Some(response) = entry.to_response() else {
+                continue;
+            };
+
+            if !response.url().path().ends_with('/') {
+                ferox_scans.add_file_scan(response.url().as_str(), ScanOrder::Initial);
+            }
+
+            RESPONSES.insert(response);
+        }
+
+        for directory in parent_directories(&entries) {
+            if !targets
+                .iter()
+                .any(|target| target.trim_end_matches('/') == directory.trim_end_matches('/'))
+            {
+                targets.push(directory);
+            }
+        }
+
+        if targets.is_empty() {
+            bail!(
+                "Did not find any http(s) requests in {}",
+                handles.config.har
+            );
+        }
     } else {
         targets.push(handles.config.target_url.clone());
     }
@@ -236,59 +269,18 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         exit(0);
     }
 
-    let words = if config.wordlist.starts_with("http") {
-        // found a url scheme, attempt to download the wordlist
-        let response = config
-            .client
-            .get(&config.wordlist)
-            .send()
-            .await
-            .context(format!(
-                "Unable to download wordlist from remote url: {}",
-                config.wordlist
-            ))?;
-
-        if !response.status().is_success() {
-            // status code isn't a 200, bail
-            bail!(
-                "[{}] Unable to download wordlist from url: {}",
-                response.status().as_str(),
-                config.wordlist
-            );
-        }
+    let (primary_path, _) = parse_wordlist_spec(&config.wordlist);
 
-        // attempt to get the filename from the url's path
-        let Some(path_segments) = response.url().path_segments() else {
-            bail!("Unable to parse path from url: {}", response.url());
-        };
+    let mut words = match load_wordlist(primary_path, &config).await {
+        Ok(w) => w,
+        Err(err) => {
+            let secondary = Path::new(SECONDARY_WORDLIST);
 
-        let Some(filename) = path_segments.last() else {
-            bail!(
-                "Unable to parse filename from url's path: {}",
-                response.url().path()
-            );
-        };
-
-        let filename = filename.to_string();
-
-        // read the body and write it to disk, then use existing code to read the wordlist
-        let body = response.text().await?;
-
-        std::fs::write(&filename, body)?;
-
-        get_unique_words_from_wordlist(&filename)?
-    } else {
-        match get_unique_words_from_wordlist(&config.wordlist) {
-            Ok(w) => w,
-            Err(err) => {
-                let secondary = Path::new(SECONDARY_WORDLIST);
-
-                if secondary.exists() {
-                    eprintln!("Found wordlist in secondary location");
-                    get_unique_words_from_wordlist(SECONDARY_WORDLIST)?
-                } else {
-                    return Err(err);
-                }
+            if !primary_path.starts_with("http") && secondary.exists() {
+                eprintln!("Found wordlist in secondary location");
+                Wordlist::from_file(SECONDARY_WORDLIST)?
+            } else {
+                return Err(err);
             }
         }
     };
@@ -297,7 +289,65 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         // the check is now <= 1 due to the initial empty string added in 2.6.0
         // 1 -> empty wordlist
         // 0 -> error
-        bail!("Did not find any words in {}", config.wordlist);
+        bail!("Did not find any words in {}", primary_path);
+    }
+
+    if !config.additional_wordlists.is_empty() {
+        let keywords = config.keywords();
+
+        for (idx, keyword) in keywords.iter().enumerate() {
+            if keywords[..idx].contains(keyword) {
+                bail!("Each wordlist needs a unique keyword (i.e. -w dirs.txt:DIR -w versions.txt:VER), found {} more than once", keyword);
+            }
+
+            if !config.uses_keyword(keyword) {
+                bail!(
+                    "The {} keyword isn't used in the url, headers, queries or body",
+                    keyword
+                );
+            }
+        }
+
+        // the leading empty string added to each wordlist only makes sense once, so it's dropped
+        // from each list before they're combined and then added back to the combined list
+        //
+        // each list is held in memory, but the combined keyspace is generated as it's scanned
+        let mut lists = vec![words.iter()?.skip(1).collect::<Vec<_>>()];
+
+        for spec in &config.additional_wordlists {
+            let (path, _) = parse_wordlist_spec(spec);
+            let list = load_wordlist(path, &config).await?;
+
+            if list.len() <= 1 {
+                bail!("Did not find any words in {}", path);
+            }
+
+            lists.push(list.iter()?.skip(1).collect());
+        }
+
+        words = combine_wordlists(lists, config.wordlist_mode)?;
+
+        if words.len() <= 1 {
+            bail!("Combining the wordlists didn't produce any words");
+        }
+    }
+
+    if !config.param_mine.is_empty() {
+        let params = load_wordlist(&config.param_mine, &config).await?;
+
+        if params.len() <= 1 {
+            bail!("Did not find any words in {}", config.param_mine);
+        }
+
+        scanner::set_param_words(params.iter()?.skip(1).collect());
+    }
+
+    if config.fingerprint {
+        fingerprint::load_signatures(&config.fingerprint_signatures)?;
+    }
+
+    if config.secrets {
+        secrets::load_rules(&config.secret_rules)?;
     }
 
     // spawn all event handlers, expect back a JoinHandle and a *Handle to the specific event
@@ -494,6 +544,11 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
 
         let mut banner = Banner::new(&targets, &config);
 
+        if config.detect_case {
+            // decisions only exist at this point when they were loaded from a resumed scan
+            banner.add_case_decisions(&handles.ferox_scans()?.case_decisions());
+        }
+
         // only interested in the side-effect that sets banner.update_status
         let _ = banner.check_for_updates(UPDATE_URL, handles.clone()).await;
 
@@ -40,12 +40,12 @@ pub fn initialize() -> Command {
             Arg::new("url")
                 .short('u')
                 .long("url")
-                .required_unless_present_any(["stdin", "resume_from", "update_app"])
+                .required_unless_present_any(["stdin", "resume_from", "update_app", "request_file", "targets_from", "har"])
                 .help_heading("Target selection")
                 .value_name("URL")
                 .use_value_delimiter(true)
                 .value_hint(ValueHint::Url)
-                .help("The target URL (required, unless [--stdin || --resume-from] used)"),
+                .help("The target URL (required, unless [--stdin || --resume-from || --request-file || --targets-from || --har] used)"),
         )
         .arg(
             Arg::new("stdin")
@@ -64,6 +64,54 @@ pub fn initialize() -> Command {
                 .help("State file from which to resume a partially complete scan (ex. --resume-from ferox-1606586780.state)")
                 .conflicts_with("url")
                 .num_args(1),
+        )
+        .arg(
+            Arg::new("targets_from")
+                .long("targets-from")
+                .value_hint(ValueHint::FilePath)
+                .value_name("FILE")
+                .help_heading("Target selection")
+                .help("Read targets from nmap XML, masscan JSON, or naabu JSON output; web ports are turned into http(s) urls (ex. --targets-from nmap.xml)")
+                .conflicts_with_all(["url", "stdin", "resume_from"])
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("har")
+                .long("har")
+                .value_hint(ValueHint::FilePath)
+                .value_name("HAR_FILE")
+                .help_heading("Target selection")
+                .help("Seed the scan from a HAR file; the parent directory of each request to the --url's origin (or the file's most common origin) becomes a target and its responses are treated as already known (ex. --har session.har)")
+                .conflicts_with_all(["stdin", "resume_from", "targets_from"])
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("har_headers")
+                .long("har-headers")
+                .num_args(0)
+                .requires("har")
+                .help_heading("Target selection")
+                .help("Reuse the headers and cookies found in the --har file's requests to the target's origin"),
+        )
+        .arg(
+            Arg::new("request_file")
+                .long("request-file")
+                .value_hint(ValueHint::FilePath)
+                .value_name("REQUEST_FILE")
+                .help_heading("Target selection")
+                .help("Raw HTTP request file to use as a template for all requests (ex. --request-file req.txt)")
+                .conflicts_with("resume_from")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("protocol")
+                .long("protocol")
+                .value_name("PROTOCOL")
+                .value_parser(["http", "https"])
+                .help_heading("Target selection")
+                .help("Protocol to use when building the target URL from --request-file (default: https)")
+                .requires("request_file")
+                .num_args(1),
         );
 
     /////////////////////////////////////////////////////////////////////
@@ -472,6 +520,62 @@ pub fn initialize() -> Command {
                 .help_heading("Scan settings")
                 .help("Don't extract links from response body (html, javascript, etc...)")
         )
+        .arg(
+            Arg::new("openapi")
+                .long("openapi")
+                .value_hint(ValueHint::FilePath)
+                .value_name("SPEC")
+                .num_args(1)
+                .help_heading("Scan settings")
+                .help("Request the endpoints described by an OpenAPI/Swagger specification (json or yaml) instead of looking for one on the target (ex. --openapi swagger.json)")
+        )
+        .arg(
+            Arg::new("openapi_methods")
+                .long("openapi-methods")
+                .num_args(0)
+                .help_heading("Scan settings")
+                .help("Request api endpoints with every method their specification declares (PUT, POST, DELETE, etc...), not only GET, HEAD and OPTIONS")
+        )
+        .arg(
+            Arg::new("discover")
+                .long("discover")
+                .num_args(0)
+                .help_heading("Scan settings")
+                .help("Request /.well-known/ resources (security.txt, openid-configuration, etc...) and common OpenAPI/Swagger specification locations before scanning each target")
+        )
+        .arg(
+            Arg::new("source_map_dir")
+                .long("source-map-dir")
+                .value_hint(ValueHint::DirPath)
+                .value_name("DIR")
+                .num_args(1)
+                .help_heading("Scan settings")
+                .help("Save the original sources found in javascript source maps to the given directory (ex. --source-map-dir ./sources)")
+        )
+        .arg(
+            Arg::new("param_mine")
+                .long("param-mine")
+                .value_hint(ValueHint::FilePath)
+                .value_name("PARAM_WORDLIST")
+                .num_args(1)
+                .help_heading("Scan settings")
+                .help("Brute force hidden query parameter names from the given wordlist against each endpoint found (ex. --param-mine params.txt)")
+        )
+        .arg(
+            Arg::new("vhost")
+                .long("vhost")
+                .value_name("DOMAIN")
+                .num_args(0..=1)
+                .help_heading("Scan settings")
+                .help("Enumerate virtual hosts by sending each word as the Host header to the target url; words become subdomains of DOMAIN when given (ex. --vhost example.com)")
+        )
+        .arg(
+            Arg::new("detect_case")
+                .long("detect-case")
+                .num_args(0)
+                .help_heading("Scan settings")
+                .help("Check whether each target is case-insensitive using the first resource found on it; if so, skip words that only differ by case")
+        )
         .arg(
             Arg::new("scan_limit")
                 .short('L')
@@ -513,8 +617,25 @@ pub fn initialize() -> Command {
                 .short('w')
                 .long("wordlist")
                 .value_hint(ValueHint::FilePath)
+                .value_name("FILE[:KEYWORD]")
+                .help("Path or URL of the wordlist (.gz/.zst are decompressed automatically); may be used more than once when each is given a unique KEYWORD (ex: -w dirs.txt:DIR -w versions.txt:VER)")
+                .help_heading("Scan settings")
+                .num_args(1)
+                .action(ArgAction::Append),
+        ).arg(
+            Arg::new("rules")
+                .long("rules")
+                .value_hint(ValueHint::FilePath)
                 .value_name("FILE")
-                .help("Path or URL of the wordlist")
+                .help("File of hashcat-style rules used to mutate each word from the wordlist (ex: c, &[2019-2024])")
+                .help_heading("Scan settings")
+                .num_args(1),
+        ).arg(
+            Arg::new("wordlist_mode")
+                .long("wordlist-mode")
+                .value_name("MODE")
+                .value_parser(["clusterbomb", "pitchfork"])
+                .help("How multiple wordlists are combined: every combination (clusterbomb) or line by line (pitchfork) (default: clusterbomb)")
                 .help_heading("Scan settings")
                 .num_args(1),
         ).arg(
@@ -545,6 +666,12 @@ pub fn initialize() -> Command {
                 .num_args(0)
                 .help_heading("Dynamic collection settings")
                 .help("Automatically discover extensions and add them to --extensions (unless they're in --dont-collect)")
+        ).arg(
+            Arg::new("auto_extensions")
+                .long("auto-extensions")
+                .num_args(0)
+                .help_heading("Dynamic collection settings")
+                .help("Add extensions to --extensions based on each target's technology (Server, X-Powered-By, cookies, and extensions seen), using the extension_map in the config")
         ).arg(
             Arg::new("collect_backups")
                 .short('B')
@@ -553,6 +680,37 @@ pub fn initialize() -> Command {
                 .help_heading("Dynamic collection settings")
                 .help("Automatically request likely backup extensions for \"found\" urls (default: ~, .bak, .bak2, .old, .1)")
         )
+        .arg(
+            Arg::new("discover_methods")
+                .long("discover-methods")
+                .value_name("METHOD")
+                .num_args(0..)
+                .help_heading("Dynamic collection settings")
+                .help("Probe \"found\" urls with OPTIONS, the given methods, and method-override headers (default: PUT, PATCH, TRACE, FOO)")
+        )
+        .arg(
+            Arg::new("bypass_403")
+                .long("bypass-403")
+                .num_args(0)
+                .help_heading("Dynamic collection settings")
+                .help("Probe 401/403 \"found\" urls with path mutations and header tricks, reporting any that get a different response")
+        )
+        .arg(
+            Arg::new("fingerprint")
+                .long("fingerprint")
+                .value_hint(ValueHint::FilePath)
+                .value_name("SIGNATURES")
+                .num_args(0..=1)
+                .help_heading("Dynamic collection settings")
+                .help("Detect the technologies (and versions) behind each host from headers, cookies, bodies, generator tags and favicons; SIGNATURES is a TOML file of signatures used in addition to the built-in ones")
+        )
+        .arg(
+            Arg::new("secrets")
+                .long("secrets")
+                .num_args(0)
+                .help_heading("Dynamic collection settings")
+                .help("Scan the body of each \"found\" url for leaked secrets (keys, tokens, passwords, ...), using the secret_rules in the config")
+        )
         .arg(
             Arg::new("collect_words")
                 .short('g')
@@ -630,6 +788,57 @@ pub fn initialize() -> Command {
                 .help("Output file to write log entries (use w/ --json for JSON entries)")
                 .num_args(1),
         )
+        .arg(
+            Arg::new("save_responses")
+                .long("save-responses")
+                .value_name("DIR")
+                .value_hint(ValueHint::DirPath)
+                .help_heading("Output settings")
+                .help("Save the body of each \"found\" url to a directory tree mirroring host/path (ex. --save-responses ./responses)")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("save_headers")
+                .long("save-headers")
+                .num_args(0)
+                .requires("save_responses")
+                .help_heading("Output settings")
+                .help("Save each response's headers alongside its body (use w/ --save-responses)"),
+        )
+        .arg(
+            Arg::new("warc")
+                .long("warc")
+                .value_name("FILE")
+                .value_hint(ValueHint::FilePath)
+                .help_heading("Output settings")
+                .help("Archive the request and response of each \"found\" url to a WARC file (ex. --warc scan.warc)")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("warc_all")
+                .long("warc-all")
+                .num_args(0)
+                .requires("warc")
+                .help_heading("Output settings")
+                .help("Archive every request made while scanning, not only \"found\" urls (use w/ --warc)"),
+        )
+        .arg(
+            Arg::new("har_out")
+                .long("har-out")
+                .value_name("FILE")
+                .value_hint(ValueHint::FilePath)
+                .help_heading("Output settings")
+                .help("Export \"found\" urls to a HAR 1.2 file, written as they're found (ex. --har-out findings.har)")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("har_bodies")
+                .long("har-bodies")
+                .num_args(0)
+                .requires("har_out")
+                .help_heading("Output settings")
+                .help("Include response bodies in the exported HAR file (use w/ --har-out)"),
+        )
         .arg(
             Arg::new("no_state")
                 .long("no-state")
@@ -722,6 +931,12 @@ EXAMPLES:
     Pass auth token via query parameter
         ./feroxbuster -u http://127.1 --query token=0123456789ABCDEF
 
+    Place words at the FUZZ keyword instead of the end of the url (works in headers, queries, and --data too)
+        ./feroxbuster -u http://127.1/api/FUZZ/users -H 'X-Api-Version: FUZZ'
+
+    Combine two keyword-tagged wordlists line by line (use clusterbomb for every combination)
+        ./feroxbuster -u http://127.1/DIR/VER -w dirs.txt:DIR -w versions.txt:VER --wordlist-mode pitchfork
+
     Ludicrous speed... go!
         ./feroxbuster -u http://127.1 --threads 200
         
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     convert::{TryFrom, TryInto},
     fmt,
@@ -8,8 +9,10 @@ use std::{
 
 use anyhow::{Context, Result};
 use console::style;
+use encoding_rs::{Encoding, UTF_8};
+use mime::Mime;
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
     Method, Response, StatusCode, Url,
 };
 use serde::ser::SerializeStruct;
@@ -17,7 +20,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
 use crate::{
-    config::OutputLevel,
+    config::{Configuration, OutputLevel},
     event_handlers::{Command, Handles},
     traits::FeroxSerialize,
     url::FeroxUrl,
@@ -25,6 +28,42 @@ use crate::{
     CommandSender,
 };
 
+/// The status code a resource responded with to a single method probe (--discover-methods)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MethodProbe {
+    /// method that was probed
+    pub method: String,
+
+    /// status code of the response
+    pub status: u16,
+
+    /// method-override header the method was sent in, if it wasn't sent as the request's method
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub override_header: Option<String>,
+}
+
+/// Implement Display for MethodProbe
+impl fmt::Display for MethodProbe {
+    /// formatter for Display, i.e. `PUT 405` or `X-HTTP-Method-Override: DELETE 200`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(header) = &self.override_header {
+            write!(f, "{header}: ")?;
+        }
+
+        write!(f, "{} {}", self.method, self.status)
+    }
+}
+
+/// HTTP methods discovered for a single "found" resource (--discover-methods)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveredMethods {
+    /// methods listed in the Allow header of the resource's response to OPTIONS
+    pub allow: Vec<String>,
+
+    /// every probe made against the resource
+    pub probes: Vec<MethodProbe>,
+}
+
 /// A `FeroxResponse`, derived from a `Response` to a submitted `Request`
 #[derive(Debug, Clone)]
 pub struct FeroxResponse {
@@ -63,6 +102,45 @@ pub struct FeroxResponse {
 
     /// Url's file extension, if one exists
     pub(crate) extension: Option<String>,
+
+    /// Host header value the request was sent with, when scanning virtual hosts (--vhost)
+    vhost: Option<String>,
+
+    /// methods the resource responded to, when --discover-methods is used
+    methods: Option<DiscoveredMethods>,
+
+    /// where the body was written to, when --save-responses is used
+    saved_to: Option<String>,
+
+    /// header values the request was sent with on top of the configured ones, i.e. those with
+    /// the FUZZ keyword substituted, or the Host header in --vhost mode
+    sent_headers: Option<HashMap<String, String>>,
+
+    /// body the request was sent with, with the FUZZ keyword substituted, if it had one
+    sent_body: Option<Vec<u8>>,
+
+    /// body as it was received, when `text` doesn't hold the same bytes (i.e. binary bodies)
+    raw_body: Option<Vec<u8>>,
+}
+
+/// the given body decoded into text using the charset of its Content-Type, utf-8 otherwise, the
+/// same way reqwest's `Response::text` does; the raw bytes are returned along with the text when
+/// it doesn't hold them as-is, i.e. bodies that aren't valid in their charset, like images
+fn decode_body(headers: &HeaderMap, body: &[u8]) -> (String, Option<Vec<u8>>) {
+    let encoding = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<Mime>().ok())
+        .and_then(|mime| {
+            mime.get_param("charset")
+                .and_then(|charset| Encoding::for_label(charset.as_str().as_bytes()))
+        })
+        .unwrap_or(UTF_8);
+
+    match encoding.decode(body).0 {
+        Cow::Borrowed(text) => (text.to_string(), None),
+        Cow::Owned(text) => (text, Some(body.to_vec())),
+    }
 }
 
 /// implement Default trait for FeroxResponse
@@ -82,6 +160,12 @@ impl Default for FeroxResponse {
             wildcard: false,
             output_level: Default::default(),
             extension: None,
+            vhost: None,
+            methods: None,
+            saved_to: None,
+            sent_headers: None,
+            sent_body: None,
+            raw_body: None,
         }
     }
 }
@@ -123,6 +207,21 @@ impl FeroxResponse {
         &self.url
     }
 
+    /// Get the Host header value this `FeroxResponse` was requested with, if --vhost was used
+    pub fn vhost(&self) -> Option<&str> {
+        self.vhost.as_deref()
+    }
+
+    /// Get the methods discovered for this `FeroxResponse`, if --discover-methods was used
+    pub fn methods(&self) -> Option<&DiscoveredMethods> {
+        self.methods.as_ref()
+    }
+
+    /// Get the path this `FeroxResponse`'s body was saved to, if --save-responses was used
+    pub fn saved_to(&self) -> Option<&str> {
+        self.saved_to.as_deref()
+    }
+
     /// Get the full response text
     pub fn text(&self) -> &str {
         &self.text
@@ -133,6 +232,52 @@ impl FeroxResponse {
         &self.headers
     }
 
+    /// Headers of the request that produced this `FeroxResponse`, as used by --warc and
+    /// --har-out
+    ///
+    /// reqwest doesn't expose what it sent, so they're rebuilt from the configuration, with the
+    /// values set by `set_sent_request` (i.e. fuzzed headers) replacing the configured ones;
+    /// --random-agent isn't reflected
+    pub(crate) fn request_headers(&self, config: &Configuration) -> Vec<(String, String)> {
+        // virtual hosts all share the same url, their Host header is what tells them apart
+        let host = match (self.vhost(), self.url.host_str(), self.url.port()) {
+            (Some(vhost), _, _) => vhost.to_string(),
+            (None, Some(host), Some(port)) => format!("{host}:{port}"),
+            (None, Some(host), None) => host.to_string(),
+            _ => String::new(),
+        };
+
+        let mut headers = vec![(String::from("Host"), host)];
+
+        let user_agent_header = config
+            .headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case("user-agent"));
+
+        if !config.random_agent && !user_agent_header {
+            headers.push((String::from("User-Agent"), config.user_agent.clone()));
+        }
+
+        headers.extend(
+            config
+                .headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+
+        for (name, value) in self.sent_headers.iter().flatten() {
+            match headers
+                .iter_mut()
+                .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            {
+                Some((_, known_value)) => *known_value = value.clone(),
+                None => headers.push((name.clone(), value.clone())),
+            }
+        }
+
+        headers
+    }
+
     /// Get the content-length of this response, if known
     pub fn content_length(&self) -> u64 {
         self.content_length
@@ -155,6 +300,38 @@ impl FeroxResponse {
         self.wildcard = is_wildcard;
     }
 
+    /// set `vhost` attribute
+    pub fn set_vhost(&mut self, vhost: &str) {
+        self.vhost = Some(vhost.to_string());
+    }
+
+    /// set the header values and body the request was actually sent with, as opposed to the
+    /// configured ones they were built from
+    pub fn set_sent_request(
+        &mut self,
+        headers: Option<&HashMap<String, String>>,
+        body: Option<&[u8]>,
+    ) {
+        self.sent_headers = headers.cloned();
+        self.sent_body = body.map(<[u8]>::to_vec);
+    }
+
+    /// Get the body the request for this `FeroxResponse` was sent with, if it's known to have
+    /// had one
+    pub fn sent_body(&self) -> Option<&[u8]> {
+        self.sent_body.as_deref()
+    }
+
+    /// set `methods` attribute
+    pub fn set_methods(&mut self, methods: DiscoveredMethods) {
+        self.methods = Some(methods);
+    }
+
+    /// set `saved_to` attribute
+    pub fn set_saved_to(&mut self, path: &str) {
+        self.saved_to = Some(path.to_string());
+    }
+
     /// set `text` attribute; update words/lines/content_length
     #[cfg(test)]
     pub fn set_text(&mut self, text: &str) {
@@ -172,6 +349,12 @@ impl FeroxResponse {
     pub fn drop_text(&mut self) {
         self.text.clear(); // length is set to 0
         self.text.shrink_to_fit(); // allocated capacity shrinks to reflect the new size
+        self.raw_body = None;
+    }
+
+    /// Get the body as it was received, before it was decoded into `text`
+    pub fn body(&self) -> &[u8] {
+        self.raw_body.as_deref().unwrap_or(self.text.as_bytes())
     }
 
     /// Make a reasonable guess at whether the response is a file or not
@@ -217,13 +400,15 @@ impl FeroxResponse {
         let headers = response.headers().clone();
         let content_length = response.content_length().unwrap_or(0);
 
-        // .text() consumes the response, must be called last
-        let text = response
-            .text()
+        // .bytes() consumes the response, must be called last
+        let body = response
+            .bytes()
             .await
             .with_context(|| "Could not parse body from response")
             .unwrap_or_default();
 
+        let (text, raw_body) = decode_body(&headers, &body);
+
         // in the event that the content_length was 0, we can try to get the length
         // of the body we just parsed. At worst, it's still 0; at best we've accounted
         // for sites that reply without a content-length header and yet still have
@@ -248,6 +433,12 @@ impl FeroxResponse {
             output_level,
             wildcard: false,
             extension: None,
+            vhost: None,
+            methods: None,
+            saved_to: None,
+            sent_headers: None,
+            sent_body: None,
+            raw_body,
         }
     }
 
@@ -392,15 +583,21 @@ impl FeroxResponse {
     }
 
     /// Simple helper to send a `FeroxResponse` over the tx side of an `mpsc::unbounded_channel`
-    pub fn send_report(self, report_sender: CommandSender) -> Result<()> {
-        log::trace!("enter: send_report({:?}", report_sender);
+    ///
+    /// `keep_body` sends the body along with the response, i.e. when --save-responses, --warc,
+    /// --har-bodies or --secrets need it
+    pub fn send_report(self, report_sender: CommandSender, keep_body: bool) -> Result<()> {
+        log::trace!("enter: send_report({:?}, {})", report_sender, keep_body);
 
         // there's no reason to send the response body across the mpsc
         //
         // the only possible reason is for filtering on the body, but both `send_report`
         // calls are gated behind checks for `should_filter_response`
         let mut me = self;
-        me.drop_text();
+
+        if !keep_body {
+            me.drop_text();
+        }
 
         report_sender.send(Command::Report(Box::new(me)))?;
 
@@ -420,6 +617,11 @@ impl FeroxSerialize for FeroxResponse {
         let method = self.method().as_str();
         let wild_status = status_colorizer("WLD");
 
+        // virtual hosts all share the same url, so the host is reported in its place
+        let location = self
+            .vhost()
+            .map_or_else(|| self.url().to_string(), String::from);
+
         let mut url_with_redirect = match (
             self.status().is_redirection(),
             self.headers().get("Location").is_some(),
@@ -447,11 +649,11 @@ impl FeroxSerialize for FeroxResponse {
                 // prettify the redirect target
                 let loc = style(loc).yellow();
 
-                format!("{} => {loc}", self.url())
+                format!("{location} => {loc}")
             }
             _ => {
                 // no redirect, just use the normal url
-                self.url().to_string()
+                location.clone()
             }
         };
 
@@ -468,7 +670,7 @@ impl FeroxSerialize for FeroxResponse {
                 words,
                 chars,
                 status_colorizer(status),
-                self.url(),
+                location,
             );
 
             if self.status().is_redirection() {
@@ -488,7 +690,7 @@ impl FeroxSerialize for FeroxResponse {
             if matches!(self.output_level, OutputLevel::SilentJSON) {
                 self.as_json().unwrap_or_default()
             } else {
-                utils::create_report_string(
+                let mut report = utils::create_report_string(
                     self.status.as_str(),
                     method,
                     &lines,
@@ -496,7 +698,33 @@ impl FeroxSerialize for FeroxResponse {
                     &chars,
                     &url_with_redirect,
                     self.output_level,
-                )
+                );
+
+                match &self.methods {
+                    Some(methods) if !matches!(self.output_level, OutputLevel::Silent) => {
+                        // discovered methods go on their own line, directly beneath the finding
+                        //
+                        // ex: MTH      GET http://localhost/api [OPTIONS 200, PUT 201] (Allow: GET, PUT)
+                        let probes: Vec<_> = methods.probes.iter().map(|p| p.to_string()).collect();
+
+                        let allow = if methods.allow.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" (Allow: {})", methods.allow.join(", "))
+                        };
+
+                        report.push_str(&format!(
+                            "{} {:>8} {} [{}]{allow}\n",
+                            style("MTH").bg(console::Color::Magenta).black(),
+                            method,
+                            location,
+                            probes.join(", ")
+                        ));
+                    }
+                    _ => {}
+                }
+
+                report
             }
         }
     }
@@ -571,6 +799,18 @@ impl Serialize for FeroxResponse {
             self.extension.as_ref().unwrap_or(&String::new()),
         )?;
 
+        if let Some(vhost) = &self.vhost {
+            state.serialize_field("vhost", vhost)?;
+        }
+
+        if let Some(methods) = &self.methods {
+            state.serialize_field("methods", methods)?;
+        }
+
+        if let Some(saved_to) = &self.saved_to {
+            state.serialize_field("saved_to", saved_to)?;
+        }
+
         state.end()
     }
 }
@@ -595,6 +835,12 @@ impl<'de> Deserialize<'de> for FeroxResponse {
             line_count: 0,
             word_count: 0,
             extension: None,
+            vhost: None,
+            methods: None,
+            saved_to: None,
+            sent_headers: None,
+            sent_body: None,
+            raw_body: None,
         };
 
         let map: HashMap<String, Value> = HashMap::deserialize(deserializer)?;
@@ -668,6 +914,21 @@ impl<'de> Deserialize<'de> for FeroxResponse {
                         response.extension = Some(result.to_string());
                     }
                 }
+                "vhost" => {
+                    if let Some(vhost) = value.as_str() {
+                        response.vhost = Some(vhost.to_string());
+                    }
+                }
+                "methods" => {
+                    if let Ok(methods) = serde_json::from_value(value.clone()) {
+                        response.methods = Some(methods);
+                    }
+                }
+                "saved_to" => {
+                    if let Some(saved_to) = value.as_str() {
+                        response.saved_to = Some(saved_to.to_string());
+                    }
+                }
                 _ => {}
             }
         }
@@ -679,9 +940,109 @@ impl<'de> Deserialize<'de> for FeroxResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::Configuration;
     use std::default::Default;
 
+    #[test]
+    /// text bodies are decoded using their charset, and the raw bytes are only kept when the
+    /// text doesn't hold them as-is
+    fn decode_body_keeps_raw_bytes_of_binary_bodies() {
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(
+            decode_body(&headers, "caf\u{e9}".as_bytes()),
+            (String::from("caf\u{e9}"), None)
+        );
+
+        let image = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff];
+        let (_, raw) = decode_body(&headers, &image);
+        assert_eq!(raw.as_deref(), Some(image.as_slice()));
+
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/html; charset=iso-8859-1"),
+        );
+
+        assert_eq!(
+            decode_body(&headers, b"caf\xe9"),
+            (String::from("caf\u{e9}"), Some(b"caf\xe9".to_vec()))
+        );
+    }
+
+    #[test]
+    /// a response requested with a Host header reports the host in place of the url, and keeps
+    /// it when serialized
+    fn ferox_response_reports_vhost_instead_of_url() {
+        let mut response = FeroxResponse {
+            url: Url::parse("http://10.0.0.1/").unwrap(),
+            ..Default::default()
+        };
+        response.set_vhost("admin.example.com");
+
+        let report = response.as_str();
+        assert!(report.contains("admin.example.com"));
+        assert!(!report.contains("http://10.0.0.1/"));
+
+        let json = response.as_json().unwrap();
+        let deserialized: FeroxResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.vhost(), Some("admin.example.com"));
+        assert_eq!(deserialized.url().as_str(), "http://10.0.0.1/");
+    }
+
+    #[test]
+    /// discovered methods are reported on their own line beneath the finding, and survive a
+    /// round trip through serialization
+    fn ferox_response_reports_discovered_methods() {
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/api").unwrap(),
+            ..Default::default()
+        };
+        response.set_methods(DiscoveredMethods {
+            allow: vec![String::from("GET"), String::from("PUT")],
+            probes: vec![
+                MethodProbe {
+                    method: String::from("PUT"),
+                    status: 201,
+                    override_header: None,
+                },
+                MethodProbe {
+                    method: String::from("DELETE"),
+                    status: 204,
+                    override_header: Some(String::from("X-HTTP-Method-Override")),
+                },
+            ],
+        });
+
+        let report = console::strip_ansi_codes(&response.as_str()).to_string();
+        assert!(report.contains(
+            "http://localhost/api [PUT 201, X-HTTP-Method-Override: DELETE 204] (Allow: GET, PUT)"
+        ));
+
+        let json = response.as_json().unwrap();
+        let deserialized: FeroxResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.methods(), response.methods());
+    }
+
+    #[test]
+    /// the path a response's body was saved to is only serialized when it was saved, and
+    /// survives a round trip through serialization
+    fn ferox_response_serializes_saved_to() {
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/js/app.js").unwrap(),
+            ..Default::default()
+        };
+
+        assert!(!response.as_json().unwrap().contains("saved_to"));
+
+        response.set_saved_to("responses/localhost/js/app.js");
+
+        let json = response.as_json().unwrap();
+        let deserialized: FeroxResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.saved_to(),
+            Some("responses/localhost/js/app.js")
+        );
+    }
+
     #[test]
     /// call reached_max_depth with max depth of zero, which is infinite recursion, expect false
     fn reached_max_depth_returns_early_on_zero() {
@@ -0,0 +1,655 @@
+//! hashcat/john style rules used to mutate each word from the wordlist as it's requested
+//!
+//! each rule is a single line made up of one or more functions that are applied, in order, to a
+//! word. Every rule produces at least one candidate per word, and the original word is only
+//! requested when a rule asks for it (`:`), mirroring hashcat's behavior.
+//!
+//! supported functions:
+//!
+//! | function           | description                                       | example rule       | admin ->                 |
+//! |--------------------|---------------------------------------------------|--------------------|--------------------------|
+//! | `:`                | do nothing                                        | `:`                | `admin`                  |
+//! | `l`                | lowercase all letters                             | `l`                | `admin`                  |
+//! | `u`                | uppercase all letters                             | `u`                | `ADMIN`                  |
+//! | `c`                | capitalize the first letter, lowercase the rest   | `c`                | `Admin`                  |
+//! | `C`                | lowercase the first letter, uppercase the rest    | `C`                | `aDMIN`                  |
+//! | `t`                | toggle the case of all letters                    | `t`                | `ADMIN`                  |
+//! | `TN`               | toggle the case of the letter at position N       | `T1`               | `aDmin`                  |
+//! | `r`                | reverse the word                                  | `r`                | `nimda`                  |
+//! | `d`                | duplicate the word                                | `d`                | `adminadmin`             |
+//! | `$X`               | append character X                                | `$_ $1`            | `admin_1`                |
+//! | `^X`               | prepend character X                               | `^.`               | `.admin`                 |
+//! | `sXY`              | replace all instances of X with Y                 | `sa4`              | `4dmin`                  |
+//! | `@X`               | remove all instances of X                         | `@a`               | `dmin`                   |
+//! | `&[A-B]`           | append every number from A to B                   | `&[2019-2021]`     | `admin2019` ... `admin2021` |
+//! | `~[A-B]`           | prepend every number from A to B                  | `~[00-02]`         | `00admin` ... `02admin`  |
+//! | `&(START:END:FMT)` | append every date from START to END (YYYY-MM-DD)  | `&(2024-01-01:2024-01-31:%Y%m%d)` | `admin20240101` ... |
+//! | `~(START:END:FMT)` | prepend every date from START to END (YYYY-MM-DD) | `~(2024-01-01:2024-01-02:%y%m%d_)` | `240101_admin` ... |
+//!
+//! ranges are a feroxbuster extension; hashcat has no `&` or `~` function, so existing hashcat
+//! rule files keep their meaning (`$[` still appends a `[`). Number ranges keep the zero-padding
+//! of their start value (`&[00-99]`). Date formats support
+//! `%Y` (2024), `%y` (24), `%m` (01) and `%d` (09); the format defaults to `%Y%m%d`. Whitespace
+//! between functions is ignored and lines beginning with `#` are treated as comments.
+//!
+//! when multiple wordlists are combined (`-w dirs.txt:DIR -w versions.txt:VER`), rules only
+//! mutate the value from the first wordlist; the other values are left as they are.
+use crate::wordlists::KEYWORD_SEPARATOR;
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::Chars, sync::Arc};
+
+/// a single operation within a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Function {
+    /// `:`
+    Noop,
+
+    /// `l`
+    Lowercase,
+
+    /// `u`
+    Uppercase,
+
+    /// `c`
+    Capitalize,
+
+    /// `C`
+    InvertCapitalize,
+
+    /// `t`
+    ToggleCase,
+
+    /// `TN`
+    ToggleAt(usize),
+
+    /// `r`
+    Reverse,
+
+    /// `d`
+    Duplicate,
+
+    /// `$X`
+    Append(char),
+
+    /// `^X`
+    Prepend(char),
+
+    /// `sXY`
+    Substitute(char, char),
+
+    /// `@X`
+    Purge(char),
+
+    /// `&[A-B]` and `&(START:END:FMT)`; every value produces its own candidate
+    AppendEach(Range),
+
+    /// `~[A-B]` and `~(START:END:FMT)`; every value produces its own candidate
+    PrependEach(Range),
+}
+
+impl Function {
+    /// number of candidates produced for each word given to this function
+    fn num_candidates(&self) -> usize {
+        match self {
+            Function::AppendEach(range) | Function::PrependEach(range) => range.len(),
+            _ => 1,
+        }
+    }
+
+    /// apply this function to the given word, producing the candidate at the given index; the
+    /// index only matters for functions that produce more than one candidate
+    fn apply(&self, word: &str, idx: usize) -> String {
+        match self {
+            Function::Noop => word.to_string(),
+            Function::Lowercase => word.to_lowercase(),
+            Function::Uppercase => word.to_uppercase(),
+            Function::Capitalize => {
+                let lowered = word.to_lowercase();
+                let mut chars = lowered.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => lowered,
+                }
+            }
+            Function::InvertCapitalize => {
+                let uppered = word.to_uppercase();
+                let mut chars = uppered.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => uppered,
+                }
+            }
+            Function::ToggleCase => word.chars().map(toggle_case).collect(),
+            Function::ToggleAt(position) => word
+                .chars()
+                .enumerate()
+                .map(|(idx, c)| if idx == *position { toggle_case(c) } else { c })
+                .collect(),
+            Function::Reverse => word.chars().rev().collect(),
+            Function::Duplicate => word.repeat(2),
+            Function::Append(c) => format!("{word}{c}"),
+            Function::Prepend(c) => format!("{c}{word}"),
+            Function::Substitute(from, to) => word.replace(*from, &to.to_string()),
+            Function::Purge(c) => word.replace(*c, ""),
+            Function::AppendEach(range) => format!("{word}{}", range.value(idx)),
+            Function::PrependEach(range) => format!("{}{word}", range.value(idx)),
+        }
+    }
+}
+
+/// the values of a number or date range; each value is computed from its index when it's needed,
+/// so large ranges don't take up any memory
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Range {
+    /// every number from `first` to `last` (inclusive), zero-padded to `width`
+    Numbers {
+        first: u64,
+        len: usize,
+        width: usize,
+    },
+
+    /// every day from `first` (days since 1970-01-01) to the `len`'th day after it, formatted
+    /// using `format`
+    Dates {
+        first: i64,
+        len: usize,
+        format: String,
+    },
+}
+
+impl Range {
+    /// number of values in the range
+    fn len(&self) -> usize {
+        match self {
+            Range::Numbers { len, .. } | Range::Dates { len, .. } => *len,
+        }
+    }
+
+    /// the value at the given index
+    fn value(&self, idx: usize) -> String {
+        match self {
+            Range::Numbers { first, width, .. } => format!("{:0width$}", first + idx as u64),
+            Range::Dates { first, format, .. } => {
+                let (year, month, day) = civil_from_days(first + idx as i64);
+
+                format
+                    .replace("%Y", &format!("{year:04}"))
+                    .replace("%y", &format!("{:02}", year % 100))
+                    .replace("%m", &format!("{month:02}"))
+                    .replace("%d", &format!("{day:02}"))
+            }
+        }
+    }
+}
+
+/// swap the case of a single character, returning the first char of the result
+fn toggle_case(c: char) -> char {
+    if c.is_lowercase() {
+        c.to_uppercase().next().unwrap_or(c)
+    } else {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+}
+
+/// a single rule (one line from a rules file), made up of one or more functions
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    /// the rule as it was written
+    source: String,
+
+    /// parsed functions, applied in order
+    functions: Vec<Function>,
+}
+
+impl Rule {
+    /// parse a single rule
+    fn parse(source: &str) -> Result<Self> {
+        let mut functions = Vec::new();
+        let mut chars = source.chars();
+
+        while let Some(c) = chars.next() {
+            let function = match c {
+                ' ' | '\t' => continue,
+                ':' => Function::Noop,
+                'l' => Function::Lowercase,
+                'u' => Function::Uppercase,
+                'c' => Function::Capitalize,
+                'C' => Function::InvertCapitalize,
+                't' => Function::ToggleCase,
+                'r' => Function::Reverse,
+                'd' => Function::Duplicate,
+                'T' => {
+                    let position = next_char(&mut chars, c)?;
+                    let position = position
+                        .to_digit(36)
+                        .ok_or_else(|| anyhow!("Invalid position '{position}' for T"))?;
+                    Function::ToggleAt(position as usize)
+                }
+                's' => Function::Substitute(next_char(&mut chars, c)?, next_char(&mut chars, c)?),
+                '@' => Function::Purge(next_char(&mut chars, c)?),
+                '$' => Function::Append(next_char(&mut chars, c)?),
+                '^' => Function::Prepend(next_char(&mut chars, c)?),
+                '&' | '~' => {
+                    let range = match next_char(&mut chars, c)? {
+                        '[' => parse_number_range(&take_until(&mut chars, ']')?)?,
+                        '(' => parse_date_range(&take_until(&mut chars, ')')?)?,
+                        arg => bail!("Rule function '{c}' expects a range, found '{arg}'"),
+                    };
+
+                    if c == '&' {
+                        Function::AppendEach(range)
+                    } else {
+                        Function::PrependEach(range)
+                    }
+                }
+                _ => bail!("Unknown rule function '{c}' in rule: {source}"),
+            };
+
+            functions.push(function);
+        }
+
+        if functions.is_empty() {
+            bail!("Rule is empty: {source}");
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            functions,
+        })
+    }
+
+    /// number of candidates produced for each word given to this rule
+    fn num_candidates(&self) -> usize {
+        self.functions
+            .iter()
+            .map(|function| function.num_candidates())
+            .fold(1, usize::saturating_mul)
+    }
+
+    /// apply every function in this rule to the given word, producing the candidate at the given
+    /// index
+    ///
+    /// the index is treated as a mixed-radix number, where each digit selects one of a function's
+    /// candidates and the last function is the least significant digit
+    fn apply(&self, word: &str, idx: usize) -> String {
+        let mut digits = vec![0; self.functions.len()];
+        let mut remainder = idx;
+
+        for (digit, function) in digits.iter_mut().zip(&self.functions).rev() {
+            *digit = remainder % function.num_candidates();
+            remainder /= function.num_candidates();
+        }
+
+        self.functions
+            .iter()
+            .zip(digits)
+            .fold(word.to_string(), |candidate, (function, digit)| {
+                function.apply(&candidate, digit)
+            })
+    }
+}
+
+/// helper to get the argument of a function, erroring when the rule ends early
+fn next_char(chars: &mut Chars, function: char) -> Result<char> {
+    chars
+        .next()
+        .ok_or_else(|| anyhow!("Missing argument for rule function '{function}'"))
+}
+
+/// helper to collect the contents of a range, up to (and consuming) the closing delimiter
+fn take_until(chars: &mut Chars, closing: char) -> Result<String> {
+    let mut contents = String::new();
+
+    for c in chars.by_ref() {
+        if c == closing {
+            return Ok(contents);
+        }
+        contents.push(c);
+    }
+
+    bail!("Missing closing '{closing}' in rule range")
+}
+
+/// parse `A-B` into every number from A to B (inclusive), keeping any zero-padding of A
+fn parse_number_range(range: &str) -> Result<Range> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| anyhow!("Number range must look like [A-B], found [{range}]"))?;
+
+    let first: u64 = start.trim().parse()?;
+    let last: u64 = end.trim().parse()?;
+
+    if first > last {
+        bail!("Number range start is greater than its end: [{range}]");
+    }
+
+    let width = if start.trim().starts_with('0') {
+        start.trim().len()
+    } else {
+        0
+    };
+
+    let len = usize::try_from(last - first)
+        .ok()
+        .and_then(|len| len.checked_add(1))
+        .ok_or_else(|| anyhow!("Number range is too large: [{range}]"))?;
+
+    Ok(Range::Numbers { first, len, width })
+}
+
+/// parse `START:END[:FMT]` into every date from START to END (inclusive), formatted using FMT
+fn parse_date_range(range: &str) -> Result<Range> {
+    let mut parts = range.splitn(3, ':');
+
+    let start = parse_date(parts.next().unwrap_or_default())?;
+    let end = parse_date(parts.next().unwrap_or_default())?;
+    let format = parts.next().unwrap_or("%Y%m%d");
+
+    if start > end {
+        bail!("Date range start is after its end: ({range})");
+    }
+
+    let first = days_from_civil(start);
+
+    Ok(Range::Dates {
+        first,
+        len: (days_from_civil(end) - first) as usize + 1,
+        format: format.to_string(),
+    })
+}
+
+/// number of days between 1970-01-01 and the given (year, month, day)
+///
+/// see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil((year, month, day): (u32, u32, u32)) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let month = i64::from(month);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// the (year, month, day) that's the given number of days after 1970-01-01; the inverse of
+/// [`days_from_civil`]
+fn civil_from_days(days: i64) -> (u32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as u32, month as u32, day as u32)
+}
+
+/// parse a YYYY-MM-DD date into its (year, month, day)
+fn parse_date(date: &str) -> Result<(u32, u32, u32)> {
+    let mut pieces = date.trim().splitn(3, '-').map(|piece| piece.parse::<u32>());
+
+    match (pieces.next(), pieces.next(), pieces.next()) {
+        (Some(Ok(year)), Some(Ok(month)), Some(Ok(day)))
+            if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) =>
+        {
+            Ok((year, month, day))
+        }
+        _ => bail!("Dates in rules must look like YYYY-MM-DD, found {date}"),
+    }
+}
+
+/// number of days in the given month, accounting for leap years
+#[allow(clippy::manual_is_multiple_of)] // u32::is_multiple_of needs a newer rust than the crate's msrv
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && year % 100 != 0 || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// collection of rules used to mutate each word from the wordlist
+///
+/// stored in the configuration as the original rule strings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct Rules {
+    /// parsed rules, applied in order
+    rules: Arc<Vec<Rule>>,
+}
+
+impl Rules {
+    /// parse the given rules, ignoring blank lines and comments
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self> {
+        let rules = lines
+            .iter()
+            .map(|line| line.as_ref().trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(Rule::parse)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            rules: Arc::new(rules),
+        })
+    }
+
+    /// whether or not any rules were given
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// number of candidates each word from the wordlist expands into; 1 when there are no rules
+    pub fn num_candidates(&self) -> usize {
+        if self.rules.is_empty() {
+            return 1;
+        }
+
+        self.rules
+            .iter()
+            .map(|rule| rule.num_candidates())
+            .fold(0, usize::saturating_add)
+    }
+
+    /// expand a word from the wordlist into every candidate produced by the rules; candidates are
+    /// built as the iterator is consumed
+    ///
+    /// when there are no rules, the word is returned as-is. For a combined word (one value per
+    /// keyword), only the first value is mutated
+    pub fn expand(&self, word: &str) -> impl Iterator<Item = String> + Send + 'static {
+        let rules = self.rules.clone();
+
+        let (value, others) = match word.split_once(KEYWORD_SEPARATOR) {
+            Some((value, others)) => (value.to_string(), Some(others.to_string())),
+            None => (word.to_string(), None),
+        };
+
+        let unchanged = rules.is_empty().then(|| value.clone());
+
+        let candidates = (0..rules.len()).flat_map(move |rule_idx| {
+            let rules = rules.clone();
+            let value = value.clone();
+
+            (0..rules[rule_idx].num_candidates()).map(move |idx| rules[rule_idx].apply(&value, idx))
+        });
+
+        unchanged
+            .into_iter()
+            .chain(candidates)
+            .map(move |candidate| match &others {
+                Some(others) => format!("{candidate}{KEYWORD_SEPARATOR}{others}"),
+                None => candidate,
+            })
+    }
+}
+
+impl TryFrom<Vec<String>> for Rules {
+    type Error = anyhow::Error;
+
+    fn try_from(lines: Vec<String>) -> Result<Self> {
+        Self::parse(&lines)
+    }
+}
+
+impl From<Rules> for Vec<String> {
+    fn from(rules: Rules) -> Self {
+        rules.rules.iter().map(|rule| rule.source.clone()).collect()
+    }
+}
+
+impl fmt::Display for Rules {
+    /// comma separated list of the rules, as they were written
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sources: Vec<&str> = self.rules.iter().map(|rule| rule.source.as_str()).collect();
+        write!(f, "{}", sources.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// helper to expand a single word using the given rules
+    fn expand(rules: &[&str], word: &str) -> Vec<String> {
+        Rules::parse(rules).unwrap().expand(word).collect()
+    }
+
+    #[test]
+    /// case functions change the case of the word as expected
+    fn rules_apply_case_functions() {
+        assert_eq!(
+            expand(&[":", "l", "u", "c", "C", "t", "T1"], "aDmIn"),
+            vec!["aDmIn", "admin", "ADMIN", "Admin", "aDMIN", "AdMiN", "admIn"]
+        );
+    }
+
+    #[test]
+    /// functions within a rule are applied in order
+    fn rules_apply_functions_in_order() {
+        assert_eq!(
+            expand(&["c $_ $1", "^. sa4", "@a r", "d"], "admin"),
+            vec!["Admin_1", ".4dmin", "nimd", "adminadmin"]
+        );
+    }
+
+    #[test]
+    /// brackets after `$` and `^` keep their hashcat meaning of appending/prepending a character
+    fn rules_keep_hashcat_meaning_of_brackets() {
+        assert_eq!(
+            expand(&["$[ $]", "^( ^)"], "admin"),
+            vec!["admin[]", ")(admin"]
+        );
+    }
+
+    #[test]
+    /// number ranges produce one candidate per number and keep zero-padding
+    fn rules_expand_number_ranges() {
+        let rules = Rules::parse(&["&[2019-2021]", "~[08-10]"]).unwrap();
+
+        assert_eq!(rules.num_candidates(), 6);
+        assert_eq!(
+            rules.expand("backup").collect::<Vec<_>>(),
+            vec![
+                "backup2019",
+                "backup2020",
+                "backup2021",
+                "08backup",
+                "09backup",
+                "10backup"
+            ]
+        );
+    }
+
+    #[test]
+    /// date ranges roll over months and years, and respect leap years
+    fn rules_expand_date_ranges() {
+        assert_eq!(
+            expand(&["&(2023-12-31:2024-01-01)"], "log"),
+            vec!["log20231231", "log20240101"]
+        );
+        assert_eq!(
+            expand(&["~(2024-02-28:2024-03-01:%y-%m-%d_)"], "db"),
+            vec!["24-02-28_db", "24-02-29_db", "24-03-01_db"]
+        );
+        assert_eq!(expand(&["&(1999-12-31:2000-03-01:%Y%m%d)"], "x").len(), 62);
+    }
+
+    #[test]
+    /// converting to and from days since the epoch round trips, and lands on known dates
+    fn rules_convert_dates_to_days() {
+        assert_eq!(days_from_civil((1970, 1, 1)), 0);
+        assert_eq!(days_from_civil((2000, 3, 1)), 11017);
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+
+        for days in (-719468..100_000).step_by(97) {
+            assert_eq!(days_from_civil(civil_from_days(days)), days);
+        }
+    }
+
+    #[test]
+    /// huge ranges are counted without being generated, and any candidate can be reached
+    fn rules_expand_large_ranges_lazily() {
+        let rules = Rules::parse(&["&[0-18446744073709551614]"]).unwrap();
+
+        assert_eq!(rules.num_candidates(), usize::MAX);
+        assert_eq!(
+            rules.expand("w").nth(1_000_000),
+            Some(String::from("w1000000"))
+        );
+        assert!(Rules::parse(&["&[0-18446744073709551615]"]).is_err());
+    }
+
+    #[test]
+    /// only the first value of a combined word is mutated
+    fn rules_expand_first_value_of_combined_word() {
+        assert_eq!(
+            expand(&[":", "u &[1-2]"], "admin\nv1\nbeta"),
+            vec!["admin\nv1\nbeta", "ADMIN1\nv1\nbeta", "ADMIN2\nv1\nbeta"]
+        );
+    }
+
+    #[test]
+    /// ranges within the same rule multiply, and the count is known without any words
+    fn rules_count_candidates() {
+        let rules = Rules::parse(&[":", "&[0-9] &[0-1]", "# comment", ""]).unwrap();
+        assert_eq!(rules.num_candidates(), 21);
+        assert_eq!(rules.expand("word").count(), 21);
+        assert_eq!(Rules::default().num_candidates(), 1);
+        assert_eq!(
+            Rules::default().expand("word").collect::<Vec<_>>(),
+            vec!["word"]
+        );
+    }
+
+    #[test]
+    /// bad rules are reported instead of silently ignored
+    fn rules_reject_invalid_input() {
+        assert!(Rules::parse(&["x"]).is_err());
+        assert!(Rules::parse(&["$"]).is_err());
+        assert!(Rules::parse(&["&[10-1]"]).is_err());
+        assert!(Rules::parse(&["&[0-9"]).is_err());
+        assert!(Rules::parse(&["~1"]).is_err());
+        assert!(Rules::parse(&["&(2024-02-30:2024-03-01)"]).is_err());
+    }
+
+    #[test]
+    /// rules round trip through their serialized form
+    fn rules_serialize_as_strings() {
+        let rules = Rules::parse(&["c", "&[0-9]"]).unwrap();
+        let json = serde_json::to_string(&rules).unwrap();
+        assert_eq!(json, r#"["c","&[0-9]"]"#);
+        assert_eq!(serde_json::from_str::<Rules>(&json).unwrap(), rules);
+        assert_eq!(rules.to_string(), "c, &[0-9]");
+    }
+}
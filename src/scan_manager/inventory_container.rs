@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::{Arc, RwLock};
+
+use serde::{ser::SerializeSeq, Serialize, Serializer};
+
+/// A single record of an inventory built up over the course of a scan (i.e. the parameters of an
+/// endpoint, or the technologies of a host)
+///
+/// records with the same key describe the same thing and are merged together
+pub trait InventoryRecord: Clone {
+    /// what identifies the record within the inventory
+    type Key: Hash + Eq + Debug;
+
+    /// the record's key
+    fn key(&self) -> Self::Key;
+
+    /// fold another record with the same key into this one
+    fn merge(&mut self, other: Self);
+
+    /// whether the record holds anything worth keeping; empty records aren't added
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+/// records of an inventory, in the order in which they were first added, along with the position
+/// of each key
+#[derive(Debug)]
+struct Records<T: InventoryRecord> {
+    /// records in insertion order
+    records: Vec<T>,
+
+    /// index into `records` for each key
+    positions: HashMap<T::Key, usize>,
+}
+
+impl<T: InventoryRecord> Default for Records<T> {
+    fn default() -> Self {
+        Self {
+            records: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+}
+
+/// Container around a locked set of `InventoryRecord`s, keyed by each record's key
+#[derive(Debug)]
+pub struct FeroxInventory<T: InventoryRecord> {
+    /// Internal structure: locked records and their positions
+    inner: Arc<RwLock<Records<T>>>,
+}
+
+impl<T: InventoryRecord> Default for FeroxInventory<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Records::default())),
+        }
+    }
+}
+
+/// Serialize implementation for FeroxInventory
+impl<T: InventoryRecord + Serialize> Serialize for FeroxInventory<T> {
+    /// Function that handles serialization of FeroxInventory
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Ok(inner) = self.inner.read() {
+            let mut seq = serializer.serialize_seq(Some(inner.records.len()))?;
+
+            for record in inner.records.iter() {
+                seq.serialize_element(record)?;
+            }
+
+            seq.end()
+        } else {
+            // if for some reason we can't unlock the mutex, just write an empty list
+            let seq = serializer.serialize_seq(Some(0))?;
+            seq.end()
+        }
+    }
+}
+
+/// Implementation of `FeroxInventory`
+impl<T: InventoryRecord> FeroxInventory<T> {
+    /// Add a record (i.e. from a state file) to the inventory, merging it with the known record
+    /// of the same key
+    pub fn insert(&self, new: T) {
+        if new.is_empty() {
+            return;
+        }
+
+        if let Ok(mut inner) = self.inner.write() {
+            let Records { records, positions } = &mut *inner;
+
+            match positions.get(&new.key()) {
+                Some(&position) => records[position].merge(new),
+                None => {
+                    positions.insert(new.key(), records.len());
+                    records.push(new);
+                }
+            }
+        }
+    }
+
+    /// copy of every record in the inventory
+    pub fn snapshot(&self) -> Vec<T> {
+        match self.inner.read() {
+            Ok(inner) => inner.records.clone(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 mod scan_container;
 mod response_container;
+mod inventory_container;
+mod parameter_container;
+mod technology_container;
 mod scan;
 mod menu;
 mod utils;
@@ -11,8 +14,10 @@ mod tests;
 use menu::Menu;
 pub use menu::{MenuCmd, MenuCmdResult};
 pub use order::ScanOrder;
+pub use parameter_container::{EndpointParameters, FeroxParameters, ParameterSource};
 pub use response_container::FeroxResponses;
 pub use scan::{FeroxScan, ScanStatus, ScanType};
 pub use scan_container::{FeroxScans, PAUSE_SCAN};
 pub use state::FeroxState;
+pub use technology_container::{Evidence, FeroxTechnologies, HostTechnologies, Technology};
 pub use utils::{resume_scan, start_max_time_thread};
@@ -0,0 +1,143 @@
+use std::collections::BTreeSet;
+
+use anyhow::Context;
+use console::style;
+use serde::{Deserialize, Serialize};
+
+use super::inventory_container::{FeroxInventory, InventoryRecord};
+use crate::{traits::FeroxSerialize, utils::fmt_err};
+
+/// Where a set of parameters was found
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterSource {
+    /// `<form>` inputs, selects, and textareas
+    Form,
+
+    /// query string keys on an extracted link
+    Query,
+
+    /// object keys passed to fetch/XHR/axios/jQuery calls in javascript
+    JavaScript,
+
+    /// confirmed by brute forcing parameter names (--param-mine)
+    Mined,
+}
+
+/// Every parameter known to be accepted by a single endpoint (url + method)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointParameters {
+    #[serde(rename = "type", default = "parameters_type")]
+    /// Name of this type of struct, used for serialization, i.e. `{"type":"parameters"}`
+    kind: String,
+
+    /// url of the endpoint, without a query string
+    pub(crate) url: String,
+
+    /// http method the parameters are sent with
+    pub(crate) method: String,
+
+    /// names of the parameters
+    pub(crate) parameters: BTreeSet<String>,
+
+    /// where the parameters were found
+    pub(crate) sources: BTreeSet<ParameterSource>,
+}
+
+/// default value for `EndpointParameters.kind`
+fn parameters_type() -> String {
+    String::from("parameters")
+}
+
+/// implementation of EndpointParameters
+impl EndpointParameters {
+    /// create a new EndpointParameters from a single source
+    pub fn new<I, S>(url: &str, method: &str, names: I, source: ParameterSource) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            kind: parameters_type(),
+            url: url.to_string(),
+            method: method.to_uppercase(),
+            parameters: names
+                .into_iter()
+                .map(Into::into)
+                .filter(|name: &String| !name.is_empty())
+                .collect(),
+            sources: BTreeSet::from([source]),
+        }
+    }
+}
+
+/// FeroxSerialize implementation for EndpointParameters
+impl FeroxSerialize for EndpointParameters {
+    /// Create a string representation of the endpoint's parameters
+    ///
+    /// ex: PRM     POST https://localhost/login [password, username]
+    fn as_str(&self) -> String {
+        let parameters: Vec<_> = self.parameters.iter().map(String::as_str).collect();
+
+        format!(
+            "{} {:>8} {} [{}]\n",
+            style("PRM").bg(console::Color::Blue).black(),
+            self.method,
+            self.url,
+            parameters.join(", ")
+        )
+    }
+
+    /// Create an NDJSON representation of the endpoint's parameters
+    ///
+    /// (expanded for clarity)
+    /// ex:
+    /// {
+    ///   "type": "parameters",
+    ///   "url": "https://localhost/login",
+    ///   "method": "POST",
+    ///   "parameters": ["password", "username"],
+    ///   "sources": ["form"]
+    /// }\n
+    fn as_json(&self) -> anyhow::Result<String> {
+        let mut json = serde_json::to_string(&self)
+            .with_context(|| fmt_err(&format!("Could not convert {} to JSON", self.url)))?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// InventoryRecord implementation for EndpointParameters; endpoints are keyed by url and method
+impl InventoryRecord for EndpointParameters {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.url.clone(), self.method.clone())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.parameters.extend(other.parameters);
+        self.sources.extend(other.sources);
+    }
+
+    /// endpoints without any parameters aren't recorded
+    fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+}
+
+/// the scan's parameter inventory
+pub type FeroxParameters = FeroxInventory<EndpointParameters>;
+
+/// Implementation of `FeroxParameters`
+impl FeroxParameters {
+    /// Record the given parameter names for the endpoint at `url`, merging them with any that
+    /// are already known; endpoints without any parameters aren't recorded
+    pub fn add<I, S>(&self, url: &str, method: &str, names: I, source: ParameterSource)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.insert(EndpointParameters::new(url, method, names, source));
+    }
+}
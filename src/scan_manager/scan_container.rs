@@ -22,7 +22,7 @@ use console::style;
 use reqwest::StatusCode;
 use serde::{ser::SerializeSeq, Serialize, Serializer};
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     convert::TryInto,
     fs::File,
     io::BufReader,
@@ -60,6 +60,13 @@ pub struct FeroxScans {
 
     /// vector of extensions discovered and collected during scans
     pub(crate) collected_extensions: RwLock<HashSet<String>>,
+
+    /// decisions made by --detect-case, keyed by origin (i.e. http://localhost:8080); `true`
+    /// means the target is case-insensitive
+    pub(crate) case_insensitive: RwLock<HashMap<String, bool>>,
+
+    /// origins for which --detect-case is currently running its heuristic
+    case_detections: Mutex<HashSet<String>>,
 }
 
 /// Serialize implementation for FeroxScans
@@ -172,6 +179,16 @@ impl FeroxScans {
             }
         }
 
+        if let Some(decisions) = state.get("case_insensitive") {
+            if let Some(obj_decisions) = decisions.as_object() {
+                if let Ok(mut guard) = self.case_insensitive.write() {
+                    for (origin, insensitive) in obj_decisions {
+                        guard.insert(origin.to_owned(), insensitive.as_bool().unwrap_or_default());
+                    }
+                }
+            }
+        }
+
         if let Some(filters) = state.get("filters") {
             if let Some(arr_filters) = filters.as_array() {
                 for filter in arr_filters {
@@ -709,6 +726,57 @@ impl FeroxScans {
         log::trace!("exit: add_discovered_extension -> {}", extension_added);
         extension_added
     }
+
+    /// whether --detect-case found the target at the given origin to be case-insensitive; None
+    /// when no decision has been made for it yet
+    pub fn is_case_insensitive(&self, origin: &str) -> Option<bool> {
+        self.case_insensitive
+            .read()
+            .ok()
+            .and_then(|decisions| decisions.get(origin).copied())
+    }
+
+    /// record the decision made by --detect-case for the given origin
+    /// returns `true` if the decision was recorded, `false` if one had already been made
+    pub fn add_case_decision(&self, origin: &str, insensitive: bool) -> bool {
+        log::trace!("enter: add_case_decision({}, {})", origin, insensitive);
+        let mut decision_added = false;
+
+        if let Ok(mut decisions) = self.case_insensitive.write() {
+            if !decisions.contains_key(origin) {
+                decisions.insert(origin.to_string(), insensitive);
+                decision_added = true;
+            }
+        }
+
+        log::trace!("exit: add_case_decision -> {}", decision_added);
+        decision_added
+    }
+
+    /// all decisions made by --detect-case so far
+    pub fn case_decisions(&self) -> HashMap<String, bool> {
+        match self.case_insensitive.read() {
+            Ok(decisions) => decisions.clone(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    /// claim the --detect-case heuristic for the given origin, so that only one of its
+    /// resources is tested at a time
+    /// returns `true` if claimed, `false` if the heuristic is already running for the origin
+    pub fn start_case_detection(&self, origin: &str) -> bool {
+        match self.case_detections.lock() {
+            Ok(mut detections) => detections.insert(origin.to_string()),
+            Err(_) => false,
+        }
+    }
+
+    /// release the claim made by `start_case_detection`
+    pub fn finish_case_detection(&self, origin: &str) {
+        if let Ok(mut detections) = self.case_detections.lock() {
+            detections.remove(origin);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -745,4 +813,31 @@ mod tests {
         assert!(!added);
         assert_eq!(1, scans.collected_extensions.read().unwrap().len());
     }
+
+    #[test]
+    /// the first case-sensitivity decision for an origin should stick
+    fn first_case_decision_is_kept() {
+        let scans = FeroxScans::new(OutputLevel::Default);
+
+        assert_eq!(scans.is_case_insensitive("http://localhost"), None);
+
+        assert!(scans.add_case_decision("http://localhost", true));
+        assert!(!scans.add_case_decision("http://localhost", false));
+
+        assert_eq!(scans.is_case_insensitive("http://localhost"), Some(true));
+        assert_eq!(scans.is_case_insensitive("http://otherhost"), None);
+    }
+
+    #[test]
+    /// only one case-sensitivity heuristic runs per origin at a time
+    fn case_detection_is_claimed_per_origin() {
+        let scans = FeroxScans::new(OutputLevel::Default);
+
+        assert!(scans.start_case_detection("http://localhost"));
+        assert!(!scans.start_case_detection("http://localhost"));
+        assert!(scans.start_case_detection("http://otherhost"));
+
+        scans.finish_case_detection("http://localhost");
+        assert!(scans.start_case_detection("http://localhost"));
+    }
 }
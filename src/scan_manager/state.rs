@@ -3,7 +3,7 @@ use crate::filters::FeroxFilters;
 use crate::{config::Configuration, statistics::Stats, traits::FeroxSerialize, utils::fmt_err};
 use anyhow::{Context, Result};
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Data container for (de)?serialization of multiple items
@@ -18,12 +18,21 @@ pub struct FeroxState {
     /// Known responses
     responses: &'static FeroxResponses,
 
+    /// Parameter inventory
+    parameters: &'static FeroxParameters,
+
+    /// Technology inventory
+    technologies: &'static FeroxTechnologies,
+
     /// Gathered statistics
     statistics: Arc<Stats>,
 
     /// collected extensions
     collected_extensions: HashSet<String>,
 
+    /// case-sensitivity decisions made by --detect-case, keyed by origin
+    case_insensitive: HashMap<String, bool>,
+
     /// runtime filters, as they may differ from original config
     filters: Arc<FeroxFilters>,
 }
@@ -35,6 +44,8 @@ impl FeroxState {
         scans: Arc<FeroxScans>,
         config: Arc<Configuration>,
         responses: &'static FeroxResponses,
+        parameters: &'static FeroxParameters,
+        technologies: &'static FeroxTechnologies,
         statistics: Arc<Stats>,
         filters: Arc<FeroxFilters>,
     ) -> Self {
@@ -43,12 +54,17 @@ impl FeroxState {
             Err(_) => HashSet::new(),
         };
 
+        let case_insensitive = scans.case_decisions();
+
         Self {
             scans,
             config,
             responses,
+            parameters,
+            technologies,
             statistics,
             collected_extensions,
+            case_insensitive,
             filters,
         }
     }
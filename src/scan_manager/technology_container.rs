@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use anyhow::Context;
+use console::style;
+use serde::{Deserialize, Serialize};
+
+use super::inventory_container::{FeroxInventory, InventoryRecord};
+use crate::{traits::FeroxSerialize, utils::fmt_err};
+
+/// What part of a response a technology was recognized by
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Evidence {
+    /// a response header's value
+    Header,
+
+    /// the name of a cookie that was set
+    Cookie,
+
+    /// the response body
+    Body,
+
+    /// the content of a `<meta name="generator">` tag
+    Meta,
+
+    /// the hash of the host's /favicon.ico
+    Favicon,
+}
+
+/// A single technology detected on a host, along with every version seen
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Technology {
+    /// name of the technology, i.e. nginx
+    pub(crate) name: String,
+
+    /// versions seen, if any were disclosed
+    pub(crate) versions: BTreeSet<String>,
+
+    /// what the technology was recognized by
+    pub(crate) evidence: BTreeSet<Evidence>,
+}
+
+/// implementation of Technology
+impl Technology {
+    /// create a new Technology from a single detection
+    pub fn new(name: &str, version: Option<&str>, evidence: Evidence) -> Self {
+        Self {
+            name: name.to_string(),
+            versions: version.map(str::to_string).into_iter().collect(),
+            evidence: BTreeSet::from([evidence]),
+        }
+    }
+}
+
+/// Every technology detected on a single host
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostTechnologies {
+    #[serde(rename = "type", default = "technologies_type")]
+    /// Name of this type of struct, used for serialization, i.e. `{"type":"technologies"}`
+    kind: String,
+
+    /// host (and port, when it isn't the scheme's default) the technologies were detected on
+    pub(crate) host: String,
+
+    /// technologies detected, sorted by name (case-insensitively)
+    pub(crate) technologies: Vec<Technology>,
+}
+
+/// default value for `HostTechnologies.kind`
+fn technologies_type() -> String {
+    String::from("technologies")
+}
+
+/// implementation of HostTechnologies
+impl HostTechnologies {
+    /// create a new HostTechnologies from a single technology
+    pub fn new(host: &str, technology: Technology) -> Self {
+        Self {
+            kind: technologies_type(),
+            host: host.to_string(),
+            technologies: vec![technology],
+        }
+    }
+
+    /// merge the given technology with the known technology of the same name
+    fn merge_technology(&mut self, new: Technology) {
+        match self
+            .technologies
+            .iter_mut()
+            .find(|technology| technology.name == new.name)
+        {
+            Some(technology) => {
+                technology.versions.extend(new.versions);
+                technology.evidence.extend(new.evidence);
+            }
+            None => {
+                self.technologies.push(new);
+                self.technologies
+                    .sort_by_key(|technology| technology.name.to_lowercase());
+            }
+        }
+    }
+}
+
+/// FeroxSerialize implementation for HostTechnologies
+impl FeroxSerialize for HostTechnologies {
+    /// Create a string representation of the host's technologies
+    ///
+    /// ex: TEC localhost:8080 [Apache HTTP Server 2.4.41, PHP 7.4.3, WordPress]
+    fn as_str(&self) -> String {
+        let technologies: Vec<_> = self
+            .technologies
+            .iter()
+            .map(|technology| {
+                if technology.versions.is_empty() {
+                    technology.name.clone()
+                } else {
+                    let versions: Vec<_> = technology.versions.iter().map(String::as_str).collect();
+                    format!("{} {}", technology.name, versions.join("/"))
+                }
+            })
+            .collect();
+
+        format!(
+            "{} {} [{}]\n",
+            style("TEC").bg(console::Color::Cyan).black(),
+            self.host,
+            technologies.join(", ")
+        )
+    }
+
+    /// Create an NDJSON representation of the host's technologies
+    ///
+    /// (expanded for clarity)
+    /// ex:
+    /// {
+    ///   "type": "technologies",
+    ///   "host": "localhost:8080",
+    ///   "technologies": [
+    ///     {"name": "Apache HTTP Server", "versions": ["2.4.41"], "evidence": ["header"]},
+    ///     {"name": "WordPress", "versions": [], "evidence": ["body", "meta"]}
+    ///   ]
+    /// }\n
+    fn as_json(&self) -> anyhow::Result<String> {
+        let mut json = serde_json::to_string(&self)
+            .with_context(|| fmt_err(&format!("Could not convert {} to JSON", self.host)))?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// InventoryRecord implementation for HostTechnologies; technologies are keyed by host
+impl InventoryRecord for HostTechnologies {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.host.clone()
+    }
+
+    fn merge(&mut self, other: Self) {
+        for technology in other.technologies {
+            self.merge_technology(technology);
+        }
+    }
+}
+
+/// the scan's technology inventory
+pub type FeroxTechnologies = FeroxInventory<HostTechnologies>;
+
+/// Implementation of `FeroxTechnologies`
+impl FeroxTechnologies {
+    /// Record the given technology as detected on `host`, merging it with what's already known
+    pub fn add(&self, host: &str, technology: Technology) {
+        self.insert(HostTechnologies::new(host, technology));
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     config::{Configuration, OutputLevel},
     event_handlers::Handles,
     response::FeroxResponse,
-    scanner::RESPONSES,
+    scanner::{PARAMETERS, RESPONSES, TECHNOLOGIES},
     statistics::Stats,
     traits::FeroxSerialize,
     SLEEP_DURATION, VERSION,
@@ -371,6 +371,8 @@ fn feroxstates_feroxserialize_implementation() {
         .unwrap()
         .insert(String::from("php"));
 
+    ferox_scans.add_case_decision("https://spiritanimal.com", true);
+
     let mut config = Configuration::new().unwrap();
 
     config.collect_extensions = true;
@@ -381,6 +383,18 @@ fn feroxstates_feroxserialize_implementation() {
     let response: FeroxResponse = serde_json::from_str(json_response).unwrap();
     RESPONSES.insert(response);
 
+    PARAMETERS.add(
+        "https://nerdcore.com/login",
+        "post",
+        ["username", "password"],
+        ParameterSource::Form,
+    );
+
+    TECHNOLOGIES.add(
+        "nerdcore.com",
+        Technology::new("nginx", Some("1.16.1"), Evidence::Header),
+    );
+
     let filters = FeroxFilters::default();
     filters
         .push(Box::new(StatusCodeFilter { filter_code: 100 }))
@@ -413,6 +427,8 @@ fn feroxstates_feroxserialize_implementation() {
         Arc::new(ferox_scans),
         Arc::new(config),
         &RESPONSES,
+        &PARAMETERS,
+        &TECHNOLOGIES,
         stats,
         Arc::new(filters),
     );
@@ -508,6 +524,9 @@ fn feroxstates_feroxserialize_implementation() {
         r#""collect_words":false"#,
         r#""filters":[{"filter_code":100},{"word_count":200},{"content_length":300},{"line_count":400},{"compiled":".*","raw_string":".*"},{"hash":1,"original_url":"http://localhost:12345/"}]"#,
         r#""collected_extensions":["php"]"#,
+        r#""case_insensitive":{"https://spiritanimal.com":true}"#,
+        r#""parameters":[{"type":"parameters","url":"https://nerdcore.com/login","method":"POST","parameters":["password","username"],"sources":["form"]}]"#,
+        r#""technologies":[{"type":"technologies","host":"nerdcore.com","technologies":[{"name":"nginx","versions":["1.16.1"],"evidence":["header"]}]}]"#,
         r#""dont_collect":["tif","tiff","ico","cur","bmp","webp","svg","png","jpg","jpeg","jfif","gif","avif","apng","pjpeg","pjp","mov","wav","mpg","mpeg","mp3","mp4","m4a","m4p","m4v","ogg","webm","ogv","oga","flac","aac","3gp","css","zip","xls","xml","gz","tgz"]"#,
     ]
     .iter()
@@ -780,3 +799,103 @@ fn get_base_scan_by_url_finds_correct_scan_with_trailing_slash() {
         scan.id
     );
 }
+
+#[test]
+/// parameters for the same endpoint are merged, while a different method is its own endpoint
+/// and endpoints without any parameters aren't recorded
+fn ferox_parameters_merges_by_url_and_method() {
+    let parameters = FeroxParameters::default();
+
+    parameters.add(
+        "http://localhost/search",
+        "get",
+        ["q"],
+        ParameterSource::Query,
+    );
+    parameters.add(
+        "http://localhost/search",
+        "GET",
+        ["q", "page"],
+        ParameterSource::JavaScript,
+    );
+    parameters.add(
+        "http://localhost/search",
+        "POST",
+        ["q"],
+        ParameterSource::Form,
+    );
+    parameters.add(
+        "http://localhost/empty",
+        "GET",
+        Vec::<String>::new(),
+        ParameterSource::Form,
+    );
+
+    let endpoints = parameters.snapshot();
+
+    assert_eq!(endpoints.len(), 2);
+    assert_eq!(endpoints[0].method, "GET");
+    assert_eq!(
+        endpoints[0].parameters.iter().collect::<Vec<_>>(),
+        vec!["page", "q"]
+    );
+    assert_eq!(
+        endpoints[0].sources.iter().collect::<Vec<_>>(),
+        vec![&ParameterSource::Query, &ParameterSource::JavaScript]
+    );
+    assert_eq!(endpoints[1].method, "POST");
+
+    let json = endpoints[1].as_json().unwrap();
+    assert_eq!(
+        json,
+        "{\"type\":\"parameters\",\"url\":\"http://localhost/search\",\"method\":\"POST\",\"parameters\":[\"q\"],\"sources\":[\"form\"]}\n"
+    );
+
+    let restored = FeroxParameters::default();
+    restored.insert(serde_json::from_str(&json).unwrap());
+    assert_eq!(restored.snapshot(), vec![endpoints[1].clone()]);
+}
+
+#[test]
+/// technologies detected on the same host are merged by name, keeping every version and piece
+/// of evidence seen
+fn ferox_technologies_merges_by_host_and_name() {
+    let technologies = FeroxTechnologies::default();
+
+    technologies.add(
+        "localhost",
+        Technology::new("nginx", Some("1.18.0"), Evidence::Header),
+    );
+    technologies.add("localhost", Technology::new("PHP", None, Evidence::Cookie));
+    technologies.add(
+        "localhost",
+        Technology::new("PHP", Some("7.4.3"), Evidence::Header),
+    );
+    technologies.add(
+        "localhost:8080",
+        Technology::new("nginx", None, Evidence::Header),
+    );
+
+    let hosts = technologies.snapshot();
+
+    assert_eq!(hosts.len(), 2);
+    assert_eq!(hosts[0].technologies.len(), 2);
+    assert_eq!(hosts[0].technologies[1].name, "PHP");
+    assert_eq!(
+        hosts[0].technologies[1].evidence.iter().collect::<Vec<_>>(),
+        vec![&Evidence::Header, &Evidence::Cookie]
+    );
+    assert!(hosts[0]
+        .as_str()
+        .contains("localhost [nginx 1.18.0, PHP 7.4.3]"));
+
+    let json = hosts[1].as_json().unwrap();
+    assert_eq!(
+        json,
+        "{\"type\":\"technologies\",\"host\":\"localhost:8080\",\"technologies\":[{\"name\":\"nginx\",\"versions\":[],\"evidence\":[\"header\"]}]}\n"
+    );
+
+    let restored = FeroxTechnologies::default();
+    restored.insert(serde_json::from_str(&json).unwrap());
+    assert_eq!(restored.snapshot(), vec![hosts[1].clone()]);
+}
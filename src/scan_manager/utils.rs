@@ -1,7 +1,10 @@
 #[cfg(not(test))]
 use crate::event_handlers::TermInputHandler;
 use crate::{
-    config::Configuration, event_handlers::Handles, parser::TIMESPEC_REGEX, scanner::RESPONSES,
+    config::Configuration,
+    event_handlers::Handles,
+    parser::TIMESPEC_REGEX,
+    scanner::{PARAMETERS, RESPONSES, TECHNOLOGIES},
 };
 
 use std::{fs::File, io::BufReader, sync::Arc};
@@ -87,6 +90,26 @@ pub fn resume_scan(filename: &str) -> Configuration {
         }
     }
 
+    if let Some(parameters) = state.get("parameters") {
+        if let Some(arr_parameters) = parameters.as_array() {
+            for endpoint in arr_parameters {
+                if let Ok(deser_endpoint) = serde_json::from_value(endpoint.clone()) {
+                    PARAMETERS.insert(deser_endpoint);
+                }
+            }
+        }
+    }
+
+    if let Some(technologies) = state.get("technologies") {
+        if let Some(arr_technologies) = technologies.as_array() {
+            for host in arr_technologies {
+                if let Ok(deser_host) = serde_json::from_value(host.clone()) {
+                    TECHNOLOGIES.insert(deser_host);
+                }
+            }
+        }
+    }
+
     log::trace!("exit: resume_scan -> {:?}", config);
     config
 }
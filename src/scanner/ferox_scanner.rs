@@ -1,12 +1,15 @@
+use std::collections::{hash_map::DefaultHasher, HashSet};
 use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::AtomicBool;
-use std::{ops::Deref, sync::atomic::Ordering, sync::Arc, time::Instant};
+use std::{sync::atomic::Ordering, sync::Arc, time::Instant};
 
 use anyhow::{bail, Result};
 use console::style;
-use futures::{stream, StreamExt};
+use futures::{future, stream, StreamExt};
 use indicatif::ProgressBar;
 use lazy_static::lazy_static;
+use reqwest::Url;
 use tokio::sync::Semaphore;
 
 use crate::filters::{create_similarity_filter, EmptyFilter, SimilarityFilter};
@@ -18,14 +21,18 @@ use crate::{
         Handles,
     },
     extractor::{ExtractionTarget, ExtractorBuilder},
-    heuristics,
-    scan_manager::{FeroxResponses, FeroxScans, MenuCmdResult, ScanOrder, ScanStatus, PAUSE_SCAN},
+    fingerprint, heuristics,
+    scan_manager::{
+        FeroxParameters, FeroxResponses, FeroxScans, FeroxTechnologies, MenuCmdResult, ScanOrder,
+        ScanStatus, PAUSE_SCAN,
+    },
     scanner::requester::TF_IDF,
     statistics::{
         StatError::Other,
         StatField::{DirScanTimes, TotalExpected},
     },
     utils::fmt_err,
+    wordlists::Wordlist,
     Command,
 };
 
@@ -35,6 +42,12 @@ lazy_static! {
     /// Vector of FeroxResponse objects
     pub static ref RESPONSES: FeroxResponses = FeroxResponses::default();
     // todo consider removing this
+
+    /// Parameter inventory built during link extraction
+    pub static ref PARAMETERS: FeroxParameters = FeroxParameters::default();
+
+    /// Technology inventory built by --fingerprint, keyed by host
+    pub static ref TECHNOLOGIES: FeroxTechnologies = FeroxTechnologies::default();
 }
 
 /// check to see if `pause_flag` is set to true. when true; enter a busy loop that only exits
@@ -117,8 +130,8 @@ pub struct FeroxScanner {
     /// found via recursion
     order: ScanOrder,
 
-    /// wordlist that's already been read from disk
-    wordlist: Arc<Vec<String>>,
+    /// wordlist that's streamed from disk (or memory) for each scan
+    wordlist: Wordlist,
 
     /// limiter that restricts the number of active FeroxScanners
     scan_limiter: Arc<Semaphore>,
@@ -130,7 +143,7 @@ impl FeroxScanner {
     pub fn new(
         target_url: &str,
         order: ScanOrder,
-        wordlist: Arc<Vec<String>>,
+        wordlist: Wordlist,
         scan_limiter: Arc<Semaphore>,
         handles: Arc<Handles>,
     ) -> Self {
@@ -146,15 +159,89 @@ impl FeroxScanner {
     /// produces and awaits tasks (mp of mpsc); responsible for making requests
     async fn stream_requests(
         &self,
-        looping_words: Arc<Vec<String>>,
+        looping_words: Wordlist,
         progress_bar: ProgressBar,
         scanned_urls: Arc<FeroxScans>,
         requester: Arc<Requester>,
     ) {
         log::trace!("enter: stream_requests(params too verbose to print)");
 
-        let producers = stream::iter(looping_words.deref().to_owned())
-            .map(|word| {
+        let handles = self.handles.clone();
+        let multiplier = self.handles.expected_num_requests_multiplier() as u64;
+
+        // each word is expanded into its --rules candidates only as the stream reaches it, so the
+        // full set of candidates never needs to be held in memory
+        let words = match looping_words.iter() {
+            Ok(words) => words,
+            Err(e) => {
+                log::warn!("Could not read wordlist: {}", e);
+                self.handles.stats.send(AddError(Other)).unwrap_or_default();
+                return;
+            }
+        };
+
+        // with --detect-case, the candidates of this directory are remembered as hashes of their
+        // lowercase form, so that once the target is known to be case-insensitive, the ones that
+        // only differ by case are skipped; the set is dropped along with the stream once the
+        // directory's scan finishes
+        let detect_case = self.handles.config.detect_case;
+        let origin = Url::parse(&self.target_url)
+            .map(|url| url.origin().ascii_serialization())
+            .unwrap_or_default();
+        let mut seen_words: HashSet<u64> = HashSet::new();
+        let skip_scans = scanned_urls.clone();
+        let skip_bar = progress_bar.clone();
+        let skip_handles = self.handles.clone();
+
+        let producers = stream::iter(words)
+            .flat_map(move |word| {
+                // the empty word is only there to request the base url; it's requested once,
+                // but accounts for all of its would-be candidates on the progress bar
+                let base_url = word.is_empty().then(|| {
+                    let increment =
+                        multiplier.saturating_mul(handles.config.rules.num_candidates() as u64);
+                    (String::new(), increment)
+                });
+
+                let candidates = (!word.is_empty())
+                    .then(|| handles.config.rules.expand(&word))
+                    .into_iter()
+                    .flatten()
+                    .map(move |candidate| (candidate, multiplier));
+
+                stream::iter(base_url.into_iter().chain(candidates))
+            })
+            .filter(move |(word, increment_len)| {
+                let keep = !detect_case || word.is_empty() || {
+                    let mut hasher = DefaultHasher::new();
+                    word.to_lowercase().hash(&mut hasher);
+
+                    // recorded before a decision is made too, so that the words requested while
+                    // the heuristic runs aren't requested again in another case afterwards
+                    seen_words.insert(hasher.finish())
+                        || skip_scans.is_case_insensitive(&origin) != Some(true)
+                };
+
+                if !keep {
+                    // skipped candidates no longer count towards the totals
+                    skip_bar.set_length(
+                        skip_bar
+                            .length()
+                            .unwrap_or(0)
+                            .saturating_sub(*increment_len),
+                    );
+                    skip_handles
+                        .stats
+                        .send(SubtractFromUsizeField(
+                            TotalExpected,
+                            *increment_len as usize,
+                        ))
+                        .unwrap_or_default();
+                }
+
+                future::ready(keep)
+            })
+            .map(|(word, increment_len)| {
                 let pb = progress_bar.clone(); // progress bar is an Arc around internal state
                 let scanned_urls_clone = scanned_urls.clone();
                 let requester_clone = requester.clone();
@@ -172,21 +259,24 @@ impl FeroxScanner {
                             .unwrap_or_else(|e| log::warn!("Requester encountered an error: {}", e))
                     }),
                     pb,
+                    increment_len,
                 )
             })
-            .for_each_concurrent(self.handles.config.threads, |(resp, bar)| async move {
-                match resp.await {
-                    Ok(_) => {
-                        let increment_len = self.handles.expected_num_requests_multiplier() as u64;
-                        bar.inc(increment_len);
-                    }
-                    Err(e) => {
-                        log::warn!("error awaiting a response: {}", e);
-                        self.handles.stats.send(AddError(Other)).unwrap_or_default();
-                        std::process::exit(1);
+            .for_each_concurrent(
+                self.handles.config.threads,
+                |(resp, bar, increment_len)| async move {
+                    match resp.await {
+                        Ok(_) => {
+                            bar.inc(increment_len);
+                        }
+                        Err(e) => {
+                            log::warn!("error awaiting a response: {}", e);
+                            self.handles.stats.send(AddError(Other)).unwrap_or_default();
+                            std::process::exit(1);
+                        }
                     }
-                }
-            });
+                },
+            );
 
         // await tx tasks
         log::trace!("awaiting scan producers");
@@ -208,7 +298,8 @@ impl FeroxScanner {
         let mut extraction_tasks = Vec::new();
 
         if self.handles.config.extract_links && matches!(self.order, ScanOrder::Initial) {
-            // check for robots.txt (cannot be in sub-directories, so limited to Initial)
+            // check for robots.txt and the sitemaps it points to (cannot be in sub-directories,
+            // so limited to Initial)
             let mut extractor = ExtractorBuilder::default()
                 .target(ExtractionTarget::RobotsTxt)
                 .url(&self.target_url)
@@ -216,7 +307,35 @@ impl FeroxScanner {
                 .build()?;
 
             let result = extractor.extract().await?;
-            extraction_tasks.push(extractor.request_links(result).await?)
+            extraction_tasks.push(extractor.request_links(result).await?);
+        }
+
+        if self.handles.config.discover && matches!(self.order, ScanOrder::Initial) {
+            // check the curated set of /.well-known/ resources, which also only live at the root
+            let mut extractor = ExtractorBuilder::default()
+                .target(ExtractionTarget::WellKnown)
+                .url(&self.target_url)
+                .handles(self.handles.clone())
+                .build()?;
+
+            let result = extractor.extract().await?;
+            extraction_tasks.push(extractor.request_links(result).await?);
+        }
+
+        if (self.handles.config.discover || !self.handles.config.openapi.is_empty())
+            && matches!(self.order, ScanOrder::Initial)
+        {
+            // look for an api specification (or use the one given via --openapi); endpoints
+            // declaring methods other than GET are requested separately from the sub-paths
+            let mut extractor = ExtractorBuilder::default()
+                .target(ExtractionTarget::OpenApi)
+                .url(&self.target_url)
+                .handles(self.handles.clone())
+                .build()?;
+
+            let (links, endpoints) = extractor.extract_from_openapi().await?;
+            extraction_tasks.push(extractor.request_links(links).await?);
+            extraction_tasks.push(extractor.request_endpoints(endpoints).await?);
         }
 
         let scanned_urls = self.handles.ferox_scans()?;
@@ -251,7 +370,45 @@ impl FeroxScanner {
             // heuristics test block:
             let test = heuristics::HeuristicTests::new(self.handles.clone());
 
-            if let Ok(Some(dirlist_result)) = test.directory_listing(&self.target_url).await {
+            if self.handles.config.auto_extensions
+                && !self.handles.config.vhost
+                && matches!(self.order, ScanOrder::Initial)
+            {
+                // extensions are picked from the initial targets only, before their wordlists
+                // are streamed, so that every word is requested with them
+                if let Err(e) = test.auto_extensions(&self.target_url).await {
+                    log::warn!("Could not pick extensions for {}: {}", self.target_url, e);
+                }
+            }
+
+            if self.handles.config.fingerprint
+                && !self.handles.config.vhost
+                && matches!(self.order, ScanOrder::Initial)
+            {
+                // favicons can't be matched as part of the scan, since bodies are kept as text
+                if let Err(e) =
+                    fingerprint::fingerprint_favicon(&self.target_url, self.handles.clone()).await
+                {
+                    log::warn!(
+                        "Could not fingerprint favicon of {}: {}",
+                        self.target_url,
+                        e
+                    );
+                }
+            }
+
+            // in --vhost mode, only the Host header changes between requests; there's no
+            // directory to list and the default virtual host takes the place of a 404
+            let dirlist = if self.handles.config.vhost {
+                None
+            } else {
+                test.directory_listing(&self.target_url)
+                    .await
+                    .ok()
+                    .flatten()
+            };
+
+            if let Some(dirlist_result) = dirlist {
                 // at this point, we have a DirListingType, and it's not the None variant
                 // which means we found directory listing based on the heuristic; now we need
                 // to process the links that are available if --extract-links was used
@@ -312,7 +469,12 @@ impl FeroxScanner {
             // `detect_404_like_responses` will make the requests that the wildcard test used to
             // perform pre-2.8 in addition to new detection techniques, superseding the old
             // wildcard test
-            let num_reqs_made = test.detect_404_like_responses(&self.target_url).await?;
+            let num_reqs_made = if self.handles.config.vhost {
+                test.detect_vhost_baseline(&self.target_url).await?;
+                None
+            } else {
+                test.detect_404_like_responses(&self.target_url).await?
+            };
 
             match num_reqs_made {
                 Some(WildcardResult::WildcardDirectory(num_reqs)) => {
@@ -365,7 +527,7 @@ impl FeroxScanner {
             );
 
             self.stream_requests(
-                Arc::new(new_words),
+                Wordlist::from(new_words),
                 progress_bar.clone(),
                 scanned_urls.clone(),
                 requester.clone(),
@@ -373,6 +535,9 @@ impl FeroxScanner {
             .await;
         }
 
+        requester.discover_queued_methods().await;
+        requester.mine_queued_parameters().await;
+
         self.handles.stats.send(AddToF64Field(
             DirScanTimes,
             scan_timer.elapsed().as_secs_f64(),
@@ -4,9 +4,11 @@ mod init;
 #[cfg(test)]
 mod tests;
 mod limit_heap;
+mod param_miner;
 mod policy_data;
 mod requester;
 
-pub use self::ferox_scanner::{FeroxScanner, RESPONSES};
+pub use self::ferox_scanner::{FeroxScanner, PARAMETERS, RESPONSES, TECHNOLOGIES};
 pub use self::init::initialize;
+pub use self::param_miner::set_param_words;
 pub use self::utils::PolicyTrigger;
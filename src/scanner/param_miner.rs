@@ -0,0 +1,147 @@
+//! building blocks for hidden query parameter discovery (--param-mine); the requests themselves
+//! are made by the `Requester`, so that they're subject to its rate limiting and auto-tuning
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use lazy_static::lazy_static;
+use reqwest::Url;
+use uuid::Uuid;
+
+use crate::{
+    filters::{SimilarityFilter, WildcardFilter, SIM_HASHER},
+    nlp::preprocess,
+    response::FeroxResponse,
+    traits::FeroxFilter,
+    DEFAULT_IGNORED_EXTENSIONS,
+};
+
+/// number of parameter names sent in a single request
+pub(super) const PARAM_BATCH_SIZE: usize = 32;
+
+/// extensions of static files that are never mined, on top of `DEFAULT_IGNORED_EXTENSIONS`
+const STATIC_EXTENSIONS: [&str; 9] = [
+    "js", "mjs", "map", "woff", "woff2", "ttf", "otf", "eot", "pdf",
+];
+
+/// parameter names read from the --param-mine wordlist
+static PARAM_WORDS: OnceLock<Vec<String>> = OnceLock::new();
+
+lazy_static! {
+    /// endpoints (urls without a query string) that were already queued for mining, across all
+    /// scans
+    static ref MINED_ENDPOINTS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// set the parameter names that will be brute forced; only the first call has any effect
+pub fn set_param_words(words: Vec<String>) {
+    let _ = PARAM_WORDS.set(words);
+}
+
+/// parameter names to brute force, empty when --param-mine wasn't used
+pub(super) fn param_words() -> &'static [String] {
+    PARAM_WORDS.get().map_or(&[], Vec::as_slice)
+}
+
+/// the endpoint that should be mined for the given found url, if any
+///
+/// the endpoint is the url without its query string or fragment; it's only returned the first
+/// time it's seen, and never for static files (images, stylesheets, scripts, ...)
+pub(super) fn endpoint_to_mine(url: &Url) -> Option<Url> {
+    let mut endpoint = url.clone();
+    endpoint.set_query(None);
+    endpoint.set_fragment(None);
+
+    let extension = endpoint
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase());
+
+    if let Some(extension) = extension {
+        if DEFAULT_IGNORED_EXTENSIONS.contains(&extension.as_str())
+            || STATIC_EXTENSIONS.contains(&extension.as_str())
+        {
+            return None;
+        }
+    }
+
+    let mut mined = MINED_ENDPOINTS.lock().ok()?;
+
+    mined.insert(endpoint.to_string()).then_some(endpoint)
+}
+
+/// a batch of random parameter names, used to build and check the baseline
+pub(super) fn random_names() -> Vec<String> {
+    (0..PARAM_BATCH_SIZE)
+        .map(|_| Uuid::new_v4().as_simple().to_string()[..12].to_string())
+        .collect()
+}
+
+/// the given url with each of the given names appended to its query string
+///
+/// every parameter gets the same (random) value, which makes reflected parameters easy to
+/// spot in the response
+pub(super) fn with_parameters(url: &Url, names: &[String]) -> Url {
+    let value = Uuid::new_v4().as_simple().to_string()[..8].to_string();
+
+    let mut probe = url.clone();
+
+    {
+        let mut pairs = probe.query_pairs_mut();
+
+        for name in names {
+            pairs.append_pair(name, &value);
+        }
+    }
+
+    probe
+}
+
+/// an endpoint's response to a batch of random parameter names, against which the responses to
+/// batches of candidate names are compared
+pub(super) struct Baseline {
+    /// size/words/lines/status of the baseline, the same signals used to detect wildcards
+    wildcard: WildcardFilter,
+
+    /// simhash of the baseline's body
+    similarity: SimilarityFilter,
+}
+
+/// implementation of Baseline
+impl Baseline {
+    /// create a Baseline from the given response
+    pub(super) fn new(response: &FeroxResponse) -> Self {
+        let wildcard = WildcardFilter {
+            content_length: Some(response.content_length()),
+            line_count: Some(response.line_count()),
+            word_count: Some(response.word_count()),
+            method: response.method().to_string(),
+            status_code: response.status().as_u16(),
+            dont_filter: false,
+        };
+
+        let similarity = SimilarityFilter {
+            hash: SIM_HASHER.create_signature(preprocess(response.text()).iter()),
+            original_url: response.url().to_string(),
+        };
+
+        Self {
+            wildcard,
+            similarity,
+        }
+    }
+
+    /// whether the given response differs from the baseline
+    ///
+    /// a response is unchanged when it looks like the baseline according to the wildcard
+    /// signals, or when it has the same status and a body that's near-identical to the
+    /// baseline's
+    pub(super) fn is_changed(&self, response: &FeroxResponse) -> bool {
+        if self.wildcard.should_filter_response(response) {
+            return false;
+        }
+
+        response.status().as_u16() != self.wildcard.status_code
+            || !self.similarity.should_filter_response(response)
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     cmp::max,
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     sync::{
         self,
         atomic::{AtomicBool, Ordering},
@@ -10,8 +10,10 @@ use std::{
 
 use anyhow::Result;
 use console::style;
+use futures::{stream, StreamExt};
 use lazy_static::lazy_static;
 use leaky_bucket::RateLimiter;
+use reqwest::{Response, Url};
 use tokio::{
     sync::RwLock,
     time::{sleep, Duration},
@@ -19,22 +21,42 @@ use tokio::{
 
 use crate::{
     atomic_load, atomic_store,
-    config::RequesterPolicy,
+    config::{OutputLevel, RequesterPolicy},
     event_handlers::{
-        Command::{AddError, SubtractFromUsizeField},
+        Command::{AddError, AddToUsizeField, SubtractFromUsizeField},
         Handles,
     },
-    extractor::{ExtractionTarget, ExtractorBuilder},
+    extractor::{is_javascript, ExtractionTarget, ExtractorBuilder},
+    fingerprint::fingerprint,
+    heuristics::HeuristicTests,
     nlp::{Document, TfIdf},
-    response::FeroxResponse,
-    scan_manager::{FeroxScan, ScanStatus},
+    progress::PROGRESS_PRINTER,
+    response::{DiscoveredMethods, FeroxResponse, MethodProbe},
+    scan_manager::{EndpointParameters, FeroxScan, ParameterSource, ScanStatus},
     statistics::{StatError::Other, StatField::TotalExpected},
+    traits::FeroxSerialize,
     url::FeroxUrl,
-    utils::{logged_request, send_try_recursion_command, should_deny_url},
-    HIGH_ERROR_RATIO,
+    utils::{
+        ferox_print, fuzz_data, fuzz_headers, logged_request, send_try_recursion_command,
+        should_deny_url,
+    },
+    DEFAULT_METHOD, HIGH_ERROR_RATIO,
+};
+
+use super::{
+    param_miner::{
+        endpoint_to_mine, param_words, random_names, with_parameters, Baseline, PARAM_BATCH_SIZE,
+    },
+    policy_data::PolicyData,
+    FeroxScanner, PolicyTrigger, PARAMETERS,
 };
 
-use super::{policy_data::PolicyData, FeroxScanner, PolicyTrigger};
+/// headers that frameworks commonly honor to let a POST stand in for another method
+const METHOD_OVERRIDE_HEADERS: [&str; 3] = [
+    "X-HTTP-Method-Override",
+    "X-HTTP-Method",
+    "X-Method-Override",
+];
 
 lazy_static! {
     /// make sure to note that this is a std rwlock and not tokio
@@ -71,6 +93,14 @@ pub(super) struct Requester {
     tuning_lock: Mutex<usize>,
 
     policy_triggered: AtomicBool,
+
+    /// endpoints found by this requester that still need to be mined for parameters
+    /// (--param-mine); they're mined once the scan's words have all been requested
+    mining_queue: Mutex<Vec<Url>>,
+
+    /// findings that still need their methods discovered (--discover-methods); they're probed,
+    /// and then reported, once the scan's words have all been requested
+    method_queue: Mutex<Vec<FeroxResponse>>,
 }
 
 /// Requester implementation
@@ -99,6 +129,8 @@ impl Requester {
             target_url: scanner.target_url.to_owned(),
             tuning_lock: Mutex::new(0),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         })
     }
 
@@ -342,37 +374,431 @@ impl Requester {
         Ok(())
     }
 
+    /// Wrapper for logged_request that applies the rate limiter before the request is made and
+    /// enforces the auto-tune/auto-bail policies after it comes back
+    async fn throttled_request(
+        &self,
+        url: &Url,
+        method: &str,
+        data: Option<&[u8]>,
+        headers: Option<&HashMap<String, String>>,
+    ) -> Result<Response> {
+        // auto_tune is true, or rate_limit was set (mutually exclusive to user)
+        // and a rate_limiter has been created
+        // short-circuiting the lock access behind the first boolean check
+        let should_tune = self.handles.config.auto_tune || self.handles.config.rate_limit > 0;
+        let should_limit = should_tune && self.rate_limiter.read().await.is_some();
+
+        if should_limit {
+            // found a rate limiter, limit that junk!
+            if let Err(e) = self.limit().await {
+                log::warn!("Could not rate limit scan: {}", e);
+                self.handles.stats.send(AddError(Other)).unwrap_or_default();
+            }
+        }
+
+        let response = logged_request(url, method, data, headers, self.handles.clone()).await?;
+
+        if (should_tune || self.handles.config.auto_bail)
+            && !atomic_load!(self.policy_data.cooling_down, Ordering::SeqCst)
+        {
+            // only check for policy enforcement when the trigger isn't on cooldown and tuning
+            // or bailing is in place (should_tune used here because when auto-tune is on, we'll
+            // reach this without a rate_limiter in place)
+            match self.policy_data.policy {
+                RequesterPolicy::AutoTune => {
+                    if let Some(trigger) = self.should_enforce_policy() {
+                        self.tune(trigger).await?;
+                    } else if atomic_load!(self.policy_triggered) {
+                        self.adjust_limit(PolicyTrigger::TryAdjustUp, true).await?;
+                        self.cool_down().await;
+                    }
+                }
+                RequesterPolicy::AutoBail => {
+                    if let Some(trigger) = self.should_enforce_policy() {
+                        self.bail(trigger).await?;
+                    }
+                }
+                RequesterPolicy::Default => {}
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// request the given url with the given parameter names added to its query string
+    async fn probe_parameters(&self, url: &Url, names: &[String]) -> Result<FeroxResponse> {
+        let probe = with_parameters(url, names);
+
+        let response = self
+            .throttled_request(&probe, DEFAULT_METHOD, None, None)
+            .await?;
+
+        Ok(FeroxResponse::from(
+            response,
+            &self.target_url,
+            DEFAULT_METHOD,
+            self.handles.config.output_level,
+        )
+        .await)
+    }
+
+    /// brute force the names of query parameters accepted by the given url (--param-mine)
+    ///
+    /// names are sent in batches; whenever a batch changes the response (compared to a baseline
+    /// made of random names), it's split in half and each half is tried again until the
+    /// individual parameters responsible are found
+    async fn mine_parameters(&self, url: &Url) -> Result<()> {
+        log::trace!("enter: mine_parameters({})", url);
+
+        let words = param_words();
+
+        if words.is_empty() {
+            log::trace!("exit: mine_parameters -> no words");
+            return Ok(());
+        }
+
+        // two random batches for the baseline and stability check, one request per batch of words
+        self.handles.stats.send(AddToUsizeField(
+            TotalExpected,
+            2 + words.len().div_ceil(PARAM_BATCH_SIZE),
+        ))?;
+
+        let baseline = Baseline::new(&self.probe_parameters(url, &random_names()).await?);
+
+        if baseline.is_changed(&self.probe_parameters(url, &random_names()).await?) {
+            // the page changes on its own (or reflects parameter names), so nothing can be
+            // attributed to a specific parameter
+            log::warn!("{} is too unstable to mine for parameters", url);
+            log::trace!("exit: mine_parameters -> unstable");
+            return Ok(());
+        }
+
+        let mut confirmed = Vec::new();
+        let mut batches: Vec<&[String]> = words.chunks(PARAM_BATCH_SIZE).rev().collect();
+
+        while let Some(batch) = batches.pop() {
+            if !self.ferox_scan.is_active() {
+                // cancelled/bailed scans shouldn't keep sending requests
+                break;
+            }
+
+            let response = match self.probe_parameters(url, batch).await {
+                Ok(response) => response,
+                Err(e) => {
+                    log::warn!("Could not mine parameters from {}: {}", url, e);
+                    continue;
+                }
+            };
+
+            if !baseline.is_changed(&response) {
+                continue;
+            }
+
+            if batch.len() == 1 {
+                confirmed.push(batch[0].clone());
+                continue;
+            }
+
+            let (left, right) = batch.split_at(batch.len() / 2);
+
+            self.handles.stats.send(AddToUsizeField(TotalExpected, 2))?;
+
+            batches.push(right);
+            batches.push(left);
+        }
+
+        if !confirmed.is_empty() {
+            let found = EndpointParameters::new(
+                url.as_str(),
+                DEFAULT_METHOD,
+                confirmed,
+                ParameterSource::Mined,
+            );
+
+            if matches!(
+                self.handles.config.output_level,
+                OutputLevel::Default | OutputLevel::Quiet
+            ) {
+                ferox_print(&found.as_str(), &PROGRESS_PRINTER);
+            }
+
+            PARAMETERS.insert(found);
+        }
+
+        log::trace!("exit: mine_parameters");
+        Ok(())
+    }
+
+    /// mine every endpoint queued by `request` for parameters (--param-mine), each in its own
+    /// task, at most --threads at a time
+    pub(super) async fn mine_queued_parameters(self: &Arc<Self>) {
+        log::trace!("enter: mine_queued_parameters");
+
+        let queued = match self.mining_queue.lock() {
+            Ok(mut queue) => std::mem::take(&mut *queue),
+            Err(_) => Vec::new(),
+        };
+
+        stream::iter(queued)
+            .for_each_concurrent(self.handles.config.threads, |url| {
+                let requester = self.clone();
+
+                async move {
+                    let task = tokio::spawn(async move {
+                        if let Err(e) = requester.mine_parameters(&url).await {
+                            log::warn!("Could not mine parameters from {}: {}", url, e);
+                        }
+                    });
+
+                    if let Err(e) = task.await {
+                        log::warn!("error awaiting parameter mining: {}", e);
+                    }
+                }
+            })
+            .await;
+
+        log::trace!("exit: mine_queued_parameters");
+    }
+
+    /// internal helper to stay DRY; request the given response's url with the given method and
+    /// headers through the rate limiter, logging any error
+    async fn probe_method(
+        &self,
+        response: &FeroxResponse,
+        method: &str,
+        headers: &HashMap<String, String>,
+    ) -> Option<Response> {
+        self.throttled_request(response.url(), method, None, Some(headers))
+            .await
+            .map_err(|e| log::warn!("Could not probe {} with {}: {}", response.url(), method, e))
+            .ok()
+    }
+
+    /// given a `FeroxResponse`, request its url with OPTIONS (reading the Allow header), then
+    /// with each of the configured probe methods, and finally with a POST carrying each probe
+    /// method in the method-override headers
+    ///
+    /// override probes are only kept when their status code differs from the ones received by
+    /// the method when sent directly and by a plain POST, as that's what shows the server
+    /// honored the header
+    async fn discover_methods(&self, response: &FeroxResponse) -> DiscoveredMethods {
+        log::trace!("enter: discover_methods({})", response);
+
+        let mut discovered = DiscoveredMethods::default();
+
+        // need to manually adjust stats
+        let num_probes =
+            2 + self.handles.config.probe_methods.len() * (1 + METHOD_OVERRIDE_HEADERS.len());
+        self.handles
+            .stats
+            .send(AddToUsizeField(TotalExpected, num_probes))
+            .unwrap_or_default();
+
+        // in --vhost mode, probes are sent to the same virtual host as the finding
+        let base_headers: HashMap<String, String> = response
+            .vhost()
+            .map(|host| (String::from("Host"), host.to_string()))
+            .into_iter()
+            .collect();
+
+        if let Some(options) = self.probe_method(response, "OPTIONS", &base_headers).await {
+            discovered.allow = options
+                .headers()
+                .get_all("Allow")
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(|method| method.trim().to_uppercase())
+                .filter(|method| !method.is_empty())
+                .collect();
+
+            discovered.probes.push(MethodProbe {
+                method: String::from("OPTIONS"),
+                status: options.status().as_u16(),
+                override_header: None,
+            });
+        }
+
+        let mut direct = HashMap::new();
+
+        for method in &self.handles.config.probe_methods {
+            if let Some(probed) = self.probe_method(response, method, &base_headers).await {
+                direct.insert(method, probed.status().as_u16());
+
+                discovered.probes.push(MethodProbe {
+                    method: method.to_owned(),
+                    status: probed.status().as_u16(),
+                    override_header: None,
+                });
+            }
+        }
+
+        // a POST without an override header, used to tell whether an override probe did anything
+        // more than reveal how the server handles POST
+        let post_status = self
+            .probe_method(response, "POST", &base_headers)
+            .await
+            .map(|probed| probed.status().as_u16());
+
+        for method in &self.handles.config.probe_methods {
+            for header in METHOD_OVERRIDE_HEADERS {
+                let mut headers = base_headers.clone();
+                headers.insert(header.to_string(), method.to_owned());
+
+                let Some(probed) = self.probe_method(response, "POST", &headers).await else {
+                    continue;
+                };
+
+                let status = probed.status().as_u16();
+
+                if direct.get(method) != Some(&status) && post_status != Some(status) {
+                    discovered.probes.push(MethodProbe {
+                        method: method.to_owned(),
+                        status,
+                        override_header: Some(header.to_string()),
+                    });
+                }
+            }
+        }
+
+        log::trace!("exit: discover_methods -> {:?}", discovered);
+        discovered
+    }
+
+    /// discover the methods of every finding queued by `request` (--discover-methods), each in
+    /// its own task, at most --threads at a time; each finding is reported once its probes are
+    /// done
+    pub(super) async fn discover_queued_methods(self: &Arc<Self>) {
+        log::trace!("enter: discover_queued_methods");
+
+        let queued = match self.method_queue.lock() {
+            Ok(mut queue) => std::mem::take(&mut *queue),
+            Err(_) => Vec::new(),
+        };
+
+        stream::iter(queued)
+            .for_each_concurrent(self.handles.config.threads, |mut response| {
+                let requester = self.clone();
+
+                async move {
+                    let task = tokio::spawn(async move {
+                        if requester.ferox_scan.is_active() {
+                            // cancelled/bailed scans still report their findings, unprobed
+                            let methods = requester.discover_methods(&response).await;
+                            response.set_methods(methods);
+                        }
+
+                        if let Err(e) = response.send_report(
+                            requester.handles.output.tx.clone(),
+                            requester.handles.config.keep_response_bodies(),
+                        ) {
+                            log::warn!("Could not send FeroxResponse to output handler: {}", e);
+                        }
+                    });
+
+                    if let Err(e) = task.await {
+                        log::warn!("error awaiting method discovery: {}", e);
+                    }
+                }
+            })
+            .await;
+
+        log::trace!("exit: discover_queued_methods");
+    }
+
+    /// run the case-sensitivity heuristic against the given found response, unless a decision was
+    /// already made for the target it came from, or the heuristic is already running for it
+    async fn detect_case(&self, response: &FeroxResponse) -> Result<()> {
+        let scans = self.handles.ferox_scans()?;
+        let origin = response.url().origin().ascii_serialization();
+
+        if scans.is_case_insensitive(&origin).is_some() || !scans.start_case_detection(&origin) {
+            return Ok(());
+        }
+
+        let test = HeuristicTests::new(self.handles.clone());
+        let result = test.case_insensitivity(response).await;
+
+        // released before the decision is checked, so that a resource without anything to swap
+        // lets the next one found be tried instead
+        scans.finish_case_detection(&origin);
+
+        let Some(insensitive) = result? else {
+            return Ok(());
+        };
+
+        if scans.add_case_decision(&origin, insensitive) && insensitive {
+            let message = format!("=> {}", style("Case-insensitive").blue().bright());
+            self.ferox_scan.progress_bar().set_message(message);
+        }
+
+        Ok(())
+    }
+
     /// Wrapper for make_request
     ///
     /// Attempts recursion when appropriate and sends Responses to the output handler for processing
     pub async fn request(&self, word: &str) -> Result<()> {
         log::trace!("enter: request({})", word);
 
-        let collected = self.handles.collected_extensions();
+        let fuzzing = self.handles.config.uses_fuzz_keyword();
+
+        // words replace a keyword in the target url instead of being appended to it, so recursing
+        // would replay the same requests; -u's keyword already forces --no-recursion, targets
+        // from --stdin or --targets-from are checked here
+        let recursion = !self.handles.config.no_recursion
+            && !self.handles.config.is_keyword_target(&self.target_url);
+
+        if (fuzzing || self.handles.config.vhost) && word.is_empty() {
+            // the empty word exists to request the base url, which doesn't apply when words are
+            // placed at the FUZZ keyword or sent as the Host header
+            log::trace!("exit: request -> skipped empty word in keyword/vhost mode");
+            return Ok(());
+        }
+
+        // in --vhost mode, the word is sent as the Host header to the unchanged target url
+        let vhost = self
+            .handles
+            .config
+            .vhost
+            .then(|| self.handles.config.vhost_for(word));
 
-        let urls = FeroxUrl::from_string(&self.target_url, self.handles.clone())
-            .formatted_urls(word, collected)?;
+        let urls = if vhost.is_some() {
+            vec![FeroxUrl::from_string(&self.target_url, self.handles.clone()).format("", None)?]
+        } else {
+            let collected = self.handles.collected_extensions();
+
+            FeroxUrl::from_string(&self.target_url, self.handles.clone())
+                .formatted_urls(word, collected)?
+        };
 
         let should_test_deny = !self.handles.config.url_denylist.is_empty()
             || !self.handles.config.regex_denylist.is_empty();
 
+        // substitute the word into the body and any header values that contain the FUZZ
+        // keyword; the url and queries were already taken care of by FeroxUrl
+        let (fuzzed_data, fuzzed_headers) = if fuzzing {
+            (
+                fuzz_data(word, &self.handles.config),
+                fuzz_headers(word, &self.handles.config),
+            )
+        } else {
+            (None, None)
+        };
+
+        // the Host header replaces the client's default for this request only
+        let fuzzed_headers = match &vhost {
+            Some(host) => {
+                let mut headers = fuzzed_headers.unwrap_or_default();
+                headers.insert(String::from("Host"), host.to_owned());
+                Some(headers)
+            }
+            None => fuzzed_headers,
+        };
+
         for url in urls {
             for method in self.handles.config.methods.iter() {
-                // auto_tune is true, or rate_limit was set (mutually exclusive to user)
-                // and a rate_limiter has been created
-                // short-circuiting the lock access behind the first boolean check
-                let should_tune =
-                    self.handles.config.auto_tune || self.handles.config.rate_limit > 0;
-                let should_limit = should_tune && self.rate_limiter.read().await.is_some();
-
-                if should_limit {
-                    // found a rate limiter, limit that junk!
-                    if let Err(e) = self.limit().await {
-                        log::warn!("Could not rate limit scan: {}", e);
-                        self.handles.stats.send(AddError(Other)).unwrap_or_default();
-                    }
-                }
-
                 if should_test_deny && should_deny_url(&url, self.handles.clone())? {
                     // can't allow a denied url to be requested
                     continue;
@@ -384,32 +810,14 @@ impl Requester {
                     Some(self.handles.config.data.as_slice())
                 };
 
-                let response =
-                    logged_request(&url, method.as_str(), data, self.handles.clone()).await?;
-
-                if (should_tune || self.handles.config.auto_bail)
-                    && !atomic_load!(self.policy_data.cooling_down, Ordering::SeqCst)
-                {
-                    // only check for policy enforcement when the trigger isn't on cooldown and tuning
-                    // or bailing is in place (should_tune used here because when auto-tune is on, we'll
-                    // reach this without a rate_limiter in place)
-                    match self.policy_data.policy {
-                        RequesterPolicy::AutoTune => {
-                            if let Some(trigger) = self.should_enforce_policy() {
-                                self.tune(trigger).await?;
-                            } else if atomic_load!(self.policy_triggered) {
-                                self.adjust_limit(PolicyTrigger::TryAdjustUp, true).await?;
-                                self.cool_down().await;
-                            }
-                        }
-                        RequesterPolicy::AutoBail => {
-                            if let Some(trigger) = self.should_enforce_policy() {
-                                self.bail(trigger).await?;
-                            }
-                        }
-                        RequesterPolicy::Default => {}
-                    }
-                }
+                let response = self
+                    .throttled_request(
+                        &url,
+                        method.as_str(),
+                        fuzzed_data.as_deref().or(data),
+                        fuzzed_headers.as_ref(),
+                    )
+                    .await?;
 
                 // response came back without error, convert it to FeroxResponse
                 let mut ferox_response = FeroxResponse::from(
@@ -420,8 +828,28 @@ impl Requester {
                 )
                 .await;
 
+                if let Some(host) = &vhost {
+                    ferox_response.set_vhost(host);
+                }
+
+                ferox_response
+                    .set_sent_request(fuzzed_headers.as_ref(), fuzzed_data.as_deref().or(data));
+
+                if self.handles.config.warc_all {
+                    // --warc-all was used; every exchange is archived, filtered or not, and
+                    // reported findings aren't archived a second time by the file handler
+                    if let Err(e) = self
+                        .handles
+                        .output
+                        .archive(&ferox_response, &self.handles.config)
+                        .await
+                    {
+                        log::warn!("Could not send {} to the file handler: {}", url, e);
+                    }
+                }
+
                 // do recursion if appropriate
-                if !self.handles.config.no_recursion && !self.handles.config.force_recursion {
+                if recursion && !self.handles.config.force_recursion {
                     // to support --force-recursion, we want to limit recursive calls to only
                     // 'found' assets. That means we need to either gate or delay the call.
                     //
@@ -442,7 +870,13 @@ impl Requester {
                     continue;
                 }
 
-                if !self.handles.config.no_recursion && self.handles.config.force_recursion {
+                if self.handles.config.fingerprint {
+                    // fingerprinting happens after filtering so that, in --vhost mode, the
+                    // default virtual host's response isn't attributed to every word
+                    fingerprint(&ferox_response);
+                }
+
+                if recursion && self.handles.config.force_recursion {
                     // in this branch, we're saying that both recursion AND force recursion
                     // are turned on. It comes after should_filter_response, so those cases
                     // are handled. Now we need to account for -s/-C options.
@@ -496,7 +930,28 @@ impl Requester {
 
                     let new_links: HashSet<_>;
 
-                    let result = extractor.extract().await?;
+                    let mut result = extractor.extract().await?;
+
+                    let headers_extractor = ExtractorBuilder::default()
+                        .target(ExtractionTarget::ResponseHeaders)
+                        .response(&ferox_response)
+                        .handles(self.handles.clone())
+                        .url(self.ferox_scan.url())
+                        .build()?;
+
+                    result.extend(headers_extractor.extract().await?);
+
+                    if is_javascript(&ferox_response) {
+                        // the original sources found in a script's source map are searched too
+                        let source_map_extractor = ExtractorBuilder::default()
+                            .target(ExtractionTarget::SourceMap)
+                            .response(&ferox_response)
+                            .handles(self.handles.clone())
+                            .url(self.ferox_scan.url())
+                            .build()?;
+
+                        result.extend(source_map_extractor.extract().await?);
+                    }
 
                     {
                         // gain and quickly drop the read lock on seen_links, using it while unlocked
@@ -523,10 +978,50 @@ impl Requester {
                     }
                 }
 
-                // everything else should be reported
-                if let Err(e) = ferox_response.send_report(self.handles.output.tx.clone()) {
+                if self.handles.config.detect_case
+                    && vhost.is_none()
+                    && !fuzzing
+                    && ferox_response.status().is_success()
+                {
+                    // the first resource found on a target decides its case-sensitivity
+                    if let Err(e) = self.detect_case(&ferox_response).await {
+                        log::warn!("Could not detect case-sensitivity: {}", e);
+                    }
+                }
+
+                // the endpoint is reported right away, but only mined once the scan's words
+                // have all been requested, see mine_queued_parameters
+                let mine_url = (!self.handles.config.param_mine.is_empty()
+                    && vhost.is_none()
+                    && method == DEFAULT_METHOD
+                    && ferox_response.status().is_success())
+                .then(|| endpoint_to_mine(ferox_response.url()))
+                .flatten();
+
+                if self.handles.config.discover_methods
+                    && self
+                        .handles
+                        .config
+                        .reports_status(ferox_response.status().as_u16())
+                {
+                    // the finding is reported along with its methods, once they've been
+                    // discovered, see discover_queued_methods
+                    if let Ok(mut queue) = self.method_queue.lock() {
+                        queue.push(ferox_response);
+                    }
+                } else if let Err(e) = ferox_response.send_report(
+                    // everything else should be reported
+                    self.handles.output.tx.clone(),
+                    self.handles.config.keep_response_bodies(),
+                ) {
                     log::warn!("Could not send FeroxResponse to output handler: {}", e);
                 }
+
+                if let Some(mine_url) = mine_url {
+                    if let Ok(mut queue) = self.mining_queue.lock() {
+                        queue.push(mine_url);
+                    }
+                }
             }
         }
 
@@ -549,6 +1044,7 @@ mod tests {
         filters,
         scan_manager::{ScanOrder, ScanType},
         statistics::StatError,
+        wordlists::Wordlist,
     };
 
     use super::*;
@@ -562,7 +1058,7 @@ mod tests {
         let (filters_task, filters_handle) = FiltersHandler::initialize();
         let (out_task, out_handle) =
             TermOutHandler::initialize(configuration.clone(), stats_handle.tx.clone());
-        let wordlist = Arc::new(vec![String::from("this_is_a_test")]);
+        let wordlist = Wordlist::from(vec![String::from("this_is_a_test")]);
 
         let handles = Arc::new(Handles::new(
             stats_handle,
@@ -698,6 +1194,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         let ferox_scan = Arc::new(FeroxScan::default());
@@ -727,6 +1225,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         increment_errors(requester.handles.clone(), ferox_scan.clone(), 25).await;
@@ -753,6 +1253,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         increment_status_codes(
@@ -794,6 +1296,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         increment_status_codes(
@@ -850,6 +1354,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester.bail(PolicyTrigger::Errors).await.unwrap();
@@ -885,6 +1391,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         let result = requester.bail(PolicyTrigger::Status403).await;
@@ -908,6 +1416,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: Default::default(),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester
@@ -932,6 +1442,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         });
 
         let start = Instant::now();
@@ -963,6 +1475,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester.policy_data.set_reqs_sec(400);
@@ -1002,6 +1516,8 @@ mod tests {
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester.policy_data.set_reqs_sec(400);
@@ -1040,6 +1556,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester.policy_data.set_reqs_sec(400);
@@ -1069,6 +1587,8 @@ mod tests {
             rate_limiter: RwLock::new(None),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         assert!(!requester.too_many_status_errors(PolicyTrigger::Errors));
@@ -1113,6 +1633,8 @@ mod tests {
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         requester.set_rate_limiter(Some(200)).await.unwrap();
@@ -1157,6 +1679,8 @@ mod tests {
             rate_limiter: RwLock::new(Some(limiter)),
             policy_data: PolicyData::new(RequesterPolicy::AutoTune, 4),
             policy_triggered: AtomicBool::new(false),
+            mining_queue: Mutex::new(Vec::new()),
+            method_queue: Mutex::new(Vec::new()),
         };
 
         let start = Instant::now();
@@ -5,6 +5,7 @@ use tokio::sync::Semaphore;
 use crate::{
     config::OutputLevel,
     event_handlers::Handles,
+    response::FeroxResponse,
     scan_manager::{FeroxScans, ScanOrder},
 };
 
@@ -20,9 +21,87 @@ async fn get_scan_by_url_bails_on_unfound_url() {
     let scanner = FeroxScanner::new(
         "http://localhost",
         ScanOrder::Initial,
-        Arc::new(Default::default()),
+        Default::default(),
         Arc::new(sem),
         Arc::new(Handles::for_testing(Some(Arc::new(urls)), None).0),
     );
     scanner.scan_url().await.unwrap();
 }
+
+#[test]
+/// each word expands into every candidate produced by --rules, which the per-directory
+/// expectation needs to account for
+fn expected_num_requests_per_dir_accounts_for_rules() {
+    let config = crate::config::Configuration {
+        rules: crate::rules::Rules::parse(&[":", "&[0-9]"]).unwrap(),
+        methods: vec![String::from("GET"), String::from("POST")],
+        ..Default::default()
+    };
+
+    let handles = Handles::for_testing(None, Some(Arc::new(config))).0;
+
+    // 1 word * 11 candidates * 2 methods
+    assert_eq!(handles.expected_num_requests_multiplier(), 2);
+    assert_eq!(handles.expected_num_requests_per_dir(), 22);
+}
+
+#[test]
+/// parameter names are appended to any existing query string, all sharing the same value
+fn param_miner_with_parameters_appends_names() {
+    let url = reqwest::Url::parse("http://localhost/search?q=1").unwrap();
+    let names = vec![String::from("debug"), String::from("admin")];
+
+    let probe = param_miner::with_parameters(&url, &names);
+    let pairs: Vec<_> = probe.query_pairs().collect();
+
+    assert_eq!(pairs.len(), 3);
+    assert_eq!(pairs[0], ("q".into(), "1".into()));
+    assert_eq!(pairs[1].0, "debug");
+    assert_eq!(pairs[2].0, "admin");
+    assert_eq!(pairs[1].1, pairs[2].1);
+}
+
+#[test]
+/// each endpoint is only mined once, regardless of its query string, and static files aren't
+/// mined at all
+fn param_miner_endpoint_to_mine_dedupes_and_skips_static_files() {
+    let url = |url: &str| reqwest::Url::parse(url).unwrap();
+
+    let endpoint = param_miner::endpoint_to_mine(&url("http://mined.local/search?q=1#top"));
+    assert_eq!(endpoint, Some(url("http://mined.local/search")));
+
+    assert!(param_miner::endpoint_to_mine(&url("http://mined.local/search")).is_none());
+    assert!(param_miner::endpoint_to_mine(&url("http://mined.local/search?q=2")).is_none());
+    assert!(param_miner::endpoint_to_mine(&url("http://mined.local/logo.PNG?v=3")).is_none());
+    assert!(param_miner::endpoint_to_mine(&url("http://mined.local/app.js")).is_none());
+    assert!(param_miner::endpoint_to_mine(&url("http://mined.local/index.php")).is_some());
+}
+
+#[test]
+/// responses are only considered changed when they differ from the baseline by the wildcard
+/// signals and either their status or the similarity of their bodies
+fn param_miner_baseline_detects_changes() {
+    let response = |status: u16, body: &str| -> FeroxResponse {
+        let mut response: FeroxResponse = serde_json::from_value(serde_json::json!({
+            "url": "http://localhost/search",
+            "status": status,
+            "content_length": body.len(),
+            "line_count": body.lines().count(),
+            "word_count": body.split_whitespace().count(),
+        }))
+        .unwrap();
+        response.set_text(body);
+        response
+    };
+
+    let body = "the quick brown fox jumps over the lazy dog while the cat sleeps on the warm mat all afternoon long";
+    let baseline = param_miner::Baseline::new(&response(200, body));
+
+    assert!(!baseline.is_changed(&response(200, body)));
+    assert!(!baseline.is_changed(&response(200, &format!("{body}!"))));
+    assert!(baseline.is_changed(&response(500, &format!("{body}!"))));
+    assert!(baseline.is_changed(&response(
+        200,
+        "stack trace: database connection string, environment variables, and more"
+    )));
+}
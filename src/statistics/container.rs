@@ -69,6 +69,9 @@ pub struct Stats {
     /// response bodies and robots.txt as of v1.11.0
     links_extracted: AtomicUsize,
 
+    /// tracker for number of page urls found in sitemaps when `--extract-links` is used
+    sitemap_links_extracted: AtomicUsize,
+
     /// tracker for number of extensions discovered when `--collect-extensions` is used; sources
     /// are response bodies
     extensions_collected: AtomicUsize,
@@ -155,7 +158,7 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Stats", 32)?;
+        let mut state = serializer.serialize_struct("Stats", 33)?;
 
         state.serialize_field("type", &self.kind)?;
         state.serialize_field("timeouts", &atomic_load!(self.timeouts))?;
@@ -170,6 +173,10 @@ impl Serialize for Stats {
         state.serialize_field("total_scans", &atomic_load!(self.total_scans))?;
         state.serialize_field("initial_targets", &atomic_load!(self.initial_targets))?;
         state.serialize_field("links_extracted", &atomic_load!(self.links_extracted))?;
+        state.serialize_field(
+            "sitemap_links_extracted",
+            &atomic_load!(self.sitemap_links_extracted),
+        )?;
         state.serialize_field(
             "extensions_collected",
             &atomic_load!(self.extensions_collected),
@@ -298,6 +305,13 @@ impl<'a> Deserialize<'a> for Stats {
                         }
                     }
                 }
+                "sitemap_links_extracted" => {
+                    if let Some(num) = value.as_u64() {
+                        if let Ok(parsed) = usize::try_from(num) {
+                            atomic_increment!(stats.sitemap_links_extracted, parsed);
+                        }
+                    }
+                }
                 "extensions_collected" => {
                     if let Some(num) = value.as_u64() {
                         if let Ok(parsed) = usize::try_from(num) {
@@ -643,6 +657,9 @@ impl Stats {
             StatField::LinksExtracted => {
                 atomic_increment!(self.links_extracted, value);
             }
+            StatField::SitemapLinksExtracted => {
+                atomic_increment!(self.sitemap_links_extracted, value);
+            }
             StatField::ExtensionsCollected => {
                 atomic_increment!(self.extensions_collected, value);
             }
@@ -682,6 +699,10 @@ impl Stats {
             atomic_increment!(self.client_errors, atomic_load!(d_stats.client_errors));
             atomic_increment!(self.server_errors, atomic_load!(d_stats.server_errors));
             atomic_increment!(self.links_extracted, atomic_load!(d_stats.links_extracted));
+            atomic_increment!(
+                self.sitemap_links_extracted,
+                atomic_load!(d_stats.sitemap_links_extracted)
+            );
             atomic_increment!(
                 self.extensions_collected,
                 atomic_load!(d_stats.extensions_collected)
@@ -856,7 +877,7 @@ mod tests {
     #[test]
     /// Stats::merge_from should properly increment expected fields and ignore others
     fn stats_merge_from_alters_correct_fields() {
-        let contents = r#"{"statistics":{"type":"statistics","timeouts":1,"requests":9207,"expected_per_scan":707,"total_expected":9191,"errors":3,"successes":720,"redirects":13,"client_errors":8474,"server_errors":2,"total_scans":13,"initial_targets":1,"links_extracted":51,"extensions_collected":4,"status_403s":3,"status_200s":720,"status_301s":12,"status_302s":1,"status_401s":4,"status_429s":2,"status_500s":5,"status_503s":9,"status_504s":6,"status_508s":7,"wildcards_filtered":707,"responses_filtered":707,"resources_discovered":27,"directory_scan_times":[2.211973078,1.989015505,1.898675839,3.9714468910000003,4.938152838,5.256073528,6.021986595,6.065740734,6.42633762,7.095142125,7.336982137,5.319785619,4.843649778],"total_runtime":[11.556575456000001],"url_format_errors":17,"redirection_errors":12,"connection_errors":21,"request_errors":4}}"#;
+        let contents = r#"{"statistics":{"type":"statistics","timeouts":1,"requests":9207,"expected_per_scan":707,"total_expected":9191,"errors":3,"successes":720,"redirects":13,"client_errors":8474,"server_errors":2,"total_scans":13,"initial_targets":1,"links_extracted":51,"sitemap_links_extracted":7,"extensions_collected":4,"status_403s":3,"status_200s":720,"status_301s":12,"status_302s":1,"status_401s":4,"status_429s":2,"status_500s":5,"status_503s":9,"status_504s":6,"status_508s":7,"wildcards_filtered":707,"responses_filtered":707,"resources_discovered":27,"directory_scan_times":[2.211973078,1.989015505,1.898675839,3.9714468910000003,4.938152838,5.256073528,6.021986595,6.065740734,6.42633762,7.095142125,7.336982137,5.319785619,4.843649778],"total_runtime":[11.556575456000001],"url_format_errors":17,"redirection_errors":12,"connection_errors":21,"request_errors":4}}"#;
         let config = Configuration::new().unwrap();
         let stats = Stats::new(config.json);
 
@@ -879,6 +900,7 @@ mod tests {
         assert_eq!(atomic_load!(stats.total_scans), 0); // not updated in merge_from
         assert_eq!(atomic_load!(stats.initial_targets), 0); // not updated in merge_from
         assert_eq!(atomic_load!(stats.links_extracted), 51);
+        assert_eq!(atomic_load!(stats.sitemap_links_extracted), 7);
         assert_eq!(atomic_load!(stats.extensions_collected), 4);
         assert_eq!(atomic_load!(stats.status_200s), 720);
         assert_eq!(atomic_load!(stats.status_301s), 12);
@@ -13,6 +13,9 @@ pub enum StatField {
     /// Translates to `links_extracted`
     LinksExtracted,
 
+    /// Translates to `sitemap_links_extracted`
+    SitemapLinksExtracted,
+
     /// Translates to `extensions_collected`
     ExtensionsCollected,
 
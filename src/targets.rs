@@ -0,0 +1,441 @@
+//! turn port scanner output (nmap XML, masscan JSON, naabu JSON) into urls to be scanned
+use std::{collections::HashSet, fs::read_to_string, path::Path};
+
+use anyhow::{bail, Context, Result};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use serde_json::Value;
+
+/// ports that are assumed to speak https when a scanner didn't identify the service
+const TLS_PORTS: [u16; 5] = [443, 4443, 8443, 9443, 10443];
+
+/// ports that are assumed to speak plain http when a scanner didn't identify the service
+const HTTP_PORTS: [u16; 8] = [80, 81, 591, 3000, 8000, 8008, 8080, 8888];
+
+/// supported port scanner output formats
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TargetsFormat {
+    /// nmap -oX
+    Nmap,
+
+    /// masscan -oJ
+    Masscan,
+
+    /// naabu -json
+    Naabu,
+}
+
+/// a single open port, along with whatever the scanner learned about it
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct OpenPort {
+    /// hostname (preferred) or ip address
+    host: String,
+
+    /// port number
+    port: u16,
+
+    /// service name reported by the scanner, if any (i.e. http, https, ssl/http, ssh)
+    service: Option<String>,
+
+    /// whether or not the scanner saw tls on the port
+    tls: bool,
+}
+
+impl OpenPort {
+    /// derive the url(s) that should be tested for this port
+    ///
+    /// - services identified as something other than http(s) are ignored
+    /// - tls (tunnel="ssl", naabu's tls, https service names) results in https
+    /// - when no service was identified, well-known ports decide the scheme and any other port
+    ///   gets both http and https, leaving it to the connectivity check to discard the dead one
+    fn urls(&self) -> Vec<String> {
+        let schemes: &[&str] = match self.service.as_deref().map(str::to_lowercase) {
+            Some(name) if name.contains("https") || (name.contains("http") && self.tls) => {
+                &["https"]
+            }
+            Some(name) if name.contains("http") => &["http"],
+            Some(name) if !name.is_empty() && name != "unknown" => &[],
+            _ if self.tls || TLS_PORTS.contains(&self.port) => &["https"],
+            _ if HTTP_PORTS.contains(&self.port) => &["http"],
+            _ => &["http", "https"],
+        };
+
+        let host = if self.host.contains(':') && !self.host.starts_with('[') {
+            // bare ipv6 address
+            format!("[{}]", self.host)
+        } else {
+            self.host.clone()
+        };
+
+        schemes
+            .iter()
+            .map(|scheme| match (*scheme, self.port) {
+                ("http", 80) | ("https", 443) => format!("{scheme}://{host}"),
+                _ => format!("{scheme}://{host}:{}", self.port),
+            })
+            .collect()
+    }
+}
+
+/// determine the format of the given port scanner output
+pub fn detect_format(contents: &str) -> Result<TargetsFormat> {
+    let trimmed = contents.trim_start();
+
+    if trimmed.starts_with('<') {
+        return Ok(TargetsFormat::Nmap);
+    }
+
+    // masscan and naabu both emit json objects; masscan groups ports under a `ports` array while
+    // naabu reports a single `port` per object
+    for object in json_objects(contents) {
+        if object.get("ports").is_some() {
+            return Ok(TargetsFormat::Masscan);
+        }
+
+        if object.get("port").is_some() {
+            return Ok(TargetsFormat::Naabu);
+        }
+    }
+
+    bail!("Could not determine the format of the targets file; expected nmap XML, masscan JSON, or naabu JSON")
+}
+
+/// read the port scanner output at the given path and return every http(s) url found within
+///
+/// the format is detected automatically, and duplicate urls are removed while preserving order
+pub fn read_targets_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    let path = path.as_ref();
+    log::trace!("enter: read_targets_file({})", path.display());
+
+    let contents = read_to_string(path)
+        .with_context(|| format!("Could not read targets file {}", path.display()))?;
+
+    let format = detect_format(&contents)?;
+    log::info!("{} detected as {:?} output", path.display(), format);
+
+    let ports = match format {
+        TargetsFormat::Nmap => parse_nmap(&contents)?,
+        TargetsFormat::Masscan => parse_masscan(&contents),
+        TargetsFormat::Naabu => parse_naabu(&contents),
+    };
+
+    let mut seen = HashSet::new();
+
+    let urls: Vec<String> = ports
+        .iter()
+        .flat_map(OpenPort::urls)
+        .filter(|url| seen.insert(url.clone()))
+        .collect();
+
+    log::trace!("exit: read_targets_file -> {:?}", urls);
+    Ok(urls)
+}
+
+/// parse the json objects out of masscan/naabu output
+///
+/// masscan writes a (frequently malformed, due to trailing commas) json array with one object
+/// per line, and naabu writes newline delimited json, so both are handled line by line when the
+/// whole thing isn't valid json
+fn json_objects(contents: &str) -> Vec<Value> {
+    if let Ok(Value::Array(objects)) = serde_json::from_str(contents) {
+        return objects;
+    }
+
+    contents
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .filter(|line| line.starts_with('{'))
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// parse masscan -oJ output
+fn parse_masscan(contents: &str) -> Vec<OpenPort> {
+    let mut open_ports = Vec::new();
+
+    for object in json_objects(contents) {
+        let Some(ip) = object.get("ip").and_then(Value::as_str) else {
+            continue;
+        };
+
+        for port in object
+            .get("ports")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let status = port.get("status").and_then(Value::as_str).unwrap_or("open");
+
+            let Some(number) = port.get("port").and_then(Value::as_u64) else {
+                continue;
+            };
+
+            if status != "open" || number > u64::from(u16::MAX) {
+                continue;
+            }
+
+            open_ports.push(OpenPort {
+                host: ip.to_string(),
+                port: number as u16,
+                service: port
+                    .get("service")
+                    .and_then(|service| service.get("name"))
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                tls: false,
+            });
+        }
+    }
+
+    open_ports
+}
+
+/// parse naabu -json output
+fn parse_naabu(contents: &str) -> Vec<OpenPort> {
+    json_objects(contents)
+        .iter()
+        .filter_map(|object| {
+            let host = object
+                .get("host")
+                .and_then(Value::as_str)
+                .filter(|host| !host.is_empty())
+                .or_else(|| object.get("ip").and_then(Value::as_str))?;
+
+            // newer versions report the port as a number, older versions as {"Port": 80, ...}
+            let port = object.get("port").and_then(|port| {
+                port.as_u64()
+                    .or_else(|| port.get("Port").and_then(Value::as_u64))
+            })?;
+
+            Some(OpenPort {
+                host: host.to_string(),
+                port: u16::try_from(port).ok()?,
+                service: None,
+                tls: object.get("tls").and_then(Value::as_bool).unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+/// helper to get an attribute's value from an xml element
+fn attribute(element: &BytesStart, reader: &Reader<&[u8]>, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key == name)
+        .and_then(|attr| attr.unescape_and_decode_value(reader).ok())
+}
+
+/// parse nmap -oX output
+fn parse_nmap(contents: &str) -> Result<Vec<OpenPort>> {
+    let mut reader = Reader::from_str(contents);
+    reader.trim_text(true);
+
+    let mut open_ports = Vec::new();
+    let mut buf = Vec::new();
+
+    // per-host state
+    let mut address = None;
+    let mut hostname = None;
+    let mut host_ports: Vec<OpenPort> = Vec::new();
+
+    // per-port state
+    let mut current_port: Option<OpenPort> = None;
+    let mut port_is_open = false;
+
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref element) | Event::Empty(ref element) => match element.name() {
+                b"host" => {
+                    address = None;
+                    hostname = None;
+                    host_ports.clear();
+                }
+                b"address" => {
+                    let addrtype = attribute(element, &reader, b"addrtype");
+
+                    if address.is_none() && addrtype.as_deref() != Some("mac") {
+                        address = attribute(element, &reader, b"addr");
+                    }
+                }
+                b"hostname" => {
+                    // user supplied names are preferred over reverse dns (PTR) names
+                    let is_user = attribute(element, &reader, b"type").as_deref() == Some("user");
+
+                    if hostname.is_none() || is_user {
+                        hostname = attribute(element, &reader, b"name");
+                    }
+                }
+                b"port" => {
+                    let protocol = attribute(element, &reader, b"protocol");
+                    let portid = attribute(element, &reader, b"portid")
+                        .and_then(|portid| portid.parse::<u16>().ok());
+
+                    port_is_open = false;
+                    current_port = match (protocol.as_deref(), portid) {
+                        (Some("tcp"), Some(port)) => Some(OpenPort {
+                            port,
+                            ..Default::default()
+                        }),
+                        _ => None,
+                    };
+                }
+                b"state" => {
+                    port_is_open = attribute(element, &reader, b"state").as_deref() == Some("open");
+                }
+                b"service" => {
+                    if let Some(port) = current_port.as_mut() {
+                        port.service = attribute(element, &reader, b"name");
+                        port.tls = attribute(element, &reader, b"tunnel").as_deref() == Some("ssl");
+                    }
+                }
+                _ => {}
+            },
+            Event::End(ref element) => match element.name() {
+                b"port" => {
+                    if let Some(port) = current_port.take() {
+                        if port_is_open {
+                            host_ports.push(port);
+                        }
+                    }
+                }
+                b"host" => {
+                    let Some(host) = hostname.take().or_else(|| address.take()) else {
+                        continue;
+                    };
+
+                    open_ports.extend(host_ports.drain(..).map(|port| OpenPort {
+                        host: host.clone(),
+                        ..port
+                    }));
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+
+        buf.clear();
+    }
+
+    Ok(open_ports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    /// trimmed down nmap -oX output with a variety of web and non-web services
+    const NMAP_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE nmaprun>
+<nmaprun scanner="nmap" args="nmap -sV -oX - 10.0.0.1 10.0.0.2">
+<host><status state="up" reason="syn-ack"/>
+<address addr="10.0.0.1" addrtype="ipv4"/>
+<address addr="00:11:22:33:44:55" addrtype="mac"/>
+<hostnames><hostname name="ptr.example.com" type="PTR"/><hostname name="app.example.com" type="user"/></hostnames>
+<ports>
+<port protocol="tcp" portid="22"><state state="open" reason="syn-ack"/><service name="ssh"/></port>
+<port protocol="tcp" portid="80"><state state="open" reason="syn-ack"/><service name="http"/></port>
+<port protocol="tcp" portid="443"><state state="open" reason="syn-ack"/><service name="http" tunnel="ssl"/></port>
+<port protocol="tcp" portid="8080"><state state="closed" reason="reset"/><service name="http-proxy"/></port>
+<port protocol="tcp" portid="8443"><state state="open" reason="syn-ack"/><service name="https-alt"/></port>
+</ports>
+</host>
+<host><status state="up" reason="syn-ack"/>
+<address addr="10.0.0.2" addrtype="ipv4"/>
+<hostnames></hostnames>
+<ports>
+<port protocol="tcp" portid="9000"><state state="open" reason="syn-ack"/></port>
+<port protocol="udp" portid="161"><state state="open" reason="udp-response"/><service name="snmp"/></port>
+</ports>
+</host>
+</nmaprun>"#;
+
+    /// masscan -oJ output, trailing commas and all
+    const MASSCAN_JSON: &str = r#"[
+{   "ip": "10.0.0.3",   "timestamp": "1700000000", "ports": [ {"port": 443, "proto": "tcp", "status": "open", "reason": "syn-ack", "ttl": 64} ] },
+{   "ip": "10.0.0.3",   "timestamp": "1700000000", "ports": [ {"port": 8080, "proto": "tcp", "status": "open", "reason": "syn-ack", "ttl": 64} ] },
+{   "ip": "10.0.0.4",   "timestamp": "1700000000", "ports": [ {"port": 22, "proto": "tcp", "status": "open", "service": {"name": "ssh", "banner": "OpenSSH"}} ] },
+]"#;
+
+    /// naabu -json output
+    const NAABU_JSON: &str = r#"{"host":"shop.example.com","ip":"10.0.0.5","port":8443,"protocol":"tcp","tls":true}
+{"host":"","ip":"10.0.0.6","port":80,"protocol":"tcp","tls":false}
+{"ip":"::1","port":{"Port":8000,"Protocol":0,"TLS":false}}
+"#;
+
+    #[test]
+    /// each supported format is detected from its contents
+    fn detect_format_recognizes_each_scanner() {
+        assert_eq!(detect_format(NMAP_XML).unwrap(), TargetsFormat::Nmap);
+        assert_eq!(detect_format(MASSCAN_JSON).unwrap(), TargetsFormat::Masscan);
+        assert_eq!(detect_format(NAABU_JSON).unwrap(), TargetsFormat::Naabu);
+        assert!(detect_format("http://localhost\nhttp://127.0.0.1").is_err());
+    }
+
+    #[test]
+    /// nmap services, tunnels, hostnames and port states decide which urls are produced
+    fn nmap_output_produces_web_urls() {
+        let urls: Vec<String> = parse_nmap(NMAP_XML)
+            .unwrap()
+            .iter()
+            .flat_map(OpenPort::urls)
+            .collect();
+
+        assert_eq!(
+            urls,
+            vec![
+                "http://app.example.com",
+                "https://app.example.com",
+                "https://app.example.com:8443",
+                "http://10.0.0.2:9000",
+                "https://10.0.0.2:9000",
+            ]
+        );
+    }
+
+    #[test]
+    /// masscan ports without service info fall back to well-known port numbers
+    fn masscan_output_produces_web_urls() {
+        let urls: Vec<String> = parse_masscan(MASSCAN_JSON)
+            .iter()
+            .flat_map(OpenPort::urls)
+            .collect();
+
+        assert_eq!(urls, vec!["https://10.0.0.3", "http://10.0.0.3:8080"]);
+    }
+
+    #[test]
+    /// naabu's host, tls flag and both styles of port are understood
+    fn naabu_output_produces_web_urls() {
+        let urls: Vec<String> = parse_naabu(NAABU_JSON)
+            .iter()
+            .flat_map(OpenPort::urls)
+            .collect();
+
+        assert_eq!(
+            urls,
+            vec![
+                "https://shop.example.com:8443",
+                "http://10.0.0.6",
+                "http://[::1]:8000"
+            ]
+        );
+    }
+
+    #[test]
+    /// reading a targets file detects the format and removes duplicate urls
+    fn read_targets_file_dedupes_urls() {
+        let tmp_dir = TempDir::new().unwrap();
+        let file = tmp_dir.path().join("naabu.json");
+        write(&file, format!("{NAABU_JSON}{NAABU_JSON}")).unwrap();
+
+        let urls = read_targets_file(&file).unwrap();
+        assert_eq!(urls.len(), 3);
+        assert!(read_targets_file(tmp_dir.path().join("nope")).is_err());
+    }
+}
@@ -1,5 +1,8 @@
 use crate::utils::parse_url_with_raw_path;
-use crate::{event_handlers::Handles, statistics::StatError::UrlFormat, Command::AddError};
+use crate::{
+    event_handlers::Handles, statistics::StatError::UrlFormat, wordlists::replace_keywords,
+    Command::AddError,
+};
 use anyhow::{anyhow, bail, Result};
 use reqwest::Url;
 use std::collections::HashSet;
@@ -83,6 +86,9 @@ impl FeroxUrl {
     pub fn format(&self, word: &str, extension: Option<&str>) -> Result<Url> {
         log::trace!("enter: format({}, {:?})", word, extension);
 
+        // the unaltered word is what gets placed into any query that contains the keyword
+        let query_word = word;
+
         if Url::parse(word).is_ok() {
             // when a full url is passed in as a word to be joined to a base url using
             // reqwest::Url::join, the result is that the word (url) completely overwrites the base
@@ -143,15 +149,31 @@ impl FeroxUrl {
             word = word.trim_start_matches('/').to_string();
         };
 
-        let base_url = parse_url_with_raw_path(&url)?;
-        let mut joined = base_url.join(&word)?;
+        let keywords = self.handles.config.keywords();
+
+        let mut joined = if self.handles.config.is_keyword_target(&self.target)
+            || self.handles.config.uses_fuzz_keyword()
+        {
+            // keyword mode: the word replaces the keyword(s) in the target url instead of being
+            // appended to it. When no keyword is in the url at all (i.e. they're only in a header
+            // or the body), the target url is requested as-is
+            parse_url_with_raw_path(&replace_keywords(&self.target, &word, keywords))?
+        } else {
+            let base_url = parse_url_with_raw_path(&url)?;
+            base_url.join(&word)?
+        };
 
         if !self.handles.config.queries.is_empty() {
             // if called, this adds a '?' to the url, whether or not there are queries to be added
             // so we need to check if there are queries to be added before blindly adding the '?'
             joined
                 .query_pairs_mut()
-                .extend_pairs(self.handles.config.queries.iter());
+                .extend_pairs(self.handles.config.queries.iter().map(|(name, value)| {
+                    (
+                        replace_keywords(name, query_word, keywords),
+                        replace_keywords(value, query_word, keywords),
+                    )
+                }));
         }
 
         log::trace!("exit: format_url -> {}", joined);
@@ -489,4 +511,64 @@ mod tests {
             Err(err) => panic!("{}", err.to_string()),
         }
     }
+
+    #[test]
+    /// the FUZZ keyword in the middle of a path should be replaced by the word + extension,
+    /// instead of the word being appended to the end of the url
+    fn format_url_replaces_fuzz_keyword_in_path() {
+        let config = Configuration {
+            extensions: vec!["php".to_string()],
+            ..Default::default()
+        };
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/api/FUZZ/users", handles);
+
+        let urls = url.formatted_urls("v1", HashSet::new()).unwrap();
+
+        assert_eq!(
+            urls,
+            [
+                Url::parse("http://localhost/api/v1/users").unwrap(),
+                Url::parse("http://localhost/api/v1.php/users").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    /// the FUZZ keyword in a query should be replaced by the word, and since the keyword is in
+    /// use, the word shouldn't be appended to the url
+    fn format_url_replaces_fuzz_keyword_in_queries() {
+        let mut config = Configuration {
+            queries: vec![
+                (String::from("id"), String::from("FUZZ")),
+                (String::from("stuff"), String::from("things")),
+            ],
+            ..Default::default()
+        };
+        config.cache_keywords();
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/api", handles);
+
+        assert_eq!(
+            url.format("1337", None).unwrap(),
+            Url::parse("http://localhost/api?id=1337&stuff=things").unwrap()
+        );
+    }
+
+    #[test]
+    /// when the FUZZ keyword is only in a header, the target url is used as-is
+    fn format_url_leaves_url_alone_when_fuzz_keyword_is_elsewhere() {
+        let mut config = Configuration::default();
+        config
+            .headers
+            .insert(String::from("X-Stuff"), String::from("FUZZ"));
+        config.cache_keywords();
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/api", handles);
+
+        assert_eq!(
+            url.format("things", None).unwrap(),
+            Url::parse("http://localhost/api").unwrap()
+        );
+    }
 }
@@ -6,8 +6,10 @@ use reqwest::{Client, Method, Response, StatusCode, Url};
 #[cfg(not(target_os = "windows"))]
 use rlimit::{getrlimit, setrlimit, Resource};
 use std::{
+    collections::HashMap,
     fs,
     io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
     time::{SystemTime, UNIX_EPOCH},
@@ -26,12 +28,17 @@ use crate::{
     send_command,
     statistics::StatError::{Connection, Other, Redirection, Request, Timeout},
     traits::FeroxSerialize,
+    wordlists::{replace_keywords, replace_keywords_in_bytes},
     USER_AGENTS,
 };
 
 /// simple counter for grabbing 'random' user agents
 static mut USER_AGENT_CTR: usize = 0;
 
+/// number of characters of a url's last path segment and query string that make it into the
+/// name of a saved response (--save-responses)
+const MAX_SAVED_FILENAME_LENGTH: usize = 128;
+
 /// Given the path to a file, open the file in append mode (create it if it doesn't exist) and
 /// return a reference to the buffered file
 pub fn open_file(filename: &str) -> Result<BufWriter<fs::File>> {
@@ -119,13 +126,24 @@ pub async fn logged_request(
     url: &Url,
     method: &str,
     data: Option<&[u8]>,
+    headers: Option<&HashMap<String, String>>,
     handles: Arc<Handles>,
 ) -> Result<Response> {
     let client = &handles.config.client;
     let level = handles.config.output_level;
     let tx_stats = handles.stats.tx.clone();
 
-    let response = make_request(client, url, method, data, level, &handles.config, tx_stats).await;
+    let response = make_request(
+        client,
+        url,
+        method,
+        data,
+        headers,
+        level,
+        &handles.config,
+        tx_stats,
+    )
+    .await;
 
     let scans = handles.ferox_scans()?;
     match response {
@@ -147,11 +165,16 @@ pub async fn logged_request(
 }
 
 /// Initiate request to the given `Url` using `Client`
+///
+/// any `headers` given are sent in addition to the client's default headers, replacing any
+/// default header of the same name
+#[allow(clippy::too_many_arguments)]
 pub async fn make_request(
     client: &Client,
     url: &Url,
     method: &str,
     mut data: Option<&[u8]>,
+    headers: Option<&HashMap<String, String>>,
     output_level: OutputLevel,
     config: &Configuration,
     tx_stats: UnboundedSender<Command>,
@@ -190,6 +213,12 @@ pub async fn make_request(
         request = request.body(body_data.to_vec());
     }
 
+    if let Some(headers) = headers {
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+    }
+
     if config.random_agent {
         let index = unsafe {
             USER_AGENT_CTR += 1;
@@ -516,8 +545,43 @@ pub fn should_deny_url(url: &Url, handles: Arc<Handles>) -> Result<bool> {
     Ok(false)
 }
 
+/// given a word from the wordlist, return a copy of every configured header whose value contains
+/// a wordlist keyword (FUZZ by default), with the keyword(s) replaced by `word`
+///
+/// returns `None` when no header value contains a keyword, as the client's default headers
+/// already cover that case
+pub fn fuzz_headers(word: &str, config: &Configuration) -> Option<HashMap<String, String>> {
+    let keywords = config.keywords();
+
+    let fuzzed: HashMap<String, String> = config
+        .headers
+        .iter()
+        .filter(|(_, value)| {
+            keywords
+                .iter()
+                .any(|keyword| value.contains(keyword.as_str()))
+        })
+        .map(|(name, value)| (name.to_owned(), replace_keywords(value, word, keywords)))
+        .collect();
+
+    if fuzzed.is_empty() {
+        None
+    } else {
+        Some(fuzzed)
+    }
+}
+
+/// given a word from the wordlist, return a copy of the configured request body with every
+/// instance of a wordlist keyword (FUZZ by default) replaced by `word`
+///
+/// returns `None` when the body doesn't contain a keyword
+pub fn fuzz_data(word: &str, config: &Configuration) -> Option<Vec<u8>> {
+    replace_keywords_in_bytes(&config.data, word, config.keywords())
+}
+
 /// given a url and filename-suffix, return a unique filename comprised of the slugified url,
-/// current unix timestamp and suffix
+/// current unix timestamp and suffix; anything other than ascii letters, digits, `-` and `_` is
+/// replaced by an underscore, which keeps query strings and ports safe to use in filenames
 ///
 /// ex: ferox-http_telsa_com-1606947491.state
 pub fn slugify_filename(url: &str, prefix: &str, suffix: &str) -> String {
@@ -534,7 +598,17 @@ pub fn slugify_filename(url: &str, prefix: &str, suffix: &str) -> String {
         String::new()
     };
 
-    let slug = url.replace("://", "_").replace(['/', '.'], "_");
+    let slug: String = url
+        .replace("://", "_")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
 
     let filename = format!("{altered_prefix}{slug}-{ts}.{suffix}");
 
@@ -542,6 +616,157 @@ pub fn slugify_filename(url: &str, prefix: &str, suffix: &str) -> String {
     filename
 }
 
+/// a single url path segment, made safe to use as a file or directory name
+fn sanitize_path_segment(segment: &str) -> String {
+    segment.replace([':', '\\'], "_")
+}
+
+/// a host (or a --vhost word), made safe to use as a directory name; like `slugify_filename`,
+/// anything other than ascii letters, digits, `-`, `_` and `.` is replaced by an underscore, and
+/// names made of nothing but dots are replaced entirely
+fn sanitize_host(host: &str) -> String {
+    let sanitized: String = host
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if sanitized.chars().all(|c| c == '.') {
+        String::from("unknown")
+    } else {
+        sanitized
+    }
+}
+
+/// write the body of the given response (--save-responses) to `directory/<host>/<path>`, and its
+/// headers to a `.headers` file next to it when `headers` is true, returning where the body was
+/// written
+///
+/// urls that end in a slash are saved as `index`; when the url has a query string, or its path
+/// was already saved, the filename is built by `slugify_filename` instead, so that nothing is
+/// overwritten
+///
+/// ex: http://localhost:8080/js/app.js?v=2 -> directory/localhost_8080/js/app_v_2-1606947491.js
+pub fn save_response(response: &FeroxResponse, directory: &Path, headers: bool) -> Result<PathBuf> {
+    log::trace!(
+        "enter: save_response({}, {:?}, {})",
+        response.url(),
+        directory,
+        headers
+    );
+
+    let url = response.url();
+
+    // virtual hosts all share the same url, so their responses are kept apart by the vhost
+    let host = match (response.vhost(), url.host_str(), url.port()) {
+        (Some(vhost), _, _) => vhost.to_string(),
+        (None, Some(host), Some(port)) => format!("{host}_{port}"),
+        (None, Some(host), None) => host.to_string(),
+        _ => String::from("unknown"),
+    };
+
+    let mut segments: Vec<String> = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|part| !part.is_empty() && *part != "." && *part != "..")
+                .map(sanitize_path_segment)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let name = if url.path().ends_with('/') {
+        String::from("index")
+    } else {
+        segments.pop().unwrap_or_else(|| String::from("index"))
+    };
+
+    let host_dir = directory.join(sanitize_host(&host));
+    let mut parent = host_dir.join(segments.iter().collect::<PathBuf>());
+    let mut name = name;
+
+    if fs::create_dir_all(&parent).is_err() {
+        // a file was already saved where a directory is needed, i.e. /js was saved before
+        // /js/app.js; flatten the path into a single name under the host instead
+        segments.push(name);
+        name = segments.join("_");
+        parent = host_dir;
+        fs::create_dir_all(&parent)
+            .with_context(|| fmt_err(&format!("Could not create {}", parent.display())))?;
+    }
+
+    let mut path = parent.join(&name);
+
+    if url.query().is_some() || path.exists() {
+        let (stem, extension) = match name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() => (stem, extension),
+            _ => (name.as_str(), "body"),
+        };
+
+        let slug = match url.query() {
+            Some(query) => format!("{stem}?{query}"),
+            None => stem.to_string(),
+        };
+
+        // long query strings would otherwise exceed the maximum filename length
+        let slug: String = slug.chars().take(MAX_SAVED_FILENAME_LENGTH).collect();
+
+        path = parent.join(slugify_filename(&slug, "", extension));
+
+        let mut counter = 1;
+
+        while path.exists() {
+            path = parent.join(slugify_filename(
+                &format!("{slug}-{counter}"),
+                "",
+                extension,
+            ));
+            counter += 1;
+        }
+    }
+
+    if !path.starts_with(directory) {
+        bail!(fmt_err(&format!(
+            "Refusing to write {} outside of {}",
+            path.display(),
+            directory.display()
+        )));
+    }
+
+    fs::write(&path, response.text())
+        .with_context(|| fmt_err(&format!("Could not write {}", path.display())))?;
+
+    if headers {
+        let mut contents = format!("{}\n", response.status());
+
+        for (name, value) in response.headers() {
+            contents.push_str(&format!(
+                "{}: {}\n",
+                name,
+                String::from_utf8_lossy(value.as_bytes())
+            ));
+        }
+
+        let mut headers_path = path.clone().into_os_string();
+        headers_path.push(".headers");
+
+        fs::write(&headers_path, contents).with_context(|| {
+            fmt_err(&format!(
+                "Could not write {}",
+                Path::new(&headers_path).display()
+            ))
+        })?;
+    }
+
+    log::trace!("exit: save_response -> {}", path.display());
+    Ok(path)
+}
+
 /// This function takes a url string and returns a `url::Url`
 ///
 /// It is primarily used to detect url paths that `url::Url::parse` will
@@ -1175,4 +1400,155 @@ mod tests {
         assert!(!should_deny_url(&tested_https_url, handles.clone()).unwrap());
         assert!(should_deny_url(&tested_http_url, handles).unwrap());
     }
+
+    #[test]
+    /// only headers containing the FUZZ keyword should be returned, with the keyword replaced
+    fn fuzz_headers_replaces_keyword_in_header_values() {
+        let mut config = Configuration::new().unwrap();
+
+        assert!(fuzz_headers("stuff", &config).is_none());
+
+        config
+            .headers
+            .insert(String::from("X-Stuff"), String::from("Bearer FUZZ"));
+        config
+            .headers
+            .insert(String::from("X-Things"), String::from("static"));
+
+        let fuzzed = fuzz_headers("stuff", &config).unwrap();
+
+        assert_eq!(fuzzed.len(), 1);
+        assert_eq!(fuzzed.get("X-Stuff").unwrap(), "Bearer stuff");
+    }
+
+    #[test]
+    /// every instance of the FUZZ keyword in the body should be replaced
+    fn fuzz_data_replaces_keyword_in_body() {
+        let mut config = Configuration::new().unwrap();
+
+        assert!(fuzz_data("stuff", &config).is_none());
+
+        config.data = br#"{"a":"FUZZ","b":"FUZZFUZZ","c":1}"#.to_vec();
+
+        assert_eq!(
+            fuzz_data("stuff", &config).unwrap(),
+            br#"{"a":"stuff","b":"stuffstuff","c":1}"#.to_vec()
+        );
+
+        config.data = b"no keyword here".to_vec();
+        assert!(fuzz_data("stuff", &config).is_none());
+    }
+
+    #[test]
+    /// anything that isn't safe to use in a filename is replaced by an underscore
+    fn slugify_filename_replaces_unsafe_characters() {
+        let filename = slugify_filename("http://localhost:8080/a.js?v=1&x", "ferox", "state");
+
+        assert!(filename.starts_with("ferox-http_localhost_8080_a_js_v_1_x-"));
+        assert!(filename.ends_with(".state"));
+    }
+
+    /// helper to build a response with the given url and body
+    fn response_with(url: &str, body: &str) -> FeroxResponse {
+        let mut response = FeroxResponse::default();
+        response.set_url(url);
+        response.set_text(body);
+        response
+    }
+
+    #[test]
+    /// bodies are saved to a tree mirroring host/path, with directories saved as index and
+    /// traversal segments dropped
+    fn save_response_mirrors_host_and_path() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+
+        let path = save_response(
+            &response_with("http://localhost:8080/js/app.js", "app"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(path, tmp_dir.path().join("localhost_8080/js/app.js"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "app");
+
+        let path = save_response(
+            &response_with("http://localhost/admin/../", "index"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(path, tmp_dir.path().join("localhost/admin/index"));
+    }
+
+    #[test]
+    /// query strings and already saved paths get a slugified name instead of overwriting
+    fn save_response_never_overwrites() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+
+        let first = save_response(
+            &response_with("http://localhost/app.js", "first"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+        let second = save_response(
+            &response_with("http://localhost/app.js", "second"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+        let query = save_response(
+            &response_with("http://localhost/app.js?v=2", "query"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(fs::read_to_string(&first).unwrap(), "first");
+        assert_eq!(fs::read_to_string(&second).unwrap(), "second");
+
+        let name = query.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("app_v_2-") && name.ends_with(".js"));
+        assert_eq!(fs::read_to_string(&query).unwrap(), "query");
+    }
+
+    #[test]
+    /// a file saved where a directory is needed flattens the path under the host instead
+    fn save_response_flattens_when_file_is_in_the_way() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+
+        save_response(
+            &response_with("http://localhost/js", "js"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+
+        let path = save_response(
+            &response_with("http://localhost/js/app.js", "app"),
+            tmp_dir.path(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(path, tmp_dir.path().join("localhost/js_app.js"));
+    }
+
+    #[test]
+    /// vhost words can't escape the output directory through the host directory
+    fn save_response_sanitizes_vhost_directory() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+
+        let mut response = response_with("http://localhost/", "escaped");
+        response.set_vhost("../../etc");
+
+        let path = save_response(&response, tmp_dir.path(), false).unwrap();
+        assert_eq!(path, tmp_dir.path().join(".._.._etc/index"));
+
+        response.set_vhost("..");
+
+        let path = save_response(&response, tmp_dir.path(), false).unwrap();
+        assert_eq!(path, tmp_dir.path().join("unknown/index"));
+    }
 }
@@ -0,0 +1,270 @@
+//! WARC archive output (--warc); each archived exchange is written as a pair of WARC/1.1 records,
+//! the response and the request that produced it
+//!
+//! neither message is captured off the wire: requests are rebuilt from the configuration, and
+//! responses carry their body as it was received once de-chunked, so the headers describing its
+//! framing are rewritten; the warcinfo record says as much
+use std::time::SystemTime;
+
+use uuid::Uuid;
+
+use crate::{config::Configuration, response::FeroxResponse, VERSION};
+
+/// response headers that describe how the body was framed, which no longer holds once it's
+/// de-chunked
+const TRANSFER_HEADERS: [&str; 2] = ["transfer-encoding", "content-length"];
+
+/// fields added to every exchange record
+const EXCHANGE_FIELDS: [(&str, &str); 1] = [("WARC-Protocol", "http/1.1")];
+
+/// a fresh WARC-Record-ID
+fn record_id() -> String {
+    format!("<urn:uuid:{}>", Uuid::new_v4())
+}
+
+/// a single WARC record of the given type, with any extra named fields, wrapping `block`
+fn record(
+    warc_type: &str,
+    id: &str,
+    fields: &[(&str, &str)],
+    content_type: &str,
+    block: &[u8],
+) -> Vec<u8> {
+    let mut header = format!(
+        "WARC/1.1\r\nWARC-Type: {warc_type}\r\nWARC-Record-ID: {id}\r\nWARC-Date: {}\r\n",
+        humantime::format_rfc3339_seconds(SystemTime::now())
+    );
+
+    for (name, value) in fields {
+        header.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    header.push_str(&format!(
+        "Content-Type: {content_type}\r\nContent-Length: {}\r\n\r\n",
+        block.len()
+    ));
+
+    let mut record = header.into_bytes();
+    record.extend_from_slice(block);
+    record.extend_from_slice(b"\r\n\r\n");
+    record
+}
+
+/// the warcinfo record that starts each archive
+pub(crate) fn warcinfo() -> Vec<u8> {
+    let block = format!(
+        "software: feroxbuster/{VERSION}\r\nformat: WARC File Format 1.1\r\ndescription: \
+         exchanges are rebuilt rather than captured; requests from the configuration, responses \
+         with their body as received, de-chunked, and HTTP/1.1 as their protocol\r\n"
+    );
+
+    record(
+        "warcinfo",
+        &record_id(),
+        &[],
+        "application/warc-fields",
+        block.as_bytes(),
+    )
+}
+
+/// the request that produced the given response, as an HTTP message; it's rebuilt from the
+/// response and the configuration, see `FeroxResponse::request_headers`, and only has a body
+/// when the requester recorded one being sent, see `FeroxResponse::set_sent_request`
+pub(crate) fn request_block(response: &FeroxResponse, config: &Configuration) -> Vec<u8> {
+    let url = response.url();
+
+    let target = match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    };
+
+    let mut head = format!("{} {target} HTTP/1.1\r\n", response.method());
+
+    for (name, value) in response.request_headers(config) {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    let body = response.sent_body();
+
+    if let Some(body) = body {
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+
+    head.push_str("\r\n");
+
+    let mut block = head.into_bytes();
+    block.extend_from_slice(body.unwrap_or_default());
+    block
+}
+
+/// the given response, as an HTTP message
+///
+/// the protocol version isn't kept along with the response, so HTTP/1.1 is always used; the body
+/// is written as it was received, but de-chunked, so the framing headers are replaced by its
+/// actual length
+pub(crate) fn response_block(response: &FeroxResponse) -> Vec<u8> {
+    let body = response.body();
+
+    let mut head = format!("HTTP/1.1 {}\r\n", response.status());
+
+    for (name, value) in response.headers() {
+        if TRANSFER_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+
+        head.push_str(&format!(
+            "{}: {}\r\n",
+            name,
+            String::from_utf8_lossy(value.as_bytes())
+        ));
+    }
+
+    head.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+
+    let mut block = head.into_bytes();
+    block.extend_from_slice(body);
+    block
+}
+
+/// the response and request records of a single exchange; the request points at its response
+/// through WARC-Concurrent-To
+pub(crate) fn exchange(response: &FeroxResponse, config: &Configuration) -> Vec<u8> {
+    let url = response.url().as_str();
+    let response_id = record_id();
+
+    let mut response_fields = vec![("WARC-Target-URI", url)];
+    response_fields.extend(EXCHANGE_FIELDS);
+
+    let mut records = record(
+        "response",
+        &response_id,
+        &response_fields,
+        "application/http;msgtype=response",
+        &response_block(response),
+    );
+
+    let mut request_fields = vec![
+        ("WARC-Target-URI", url),
+        ("WARC-Concurrent-To", response_id.as_str()),
+    ];
+    request_fields.extend(EXCHANGE_FIELDS);
+
+    records.extend(record(
+        "request",
+        &record_id(),
+        &request_fields,
+        "application/http;msgtype=request",
+        &request_block(response, config),
+    ));
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// the content length covers the block exactly, and the record ends with two CRLFs
+    fn record_wraps_block_with_header_and_trailer() {
+        let record = record("request", "<urn:uuid:1>", &[], "text/plain", b"hello");
+        let record = String::from_utf8(record).unwrap();
+
+        assert!(record.starts_with("WARC/1.1\r\nWARC-Type: request\r\n"));
+        assert!(record.contains("Content-Length: 5\r\n\r\nhello\r\n\r\n"));
+    }
+
+    #[test]
+    /// the records aren't captured off the wire, which the archive says up front
+    fn warcinfo_describes_rebuilt_records() {
+        let warcinfo = String::from_utf8(warcinfo()).unwrap();
+
+        assert!(warcinfo.contains("WARC-Type: warcinfo\r\n"));
+        assert!(warcinfo.contains("description: exchanges are rebuilt rather than captured;"));
+    }
+
+    #[test]
+    /// the request line keeps the query string, and the vhost is sent as the Host header
+    fn request_block_rebuilds_request_from_config() {
+        let mut config = Configuration::new().unwrap();
+        config
+            .headers
+            .insert(String::from("X-Stuff"), String::from("things"));
+
+        let mut response = FeroxResponse::default();
+        response.set_url("http://10.0.0.1:8080/admin?debug=1");
+        response.set_vhost("admin.example.com");
+        response.set_sent_request(None, Some(b"a=1"));
+
+        let block = String::from_utf8(request_block(&response, &config)).unwrap();
+
+        assert!(block.starts_with("GET /admin?debug=1 HTTP/1.1\r\nHost: admin.example.com\r\n"));
+        assert!(block.contains(&format!("User-Agent: {}\r\n", config.user_agent)));
+        assert!(block.ends_with("X-Stuff: things\r\nContent-Length: 3\r\n\r\na=1"));
+    }
+
+    #[test]
+    /// --data only ends up in the requests that were sent with it, i.e. not in a heuristic's
+    fn request_block_skips_body_not_sent() {
+        let mut config = Configuration::new().unwrap();
+        config.data = b"a=1".to_vec();
+
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/admin");
+
+        let block = String::from_utf8(request_block(&response, &config)).unwrap();
+
+        assert!(block.ends_with("\r\n\r\n"));
+        assert!(!block.contains("Content-Length"));
+        assert!(!block.contains("a=1"));
+    }
+
+    #[test]
+    /// the body is de-chunked, so the headers describing how it was framed are replaced by its
+    /// actual length, while its content encoding still holds
+    fn response_block_rewrites_transfer_headers() {
+        let mut response: FeroxResponse = serde_json::from_value(serde_json::json!({
+            "url": "http://localhost/",
+            "status": 200,
+            "headers": {
+                "content-encoding": "gzip",
+                "transfer-encoding": "chunked",
+                "content-length": "1337",
+                "server": "nginx",
+            },
+        }))
+        .unwrap();
+        response.set_text("decoded body");
+
+        let block = String::from_utf8(response_block(&response)).unwrap();
+
+        assert!(block.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(block.contains("content-encoding: gzip\r\n"));
+        assert!(block.contains("server: nginx\r\n"));
+        assert!(!block.contains("transfer-encoding"));
+        assert!(!block.contains("1337"));
+        assert!(block.ends_with("Content-Length: 12\r\n\r\ndecoded body"));
+    }
+
+    #[test]
+    /// the request record points at the response record it belongs to
+    fn exchange_links_request_to_response() {
+        let config = Configuration::new().unwrap();
+
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/");
+        response.set_text("body");
+
+        let records = String::from_utf8(exchange(&response, &config)).unwrap();
+
+        let response_id = records
+            .lines()
+            .find_map(|line| line.strip_prefix("WARC-Record-ID: "))
+            .unwrap();
+
+        assert!(records.contains(&format!("WARC-Concurrent-To: {response_id}\r\n")));
+        assert!(records.contains("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nbody\r\n\r\n"));
+        assert_eq!(records.matches("WARC-Protocol: http/1.1\r\n").count(), 2);
+        assert!(!records.contains("WARC-Truncated"));
+    }
+}
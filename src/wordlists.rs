@@ -0,0 +1,926 @@
+//! wordlist storage, along with support for multiple keyword-tagged wordlists
+//! (`-w dirs.txt:DIR -w versions.txt:VER`) and the different ways they can be combined into a
+//! single keyspace
+use crate::{config::WordlistMode, FUZZ_KEYWORD};
+use anyhow::{anyhow, bail, Context, Result};
+use flate2::read::MultiGzDecoder;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{header::ETAG, header::IF_NONE_MATCH, Client, StatusCode};
+use ruzstd::StreamingDecoder;
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// number of words between each byte offset recorded while indexing a wordlist file; seeking to
+/// a word means jumping to the closest preceding offset and reading forward at most this many
+/// words
+const CHECKPOINT_INTERVAL: usize = 1024;
+
+/// separates the per-keyword values of a single combined word, i.e. `admin\nv1` places `admin`
+/// at the first wordlist's keyword and `v1` at the second's
+///
+/// a newline can never appear in a word read from a wordlist, which makes it a safe choice
+pub const KEYWORD_SEPARATOR: char = '\n';
+
+lazy_static! {
+    /// a valid keyword suffix on a wordlist spec, i.e. the `DIR` in `dirs.txt:DIR`
+    static ref KEYWORD_REGEX: Regex = Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap();
+}
+
+/// split a wordlist spec (`path[:KEYWORD]`) into its path and keyword
+///
+/// the keyword must be uppercase letters, digits and underscores; anything else following the
+/// last colon is considered part of the path (i.e. `C:\words.txt` or `http://host:8080/words`).
+/// When no keyword is given, the FUZZ keyword is used
+pub fn parse_wordlist_spec(spec: &str) -> (&str, &str) {
+    if let Some((path, keyword)) = spec.rsplit_once(':') {
+        if !path.is_empty() && KEYWORD_REGEX.is_match(keyword) {
+            return (path, keyword);
+        }
+    }
+
+    (spec, FUZZ_KEYWORD)
+}
+
+/// combine the given wordlists into a single [`Wordlist`], where each word holds one value per
+/// wordlist, joined by [`KEYWORD_SEPARATOR`]
+///
+/// - clusterbomb: every combination of values, the first wordlist changing the slowest
+/// - pitchfork: the first value of each list, then the second of each, etc... stopping once the
+///   shortest list is exhausted
+///
+/// only the individual lists are held in memory; combined words are built from their index as
+/// they're needed. The ordering is deterministic, which is what allows resumed scans to skip the
+/// words that were already requested. Like [`Wordlist::from_file`], the resulting list begins
+/// with an empty string
+pub fn combine_wordlists(lists: Vec<Vec<String>>, mode: WordlistMode) -> Result<Wordlist> {
+    let len = match mode {
+        WordlistMode::Clusterbomb => lists
+            .iter()
+            .try_fold(1_usize, |len, list| len.checked_mul(list.len()))
+            .ok_or_else(|| anyhow!("Combining the wordlists produces too many words"))?,
+        WordlistMode::Pitchfork => lists.iter().map(|list| list.len()).min().unwrap_or(0),
+    };
+
+    Ok(Wordlist {
+        source: Source::Combined(Arc::new(CombinedSource { lists, mode, len })),
+        start: 0,
+    })
+}
+
+/// replace every keyword found in `text` with its value from the (possibly combined) `word`
+///
+/// keywords without a corresponding value (i.e. a single random word used during heuristics) are
+/// given the first value. Returns `None` when `text` doesn't contain any of the keywords
+pub fn replace_keywords_in_bytes(text: &[u8], word: &str, keywords: &[String]) -> Option<Vec<u8>> {
+    let values: Vec<&str> = word.split(KEYWORD_SEPARATOR).collect();
+
+    // longest keywords are checked first so that FUZZ doesn't clobber the start of FUZZ2
+    let mut pairs: Vec<(&[u8], &[u8])> = keywords
+        .iter()
+        .enumerate()
+        .map(|(idx, keyword)| {
+            let value = values.get(idx).unwrap_or(&values[0]);
+            (keyword.as_bytes(), value.as_bytes())
+        })
+        .filter(|(keyword, _)| !keyword.is_empty())
+        .collect();
+
+    pairs.sort_by_key(|(keyword, _)| std::cmp::Reverse(keyword.len()));
+
+    let mut replaced = Vec::with_capacity(text.len());
+    let mut found = false;
+    let mut idx = 0;
+
+    // single pass over the text, so values that happen to contain a keyword aren't replaced again
+    'outer: while idx < text.len() {
+        for (keyword, value) in pairs.iter() {
+            if text[idx..].starts_with(keyword) {
+                replaced.extend_from_slice(value);
+                idx += keyword.len();
+                found = true;
+                continue 'outer;
+            }
+        }
+
+        replaced.push(text[idx]);
+        idx += 1;
+    }
+
+    if found {
+        Some(replaced)
+    } else {
+        None
+    }
+}
+
+/// string version of [`replace_keywords_in_bytes`]; `text` is returned unaltered when it doesn't
+/// contain any of the keywords
+pub fn replace_keywords(text: &str, word: &str, keywords: &[String]) -> String {
+    match replace_keywords_in_bytes(text.as_bytes(), word, keywords) {
+        // keywords and values are both valid utf-8 and are only ever swapped out whole
+        Some(replaced) => String::from_utf8(replaced).unwrap_or_else(|_| text.to_string()),
+        None => text.to_string(),
+    }
+}
+
+/// where the words in a [`Wordlist`] live
+#[derive(Debug, Clone)]
+enum Source {
+    /// words held in memory (collected words, tests)
+    Memory(Arc<Vec<String>>),
+
+    /// words read from disk as they're needed
+    File(Arc<FileSource>),
+
+    /// words combined from multiple wordlists as they're needed
+    Combined(Arc<CombinedSource>),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Self::Memory(Arc::new(Vec::new()))
+    }
+}
+
+/// an indexed wordlist file
+#[derive(Debug)]
+struct FileSource {
+    /// location of the wordlist on disk
+    path: PathBuf,
+
+    /// how the file is compressed, if at all
+    compression: Compression,
+
+    /// number of usable words in the file
+    len: usize,
+
+    /// byte offset of every CHECKPOINT_INTERVAL'th word in the file; compressed files can't be
+    /// seeked into, so they have no checkpoints and are read forward from the start instead
+    checkpoints: Vec<u64>,
+}
+
+/// multiple wordlists combined into a single keyspace, see [`combine_wordlists`]
+#[derive(Debug)]
+struct CombinedSource {
+    /// the wordlists being combined, in keyword order
+    lists: Vec<Vec<String>>,
+
+    /// how the lists are combined
+    mode: WordlistMode,
+
+    /// number of combined words
+    len: usize,
+}
+
+impl CombinedSource {
+    /// the combined word at the given index (not counting the leading empty string)
+    ///
+    /// clusterbomb indexes are treated as a mixed-radix number, where each digit is an index into
+    /// one of the lists and the last list is the least significant digit
+    fn word(&self, idx: usize) -> String {
+        let values: Vec<&str> = match self.mode {
+            WordlistMode::Clusterbomb => {
+                let mut values = vec![""; self.lists.len()];
+                let mut remainder = idx;
+
+                for (value, list) in values.iter_mut().zip(&self.lists).rev() {
+                    *value = list[remainder % list.len()].as_str();
+                    remainder /= list.len();
+                }
+
+                values
+            }
+            WordlistMode::Pitchfork => self.lists.iter().map(|list| list[idx].as_str()).collect(),
+        };
+
+        values.join(&KEYWORD_SEPARATOR.to_string())
+    }
+}
+
+/// compression formats that are transparently decompressed when reading a wordlist
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Compression {
+    /// plain text
+    None,
+
+    /// gzip (.gz)
+    Gzip,
+
+    /// zstandard (.zst)
+    Zstd,
+}
+
+impl Compression {
+    /// determine a file's compression from its magic bytes, regardless of its extension
+    fn detect(path: &Path) -> Result<Self> {
+        let mut magic = Vec::with_capacity(4);
+
+        File::open(path)
+            .with_context(|| format!("Could not open {}", path.display()))?
+            .take(4)
+            .read_to_end(&mut magic)?;
+
+        Ok(match magic.as_slice() {
+            [0x1f, 0x8b, ..] => Self::Gzip,
+            [0x28, 0xb5, 0x2f, 0xfd] => Self::Zstd,
+            _ => Self::None,
+        })
+    }
+}
+
+/// open the (possibly compressed) wordlist file, positioned at the given byte offset into its
+/// decompressed contents; offsets other than 0 are only valid for uncompressed files
+fn open_wordlist_file(
+    path: &Path,
+    compression: Compression,
+    offset: u64,
+) -> Result<Box<dyn BufRead + Send>> {
+    let mut file =
+        File::open(path).with_context(|| format!("Could not open {}", path.display()))?;
+
+    let reader: Box<dyn Read + Send> = match compression {
+        Compression::None => {
+            file.seek(SeekFrom::Start(offset))?;
+            Box::new(file)
+        }
+        Compression::Gzip => Box::new(MultiGzDecoder::new(file)),
+        Compression::Zstd => Box::new(
+            StreamingDecoder::new(file)
+                .map_err(|e| anyhow!("Could not decompress {}: {}", path.display(), e))?,
+        ),
+    };
+
+    Ok(Box::new(BufReader::new(reader)))
+}
+
+/// reads the usable words (no comments or blank lines) out of a wordlist file, along with the
+/// byte offset at which each word's line starts
+struct FileWords<R> {
+    /// underlying reader
+    reader: R,
+
+    /// byte offset of the next line to be read
+    position: u64,
+
+    /// reusable line buffer
+    buffer: Vec<u8>,
+
+    /// error that stopped the iteration early, i.e. a truncated or corrupt compressed stream
+    error: Option<std::io::Error>,
+}
+
+impl<R: BufRead> FileWords<R> {
+    /// create a new FileWords that starts reading at the given byte offset
+    fn new(reader: R, position: u64) -> Self {
+        Self {
+            reader,
+            position,
+            buffer: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for FileWords<R> {
+    type Item = (u64, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+
+            let start = self.position;
+            let num_read = match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(num_read) => num_read,
+                Err(e) => {
+                    log::error!("Could not read from the wordlist, stopping early: {}", e);
+                    self.error = Some(e);
+                    return None;
+                }
+            };
+
+            if num_read == 0 {
+                return None;
+            }
+
+            self.position += num_read as u64;
+
+            // lines that aren't valid utf-8 are skipped
+            let Ok(line) = std::str::from_utf8(&self.buffer) else {
+                continue;
+            };
+
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            if !line.starts_with('#') && !line.is_empty() {
+                return Some((start, line.to_string()));
+            }
+        }
+    }
+}
+
+/// list of words used to scan each directory
+///
+/// wordlist files aren't loaded into memory; instead, they're indexed once and then streamed from
+/// disk by each scan. Clones are cheap and share the underlying words, and a view that starts
+/// part-way through the list (i.e. when resuming a scan) can be made with [`Wordlist::skip`]
+#[derive(Debug, Clone, Default)]
+pub struct Wordlist {
+    /// where the words come from
+    source: Source,
+
+    /// index of the first word in this view of the wordlist
+    start: usize,
+}
+
+impl Wordlist {
+    /// index the wordlist file at the given path, without reading its words into memory
+    ///
+    /// gzip and zstandard compressed files are detected and decompressed as they're read
+    ///
+    /// the resulting list always begins with an empty string, which ensures that we call
+    /// Requester::request with the base url, i.e. `http://localhost/` instead of going straight
+    /// into `http://localhost/WORD.EXT`. For vanilla scans, it doesn't matter all that much, but it
+    /// can be a significant difference when `-e` is used, depending on the content at the base url.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        log::trace!("enter: Wordlist::from_file({})", path.display());
+
+        let compression = Compression::detect(path)?;
+        let reader = open_wordlist_file(path, compression, 0)?;
+
+        let mut len = 0;
+        let mut checkpoints = Vec::new();
+        let mut trimmed_word = false;
+
+        let mut words = FileWords::new(reader, 0);
+
+        for (offset, word) in words.by_ref() {
+            if compression == Compression::None && len % CHECKPOINT_INTERVAL == 0 {
+                checkpoints.push(offset);
+            }
+
+            trimmed_word |= word.starts_with('/');
+            len += 1;
+        }
+
+        if let Some(e) = words.error {
+            return Err(e).with_context(|| format!("Could not read {}", path.display()));
+        }
+
+        if trimmed_word {
+            log::warn!("Some words in the wordlist started with a leading forward-slash; those words were trimmed (i.e. /word -> word)");
+        }
+
+        let wordlist = Self {
+            source: Source::File(Arc::new(FileSource {
+                path: path.to_path_buf(),
+                compression,
+                len,
+                checkpoints,
+            })),
+            start: 0,
+        };
+
+        log::trace!(
+            "exit: Wordlist::from_file -> Wordlist[{} words...]",
+            wordlist.len()
+        );
+
+        Ok(wordlist)
+    }
+
+    /// total number of words in the underlying list, ignoring this view's starting point
+    fn total_len(&self) -> usize {
+        match &self.source {
+            Source::Memory(words) => words.len(),
+            // + 1 for the leading empty string
+            Source::File(file) => file.len + 1,
+            Source::Combined(combined) => combined.len + 1,
+        }
+    }
+
+    /// number of words in this view of the wordlist
+    pub fn len(&self) -> usize {
+        self.total_len().saturating_sub(self.start)
+    }
+
+    /// whether or not this view of the wordlist has any words
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// a view of the wordlist that begins `offset` words after this one
+    pub fn skip(&self, offset: usize) -> Self {
+        Self {
+            source: self.source.clone(),
+            start: (self.start + offset).min(self.total_len()),
+        }
+    }
+
+    /// iterate over the words in this view of the wordlist
+    ///
+    /// words from a file are read from disk as the iterator is consumed
+    pub fn iter(&self) -> Result<Box<dyn Iterator<Item = String> + Send>> {
+        let start = self.start;
+
+        match &self.source {
+            Source::Memory(words) => {
+                let words = words.clone();
+                Ok(Box::new(
+                    (start..words.len()).map(move |idx| words[idx].clone()),
+                ))
+            }
+            Source::File(source) => {
+                // index 0 is the leading empty string, index 1 is the file's first word
+                let leading = (start == 0).then(String::new);
+                let word_idx = start.saturating_sub(1);
+
+                // jump to the closest checkpoint, if there is one, and read forward from there
+                let (offset, to_skip) = match source.checkpoints.get(word_idx / CHECKPOINT_INTERVAL)
+                {
+                    Some(offset) => (*offset, word_idx % CHECKPOINT_INTERVAL),
+                    None => (0, word_idx),
+                };
+
+                let reader = open_wordlist_file(&source.path, source.compression, offset)?;
+
+                let words = FileWords::new(reader, offset)
+                    .skip(to_skip)
+                    .take(source.len.saturating_sub(word_idx))
+                    .map(|(_, word)| {
+                        if word.starts_with('/') {
+                            word.trim_start_matches('/').to_string()
+                        } else {
+                            word
+                        }
+                    });
+
+                Ok(Box::new(leading.into_iter().chain(words)))
+            }
+            Source::Combined(source) => {
+                // index 0 is the leading empty string, index 1 is the first combined word
+                let source = source.clone();
+                Ok(Box::new((start..source.len + 1).map(
+                    move |idx| match idx {
+                        0 => String::new(),
+                        _ => source.word(idx - 1),
+                    },
+                )))
+            }
+        }
+    }
+}
+
+impl From<Vec<String>> for Wordlist {
+    fn from(words: Vec<String>) -> Self {
+        Self {
+            source: Source::Memory(Arc::new(words)),
+            start: 0,
+        }
+    }
+}
+
+/// default location for downloaded wordlists, i.e. ~/.cache/feroxbuster/wordlists on linux
+pub fn wordlist_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("feroxbuster")
+        .join("wordlists")
+}
+
+/// filename used to cache the wordlist found at the given url
+///
+/// the url is slugified for readability and suffixed with a (stable) FNV-1a hash of the full url
+/// so that long or similar urls can't collide
+fn cache_filename(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+
+    let slug: String = url
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .take(64)
+        .collect();
+
+    format!("{slug}-{hash:016x}")
+}
+
+/// download the wordlist at the given url into `cache_dir`, returning the path to the cached copy
+///
+/// when a copy is already cached and the server gave an ETag for it, the server is asked whether
+/// the wordlist changed (If-None-Match) and the cached copy is reused when it hasn't. If the
+/// server can't be reached, a previously cached copy is used as-is
+pub async fn download_wordlist(client: &Client, url: &str, cache_dir: &Path) -> Result<PathBuf> {
+    log::trace!("enter: download_wordlist({}, {})", url, cache_dir.display());
+
+    fs::create_dir_all(cache_dir)
+        .with_context(|| format!("Could not create cache directory {}", cache_dir.display()))?;
+
+    let filename = cache_filename(url);
+    let cached = cache_dir.join(&filename);
+    let etag_file = cache_dir.join(format!("{filename}.etag"));
+
+    let mut request = client.get(url);
+
+    if cached.exists() {
+        if let Ok(etag) = fs::read_to_string(&etag_file) {
+            request = request.header(IF_NONE_MATCH, etag.trim());
+        }
+    }
+
+    let mut response = match request.send().await {
+        Ok(response) => response,
+        Err(e) if cached.exists() => {
+            log::warn!("Could not reach {}, using cached copy: {}", url, e);
+            return Ok(cached);
+        }
+        Err(e) => {
+            return Err(e).context(format!(
+                "Unable to download wordlist from remote url: {url}"
+            ))
+        }
+    };
+
+    if response.status() == StatusCode::NOT_MODIFIED && cached.exists() {
+        log::info!("cached copy of {} is up to date", url);
+        log::trace!("exit: download_wordlist -> {}", cached.display());
+        return Ok(cached);
+    }
+
+    if !response.status().is_success() {
+        if cached.exists() {
+            log::warn!(
+                "[{}] Unable to download wordlist from url: {}, using cached copy",
+                response.status().as_str(),
+                url
+            );
+            return Ok(cached);
+        }
+
+        // status code isn't a 200, bail
+        bail!(
+            "[{}] Unable to download wordlist from url: {}",
+            response.status().as_str(),
+            url
+        );
+    }
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from);
+
+    // write the body to a temporary file first, so an interrupted download never leaves a
+    // partial wordlist in the cache; the pid keeps concurrent runs from writing to the same file
+    let partial = cache_dir.join(format!("{filename}.{}.part", std::process::id()));
+    let mut file = File::create(&partial)?;
+
+    let written: Result<()> = async {
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk)?;
+        }
+
+        file.flush()?;
+        fs::rename(&partial, &cached)?;
+        Ok(())
+    }
+    .await;
+
+    if let Err(e) = written {
+        let _ = fs::remove_file(&partial);
+        return Err(e).context(format!(
+            "Unable to download wordlist from remote url: {url}"
+        ));
+    }
+
+    match etag {
+        Some(etag) => fs::write(&etag_file, etag)?,
+        None => {
+            // without an etag there's nothing to revalidate with, so the next run downloads again
+            let _ = fs::remove_file(&etag_file);
+        }
+    }
+
+    log::trace!("exit: download_wordlist -> {}", cached.display());
+    Ok(cached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression as GzCompression};
+    use httpmock::{Method::GET, MockServer};
+    use std::fs::write;
+    use tempfile::TempDir;
+
+    /// helper to build a Vec<String> from string literals
+    fn strings(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    /// wordlist specs are split into path and keyword only when the suffix is a valid keyword
+    fn parse_wordlist_spec_splits_path_and_keyword() {
+        assert_eq!(parse_wordlist_spec("dirs.txt:DIR"), ("dirs.txt", "DIR"));
+        assert_eq!(
+            parse_wordlist_spec("a:b/v.txt:VER_2"),
+            ("a:b/v.txt", "VER_2")
+        );
+        assert_eq!(parse_wordlist_spec("dirs.txt"), ("dirs.txt", FUZZ_KEYWORD));
+        assert_eq!(
+            parse_wordlist_spec("C:\\words.txt"),
+            ("C:\\words.txt", FUZZ_KEYWORD)
+        );
+        assert_eq!(
+            parse_wordlist_spec("http://localhost:8080/words"),
+            ("http://localhost:8080/words", FUZZ_KEYWORD)
+        );
+        assert_eq!(parse_wordlist_spec(":DIR"), (":DIR", FUZZ_KEYWORD));
+    }
+
+    #[test]
+    /// clusterbomb produces every combination, with the first list changing the slowest
+    fn combine_wordlists_clusterbomb_is_cartesian_product() {
+        let lists = vec![strings(&["a", "b"]), strings(&["1", "2", "3"])];
+        let combined = combine_wordlists(lists, WordlistMode::Clusterbomb).unwrap();
+
+        assert_eq!(combined.len(), 7);
+        assert_eq!(
+            combined.iter().unwrap().collect::<Vec<_>>(),
+            strings(&["", "a\n1", "a\n2", "a\n3", "b\n1", "b\n2", "b\n3"])
+        );
+    }
+
+    #[test]
+    /// skipping into a combined wordlist lands on the same word as iterating up to it
+    fn combine_wordlists_skip_computes_word_from_index() {
+        let lists = vec![
+            strings(&["a", "b"]),
+            strings(&["1", "2", "3"]),
+            strings(&["x", "y"]),
+        ];
+        let combined = combine_wordlists(lists, WordlistMode::Clusterbomb).unwrap();
+        let words: Vec<_> = combined.iter().unwrap().collect();
+
+        assert_eq!(words.len(), 13);
+
+        for offset in 0..words.len() {
+            assert_eq!(
+                combined.skip(offset).iter().unwrap().next().as_ref(),
+                words.get(offset)
+            );
+        }
+
+        assert_eq!(words[8], "b\n1\ny");
+        assert!(combined.skip(13).is_empty());
+    }
+
+    #[test]
+    /// a clusterbomb keyspace that doesn't fit in a usize is an error instead of wrapping around
+    fn combine_wordlists_clusterbomb_rejects_overflow() {
+        let list = strings(&["a"; 1 << 16]);
+        let lists = vec![list.clone(), list.clone(), list.clone(), list.clone(), list];
+
+        assert!(combine_wordlists(lists, WordlistMode::Clusterbomb).is_err());
+    }
+
+    #[test]
+    /// pitchfork zips the lists together, stopping at the shortest
+    fn combine_wordlists_pitchfork_zips_lists() {
+        let lists = vec![strings(&["a", "b", "c"]), strings(&["1", "2"])];
+        let combined = combine_wordlists(lists, WordlistMode::Pitchfork).unwrap();
+
+        assert_eq!(
+            combined.iter().unwrap().collect::<Vec<_>>(),
+            strings(&["", "a\n1", "b\n2"])
+        );
+    }
+
+    #[test]
+    /// each keyword is replaced by its own value, and missing values fall back to the first
+    fn replace_keywords_places_each_value() {
+        let keywords = strings(&["DIR", "VER"]);
+
+        assert_eq!(
+            replace_keywords("/DIR/VER/DIR", "api\nv1", &keywords),
+            "/api/v1/api"
+        );
+        assert_eq!(
+            replace_keywords("/DIR/VER", "random", &keywords),
+            "/random/random"
+        );
+        assert_eq!(
+            replace_keywords("/nothing", "api\nv1", &keywords),
+            "/nothing"
+        );
+    }
+
+    #[test]
+    /// longer keywords win over keywords that are their prefix, and values aren't re-replaced
+    fn replace_keywords_handles_overlapping_keywords() {
+        let keywords = strings(&["FUZZ", "FUZZ2"]);
+
+        assert_eq!(
+            replace_keywords("FUZZ-FUZZ2", "FUZZ2\nb", &keywords),
+            "FUZZ2-b"
+        );
+        assert!(replace_keywords_in_bytes(b"no keywords", "a\nb", &keywords).is_none());
+    }
+
+    #[test]
+    /// wordlist files are indexed instead of loaded, skipping comments and blank lines, and
+    /// trimming leading slashes
+    fn wordlist_from_file_streams_usable_words() {
+        let tmp_dir = TempDir::new().unwrap();
+        let file = tmp_dir.path().join("wordlist");
+        write(&file, "# comment\nadmin\r\n\n/api\nlogin").unwrap();
+
+        let wordlist = Wordlist::from_file(&file).unwrap();
+
+        assert_eq!(wordlist.len(), 4);
+        assert_eq!(
+            wordlist.iter().unwrap().collect::<Vec<_>>(),
+            strings(&["", "admin", "api", "login"])
+        );
+        assert_eq!(
+            wordlist.skip(2).iter().unwrap().collect::<Vec<_>>(),
+            strings(&["api", "login"])
+        );
+        assert!(wordlist.skip(10).is_empty());
+        assert!(Wordlist::from_file(tmp_dir.path().join("nope")).is_err());
+    }
+
+    #[test]
+    /// seeking into a wordlist file lands on the same word an in-memory list would, including
+    /// across checkpoint boundaries
+    fn wordlist_skip_matches_in_memory_list() {
+        let tmp_dir = TempDir::new().unwrap();
+        let file = tmp_dir.path().join("wordlist");
+        let words: Vec<String> = (0..CHECKPOINT_INTERVAL * 3)
+            .map(|num| format!("word{num}"))
+            .collect();
+        write(&file, words.join("\n")).unwrap();
+
+        let from_file = Wordlist::from_file(&file).unwrap();
+        let in_memory = Wordlist::from(
+            std::iter::once(String::new())
+                .chain(words.iter().cloned())
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(from_file.len(), in_memory.len());
+
+        for offset in [0, 1, 2, CHECKPOINT_INTERVAL, CHECKPOINT_INTERVAL + 1, 2500] {
+            let file_view = from_file.skip(offset);
+            let memory_view = in_memory.skip(offset);
+
+            assert_eq!(file_view.len(), memory_view.len());
+            assert_eq!(
+                file_view.iter().unwrap().take(5).collect::<Vec<_>>(),
+                memory_view.iter().unwrap().take(5).collect::<Vec<_>>()
+            );
+        }
+
+        // views can be taken of views
+        assert_eq!(
+            from_file.skip(10).skip(5).iter().unwrap().next(),
+            Some(String::from("word14"))
+        );
+    }
+
+    #[test]
+    /// gzip and zstandard compressed wordlists are detected by their contents and decompressed
+    /// as they're read
+    fn wordlist_from_file_decompresses_gzip_and_zstd() {
+        let tmp_dir = TempDir::new().unwrap();
+        let expected = strings(&["", "admin", "api", "login"]);
+
+        let gzipped = tmp_dir.path().join("wordlist.gz");
+        let mut encoder = GzEncoder::new(Vec::new(), GzCompression::default());
+        encoder
+            .write_all(b"admin\n# comment\n/api\nlogin\n")
+            .unwrap();
+        write(&gzipped, encoder.finish().unwrap()).unwrap();
+
+        // printf 'admin\n# comment\n/api\nlogin\n' | zstd -c
+        let zstd = tmp_dir.path().join("wordlist.zst");
+        write(
+            &zstd,
+            [
+                0x28, 0xb5, 0x2f, 0xfd, 0x04, 0x58, 0xd9, 0x00, 0x00, 0x61, 0x64, 0x6d, 0x69, 0x6e,
+                0x0a, 0x23, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x65, 0x6e, 0x74, 0x0a, 0x2f, 0x61, 0x70,
+                0x69, 0x0a, 0x6c, 0x6f, 0x67, 0x69, 0x6e, 0x0a, 0x86, 0xd9, 0x9c, 0xb7,
+            ],
+        )
+        .unwrap();
+
+        for path in [gzipped, zstd] {
+            let wordlist = Wordlist::from_file(&path).unwrap();
+
+            assert_eq!(wordlist.len(), 4);
+            assert_eq!(wordlist.iter().unwrap().collect::<Vec<_>>(), expected);
+            assert_eq!(
+                wordlist.skip(2).iter().unwrap().collect::<Vec<_>>(),
+                strings(&["api", "login"])
+            );
+        }
+    }
+
+    #[test]
+    /// a compressed stream that ends part-way through is an error, not a shorter wordlist
+    fn wordlist_from_file_reports_corrupt_streams() {
+        let tmp_dir = TempDir::new().unwrap();
+        let truncated = tmp_dir.path().join("wordlist.gz");
+
+        let mut encoder = GzEncoder::new(Vec::new(), GzCompression::default());
+        encoder.write_all(b"admin\napi\nlogin\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+        write(&truncated, &compressed[..compressed.len() - 10]).unwrap();
+
+        assert!(Wordlist::from_file(&truncated).is_err());
+    }
+
+    #[test]
+    /// cache filenames are stable, filesystem friendly and unique per url
+    fn cache_filename_is_stable_and_unique() {
+        let name = cache_filename("https://example.com/lists/words.txt.gz");
+
+        assert_eq!(
+            name,
+            cache_filename("https://example.com/lists/words.txt.gz")
+        );
+        assert!(name.starts_with("https___example_com_lists_words_txt_gz-"));
+        assert_ne!(
+            name,
+            cache_filename("https://example.com/lists/words_txt.gz")
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// downloaded wordlists are cached by url and revalidated using their etag
+    async fn download_wordlist_caches_and_revalidates_with_etag() {
+        let srv = MockServer::start();
+        let tmp_dir = TempDir::new().unwrap();
+        let client = Client::new();
+        let url = srv.url("/words.txt");
+
+        let fresh = srv.mock(|when, then| {
+            when.method(GET).path("/words.txt").matches(|req| {
+                !req.headers
+                    .iter()
+                    .flatten()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("If-None-Match"))
+            });
+            then.status(200)
+                .header("ETag", "\"v1\"")
+                .body("stuff\nthings");
+        });
+
+        let mut revalidated = srv.mock(|when, then| {
+            when.method(GET)
+                .path("/words.txt")
+                .header("If-None-Match", "\"v1\"");
+            then.status(304);
+        });
+
+        let first = download_wordlist(&client, &url, tmp_dir.path())
+            .await
+            .unwrap();
+        let second = download_wordlist(&client, &url, tmp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.parent().unwrap(), tmp_dir.path());
+        assert_eq!(fs::read_to_string(&first).unwrap(), "stuff\nthings");
+        assert_eq!(fresh.hits(), 1);
+        assert_eq!(revalidated.hits(), 1);
+
+        // an error response falls back to the cached copy
+        revalidated.delete();
+        let broken = srv.mock(|when, then| {
+            when.method(GET).path("/words.txt");
+            then.status(503);
+        });
+        let errored = download_wordlist(&client, &url, tmp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(errored, first);
+        assert_eq!(broken.hits(), 1);
+
+        // once the server can't be reached, the cached copy is still usable
+        drop(srv);
+        let offline = download_wordlist(&client, &url, tmp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(offline, first);
+    }
+}
@@ -1,7 +1,7 @@
 mod utils;
 use assert_cmd::prelude::*;
-use httpmock::Method::GET;
-use httpmock::MockServer;
+use httpmock::Method::{DELETE, GET, OPTIONS, POST};
+use httpmock::{MockServer, Regex};
 use predicates::prelude::*;
 use std::process::Command;
 use utils::{setup_tmp_directory, teardown_tmp_directory};
@@ -710,3 +710,527 @@ fn robots_text_extraction_doesnt_run_with_dont_extract_links() {
     assert_eq!(mock_scanned_file.hits(), 0);
     teardown_tmp_directory(tmp_dir);
 }
+
+#[test]
+/// the target exposes an openapi spec at a well-known location; its endpoints should be requested
+/// with their declared safe methods and its path prefixes should be requested as well; methods
+/// that may change data are skipped without --openapi-methods
+fn extractor_requests_endpoints_from_openapi_spec() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let spec = srv.mock(|when, then| {
+        when.method(GET).path("/openapi.json");
+        then.status(200).body(
+            r#"{"openapi": "3.0.0", "servers": [{"url": "/api"}], "paths": {"/users": {"get": {}, "post": {}, "options": {}}, "/users/{id}": {"get": {}}}}"#,
+        );
+    });
+
+    let get_users = srv.mock(|when, then| {
+        when.method(GET).path("/api/users");
+        then.status(200).body("all the users");
+    });
+
+    let post_users = srv.mock(|when, then| {
+        when.method(POST).path("/api/users");
+        then.status(201).body("created a user");
+    });
+
+    let options_users = srv.mock(|when, then| {
+        when.method(OPTIONS).path("/api/users");
+        then.status(204).header("Allow", "GET, POST, OPTIONS");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--discover")
+        .arg("--status-codes")
+        .arg("200,201,204")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/api/users")
+            .and(predicate::str::contains("204"))
+            .and(predicate::str::contains("OPTIONS"))
+            .and(predicate::str::contains("POST").not()),
+    );
+
+    assert_eq!(spec.hits(), 1);
+    assert_eq!(get_users.hits(), 1);
+    assert_eq!(options_users.hits(), 1);
+    assert_eq!(post_users.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --openapi reads the spec from disk instead of requesting it from the target, and
+/// --openapi-methods allows requesting endpoints with methods that may change data
+fn extractor_requests_endpoints_from_openapi_file() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let (spec_dir, spec_file) = setup_tmp_directory(
+        &[
+            "swagger: '2.0'".to_string(),
+            "basePath: /v1".to_string(),
+            "paths:".to_string(),
+            "  /orders:".to_string(),
+            "    delete: {}".to_string(),
+        ],
+        "swagger.yaml",
+    )?;
+
+    let probe = srv.mock(|when, then| {
+        when.method(GET).path("/swagger.json");
+        then.status(404);
+    });
+
+    let delete_orders = srv.mock(|when, then| {
+        when.method(DELETE).path("/v1/orders");
+        then.status(200).body("deleted the orders");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--openapi")
+        .arg(spec_file.as_os_str())
+        .arg("--openapi-methods")
+        .unwrap();
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/v1/orders").and(predicate::str::contains("DELETE")));
+
+    assert_eq!(probe.hits(), 0);
+    assert_eq!(delete_orders.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(spec_dir);
+    Ok(())
+}
+
+#[test]
+/// sitemaps referenced by robots.txt should be requested, sitemap indexes followed, gzipped
+/// sitemaps decompressed, and every page found requested
+fn extractor_follows_sitemaps_from_robots_txt() -> Result<(), Box<dyn std::error::Error>> {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(
+        format!(
+            "<urlset><url><loc>{}</loc></url></urlset>",
+            srv.url("/blog/first-post")
+        )
+        .as_bytes(),
+    )?;
+    let gzipped = encoder.finish()?;
+
+    let robots = srv.mock(|when, then| {
+        when.method(GET).path("/robots.txt");
+        then.status(200)
+            .body("User-agent: *\nsitemap: /sitemap_index.xml\n");
+    });
+
+    let default_sitemap = srv.mock(|when, then| {
+        when.method(GET).path("/sitemap.xml");
+        then.status(200)
+            .body("<urlset><url><loc>/about-us</loc></url></urlset>");
+    });
+
+    let index = srv.mock(|when, then| {
+        when.method(GET).path("/sitemap_index.xml");
+        then.status(200).body(format!(
+            "<sitemapindex><sitemap><loc>{}</loc></sitemap></sitemapindex>",
+            srv.url("/sitemaps/posts.xml.gz")
+        ));
+    });
+
+    let posts = srv.mock(|when, then| {
+        when.method(GET).path("/sitemaps/posts.xml.gz");
+        then.status(200).body(gzipped.clone());
+    });
+
+    let about = srv.mock(|when, then| {
+        when.method(GET).path("/about-us");
+        then.status(200).body("about us");
+    });
+
+    let post = srv.mock(|when, then| {
+        when.method(GET).path("/blog/first-post");
+        then.status(200).body("first post");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/about-us").and(predicate::str::contains("/blog/first-post")),
+    );
+
+    assert_eq!(robots.hits(), 1);
+    assert_eq!(default_sitemap.hits(), 1);
+    assert_eq!(index.hits(), 1);
+    assert_eq!(posts.hits(), 1);
+    assert_eq!(about.hits(), 1);
+    assert_eq!(post.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// well-known resources should be requested and reported, and the urls within them requested
+fn extractor_reports_well_known_resources_and_their_links() -> Result<(), Box<dyn std::error::Error>>
+{
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let security_txt = srv.mock(|when, then| {
+        when.method(GET).path("/.well-known/security.txt");
+        then.status(200).body(format!(
+            "Contact: mailto:security@localhost\nPolicy: {}\n",
+            srv.url("/security-policy.html")
+        ));
+    });
+
+    let openid = srv.mock(|when, then| {
+        when.method(GET).path("/.well-known/openid-configuration");
+        then.status(200).body(format!(
+            r#"{{"issuer": "{}", "jwks_uri": "{}"}}"#,
+            srv.url("/"),
+            srv.url("/oauth/keys")
+        ));
+    });
+
+    let policy = srv.mock(|when, then| {
+        when.method(GET).path("/security-policy.html");
+        then.status(200).body("be nice");
+    });
+
+    let jwks = srv.mock(|when, then| {
+        when.method(GET).path("/oauth/keys");
+        then.status(200).body(r#"{"keys": []}"#);
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--discover")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/.well-known/security.txt")
+            .and(predicate::str::contains(
+                "/.well-known/openid-configuration",
+            ))
+            .and(predicate::str::contains("/security-policy.html"))
+            .and(predicate::str::contains("/oauth/keys")),
+    );
+
+    assert_eq!(security_txt.hits(), 1);
+    assert_eq!(openid.hits(), 1);
+    assert_eq!(policy.hits(), 1);
+    assert_eq!(jwks.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// without --discover, neither well-known resources nor api specs are requested
+fn extractor_skips_discovery_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let discovery = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/(\\.well-known/|openapi|swagger)").unwrap());
+        then.status(200).body("{}");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .assert()
+        .success();
+
+    assert_eq!(discovery.hits(), 0);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request to a javascript file whose source map reveals an api route, expect the map
+/// and the route to be requested and the original sources to be saved to --source-map-dir
+fn extractor_mines_javascript_source_maps() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["app.js".to_string()], "wordlist")?;
+
+    let script = srv.mock(|when, then| {
+        when.method(GET).path("/app.js");
+        then.status(200)
+            .header("content-type", "application/javascript")
+            .body("!function(){}();\n//# sourceMappingURL=app.js.map\n");
+    });
+
+    let map = srv.mock(|when, then| {
+        when.method(GET).path("/app.js.map");
+        then.status(200).body(
+            r#"{"version":3,"sources":["webpack:///./src/users.js"],"sourcesContent":["export const list = () => fetch(\"/api/v2/users\");"],"mappings":""}"#,
+        );
+    });
+
+    let users = srv.mock(|when, then| {
+        when.method(GET).path("/api/v2/users");
+        then.status(200).body("[]");
+    });
+
+    let sources = tmp_dir.path().join("sources");
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--source-map-dir")
+        .arg(sources.as_os_str())
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/app.js.map").and(predicate::str::contains("/api/v2/users")),
+    );
+
+    let saved = sources
+        .join(format!("127.0.0.1_{}", srv.port()))
+        .join("src/users.js");
+
+    assert!(std::fs::read_to_string(saved)?.contains("/api/v2/users"));
+    assert_eq!(script.hits(), 1);
+    assert_eq!(map.hits(), 1);
+    assert_eq!(users.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request to a page that links to a script, expect the script's source map (found via
+/// the SourceMap header) to be mined for links as well
+fn extractor_mines_source_maps_of_extracted_scripts() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["index.html".to_string()], "wordlist")?;
+
+    let index = srv.mock(|when, then| {
+        when.method(GET).path("/index.html");
+        then.status(200)
+            .body(r#"<html><script src="/static/vendor.js"></script></html>"#);
+    });
+
+    let script = srv.mock(|when, then| {
+        when.method(GET).path("/static/vendor.js");
+        then.status(200)
+            .header("SourceMap", "/maps/vendor.js.map")
+            .body("!function(){}();");
+    });
+
+    let map = srv.mock(|when, then| {
+        when.method(GET).path("/maps/vendor.js.map");
+        then.status(200).body(
+            r#"{"version":3,"sources":["vendor.ts"],"sourcesContent":["const admin = '/internal/admin.php';"],"mappings":""}"#,
+        );
+    });
+
+    let admin = srv.mock(|when, then| {
+        when.method(GET).path("/internal/admin.php");
+        then.status(200).body("admin");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/maps/vendor.js.map")
+            .and(predicate::str::contains("/internal/admin.php")),
+    );
+
+    assert_eq!(index.hits(), 1);
+    assert_eq!(script.hits(), 1);
+    assert_eq!(map.hits(), 1);
+    assert_eq!(admin.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request to a page whose headers reference other resources, expect those resources
+/// to be requested even though the body never mentions them
+fn extractor_finds_links_in_response_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["login".to_string()], "wordlist")?;
+
+    let login = srv.mock(|when, then| {
+        when.method(GET).path("/login");
+        then.status(200)
+            .header("Link", "</assets/login.css>; rel=preload; as=style")
+            .header("Set-Cookie", "sid=1; Path=/portal/; HttpOnly")
+            .body("please log in");
+    });
+
+    let css = srv.mock(|when, then| {
+        when.method(GET).path("/assets/login.css");
+        then.status(200).body("body {}");
+    });
+
+    let portal = srv.mock(|when, then| {
+        when.method(GET).path("/portal");
+        then.status(200).body("portal");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/assets/login.css").and(predicate::str::contains("/portal")),
+    );
+
+    assert_eq!(login.hits(), 1);
+    assert_eq!(css.hits(), 1);
+    assert_eq!(portal.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// the extractor's own requests (robots.txt, sitemap.xml, etc...) have their headers searched
+/// for links too, expect the resources they reference to be requested
+fn extractor_finds_links_in_extractor_response_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let robots = srv.mock(|when, then| {
+        when.method(GET).path("/robots.txt");
+        then.status(200)
+            .header("Link", "</static/robots.css>; rel=preload; as=style")
+            .body("User-agent: *");
+    });
+
+    let sitemap = srv.mock(|when, then| {
+        when.method(GET).path("/sitemap.xml");
+        then.status(302).header("Location", "/maps/sitemap.xml");
+    });
+
+    let css = srv.mock(|when, then| {
+        when.method(GET).path("/static/robots.css");
+        then.status(200).body("body {}");
+    });
+
+    let moved = srv.mock(|when, then| {
+        when.method(GET).path("/maps/sitemap.xml");
+        then.status(200).body("<urlset></urlset>");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("/static/robots.css")
+            .and(predicate::str::contains("/maps/sitemap.xml")),
+    );
+
+    assert!(robots.hits() >= 1);
+    assert_eq!(sitemap.hits(), 1);
+    assert_eq!(css.hits(), 1);
+    assert_eq!(moved.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// send a request to a page with a form, a link with a query string, and a fetch call, expect
+/// a parameter inventory record for each endpoint to be written to the output file
+fn extractor_writes_parameter_inventory_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["index.html".to_string()], "wordlist")?;
+
+    let index = srv.mock(|when, then| {
+        when.method(GET).path("/index.html");
+        then.status(200).body(
+            r#"<html>
+                <form action="/login" method="post"><input name="username"><input name="password"></form>
+                <a href="/search?q=ferox&page=2">search</a>
+                <script>fetch("/api/comments", {method: "PUT", body: JSON.stringify({text: t})});</script>
+            </html>"#,
+        );
+    });
+
+    let outfile = tmp_dir.path().join("output");
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--json")
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .unwrap();
+
+    let contents = std::fs::read_to_string(outfile)?;
+
+    for expected in [
+        format!(
+            r#"{{"type":"parameters","url":"{}","method":"POST","parameters":["password","username"],"sources":["form"]}}"#,
+            srv.url("/login")
+        ),
+        format!(
+            r#"{{"type":"parameters","url":"{}","method":"GET","parameters":["page","q"],"sources":["query"]}}"#,
+            srv.url("/search")
+        ),
+        format!(
+            r#"{{"type":"parameters","url":"{}","method":"PUT","parameters":["text"],"sources":["javascript"]}}"#,
+            srv.url("/api/comments")
+        ),
+    ] {
+        assert!(contents.contains(&expected), "{expected} not in {contents}");
+    }
+
+    assert_eq!(index.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
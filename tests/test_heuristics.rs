@@ -367,6 +367,169 @@ fn heuristics_wildcard_test_that_auto_filtering_403s_still_allows_for_recursion_
     Ok(())
 }
 
+#[test]
+/// test finds an X-Powered-By header pointing to php on the target's base url, expect the php
+/// extension to be added to the scan along with the reason why
+fn heuristics_auto_extensions_adds_extension_from_powered_by(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+
+    let (tmp_dir, file) = setup_tmp_directory(&["index".to_string()], "wordlist")?;
+
+    srv.mock(|when, then| {
+        when.method(GET).path("/");
+        then.status(200)
+            .header("X-Powered-By", "PHP/8.1.2")
+            .body("home");
+    });
+
+    let with_extension = srv.mock(|when, then| {
+        when.method(GET).path("/index.php");
+        then.status(200).body("index page");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--auto-extensions")
+        .unwrap();
+
+    teardown_tmp_directory(tmp_dir);
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("Auto-extensions")
+            .and(predicate::str::contains("based on X-Powered-By: PHP/8.1.2"))
+            .and(predicate::str::contains(srv.url("/index.php"))),
+    );
+
+    assert_eq!(with_extension.hits(), 1);
+    Ok(())
+}
+
+#[test]
+/// test finds a case-insensitive target through its first found resource, expect the words that
+/// only differ by case from one already requested to be skipped
+fn heuristics_detect_case_skips_words_on_case_insensitive_target(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+
+    let words = ["admin", "ADMIN", "Admin", "AdMiN"].map(String::from);
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("(?i)^/admin$").unwrap());
+        then.status(200).body("admin page");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--detect-case")
+        .arg("--threads")
+        .arg("1")
+        .unwrap();
+
+    teardown_tmp_directory(tmp_dir);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(srv.url("/admin")));
+
+    // the found resource and its swapped-case twin, nothing else
+    assert_eq!(mock.hits(), 2);
+    Ok(())
+}
+
+#[test]
+/// test finds a case-sensitive target through its first found resource, expect every word to
+/// be requested
+fn heuristics_detect_case_keeps_words_on_case_sensitive_target(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+
+    let words = ["admin", "ADMIN"].map(String::from);
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    // httpmock's path matching ignores case, hence the regexes
+    srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/admin$").unwrap());
+        then.status(200).body("admin page");
+    });
+
+    let swapped = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/ADMIN$").unwrap());
+        then.status(404);
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--detect-case")
+        .arg("--threads")
+        .arg("1")
+        .assert()
+        .success();
+
+    teardown_tmp_directory(tmp_dir);
+
+    // once by the heuristic and once from the wordlist
+    assert_eq!(swapped.hits(), 2);
+    Ok(())
+}
+
+#[test]
+/// test finds a resource whose swapped-case twin has the same status and size but different
+/// content, expect the target to be treated as case-sensitive
+fn heuristics_detect_case_compares_content_of_swapped_response(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+
+    let words = ["admin", "ADMIN"].map(String::from);
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist")?;
+
+    srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/admin$").unwrap());
+        then.status(200).body("admin page one");
+    });
+
+    let swapped = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/ADMIN$").unwrap());
+        then.status(200).body("other text two");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--detect-case")
+        .arg("--threads")
+        .arg("1")
+        .assert()
+        .success();
+
+    teardown_tmp_directory(tmp_dir);
+
+    // once by the heuristic and once from the wordlist
+    assert_eq!(swapped.hits(), 2);
+    Ok(())
+}
+
 // #[test]
 // /// test finds a static wildcard and reports as much to stdout and a file
 // fn heuristics_wildcard_test_with_two_static_wildcards_and_output_to_file() {
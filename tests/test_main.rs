@@ -245,6 +245,7 @@ fn main_download_wordlist_from_url() -> Result<(), Box<dyn std::error::Error>> {
     Command::cargo_bin("feroxbuster")
         .unwrap()
         .current_dir(&tmp_dir)
+        .env("XDG_CACHE_HOME", tmp_dir.path())
         .arg("--url")
         .arg(srv.url("/"))
         .arg("--wordlist")
@@ -253,6 +254,13 @@ fn main_download_wordlist_from_url() -> Result<(), Box<dyn std::error::Error>> {
         .success()
         .stderr(predicate::str::contains(srv.url("/derp")));
 
+    // downloaded wordlists go into the cache directory instead of the current directory
+    assert!(!tmp_dir.path().join("derp").exists());
+    assert_eq!(
+        std::fs::read_dir(tmp_dir.path().join("feroxbuster").join("wordlists"))?.count(),
+        1
+    );
+
     teardown_tmp_directory(tmp_dir);
 
     assert_eq!(mock1.hits(), 1); // downloaded wordlist
@@ -1,7 +1,7 @@
 mod utils;
 use assert_cmd::Command;
 use httpmock::Method::GET;
-use httpmock::MockServer;
+use httpmock::{MockServer, Regex};
 use predicates::prelude::*;
 use std::fs::{read_to_string, write};
 use std::path::Path;
@@ -94,6 +94,71 @@ fn resume_scan_works() {
     assert_eq!(not_scanned_yet.hits(), 1);
 }
 
+#[test]
+/// pass a serialized scan whose target was already found to be case-insensitive, expect the
+/// decision to be shown in the banner and words only differing by case to be skipped without
+/// running the heuristic again
+fn resume_scan_keeps_case_decision() {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["css".to_string(), "CSS".to_string()], "wordlist").unwrap();
+
+    let complete_scan = format!(
+        r#"{{"id":"057016a14769414aac9a7a62707598cb","url":"{}","normalized_url":"{}","scan_type":"Directory","status":"Complete","num_requests":4174,"requests_made_so_far":0}}"#,
+        srv.url("/"),
+        srv.url("/"),
+    );
+    let incomplete_scan = format!(
+        r#"{{"id":"400b2323a16f43468a04ffcbbeba34c6","url":"{}","normalized_url":"{}/","scan_type":"Directory","status":"NotStarted","num_requests":4174,"requests_made_so_far":0}}"#,
+        srv.url("/js"),
+        srv.url("/js")
+    );
+    let scans = format!(r#""scans":[{complete_scan},{incomplete_scan}]"#);
+
+    let config = format!(
+        r#""config": {{"type":"configuration","wordlist":"{}","target_url":"{}","threads":1,"extract_links":false,"detect_case":true}}"#,
+        file.to_string_lossy(),
+        srv.url("/")
+    );
+
+    let origin = srv.url("");
+    let decisions = format!(r#""case_insensitive":{{"{origin}":true}}"#);
+
+    // httpmock's path matching ignores case, hence the regexes
+    let lowercase = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/js/css$").unwrap());
+        then.status(200).body("stylesheet");
+    });
+
+    let uppercase = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/js/CSS$").unwrap());
+        then.status(200).body("stylesheet");
+    });
+
+    let state_file_contents = format!(r#"{{{scans},{config},"responses":[],{decisions}}}"#);
+
+    let (tmp_dir2, state_file) = setup_tmp_directory(&[state_file_contents], "state-file").unwrap();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--resume-from")
+        .arg(state_file.as_os_str())
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("Detect Case")
+                .and(predicate::str::contains(format!("[{origin}: insensitive]"))),
+        );
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(tmp_dir2);
+
+    assert_eq!(lowercase.hits(), 1);
+    assert_eq!(uppercase.hits(), 0);
+}
+
 #[test]
 /// kick off scan with a time limit;  
 fn time_limit_enforced_when_specified() {